@@ -7,7 +7,7 @@ extern crate rand_core;
 
 use std::mem::size_of;
 use bencher::{black_box, Bencher};
-use xoshiro::Xoshiro128StarStar;
+use xoshiro::{Interleaved, SplitMix64, Xoshiro128StarStar, Xoshiro256StarStar};
 use rand_core::{SeedableRng, RngCore};
 
 macro_rules! make_bench_u32 {
@@ -26,7 +26,93 @@ macro_rules! make_bench_u32 {
     }
 }
 
+macro_rules! make_bench_fill_bytes {
+    ($name:ident, $rng:path) => {
+        fn $name(b: &mut Bencher) {
+            type Rng = $rng;
+            let mut rng = Rng::from_seed_u64(1);
+            let mut buf = [0u8; 1024];
+            b.iter(|| {
+                rng.fill_bytes(&mut buf);
+                black_box(&buf);
+            });
+            b.bytes = buf.len() as u64;
+        }
+    }
+}
+
 make_bench_u32!(rand_u32_xoshiro, Xoshiro128StarStar);
+make_bench_fill_bytes!(fill_bytes_xoshiro128, Xoshiro128StarStar);
+make_bench_fill_bytes!(fill_bytes_xoshiro256, Xoshiro256StarStar);
+
+fn fill_states_splitmix64(b: &mut Bencher) {
+    let mut rng = SplitMix64::from_seed_u64(1);
+    let mut states = [[0u64; 4]; 256];
+    b.iter(|| {
+        rng.fill_states(&mut states);
+        black_box(&states);
+    });
+    b.bytes = size_of::<[[u64; 4]; 256]>() as u64;
+}
+
+fn next_u64_splitmix64(b: &mut Bencher) {
+    let mut rng = SplitMix64::from_seed_u64(1);
+    let mut states = [[0u64; 4]; 256];
+    b.iter(|| {
+        for state in states.iter_mut() {
+            for w in state.iter_mut() {
+                *w = rng.next_u64();
+            }
+        }
+        black_box(&states);
+    });
+    b.bytes = size_of::<[[u64; 4]; 256]>() as u64;
+}
+
+fn next_u64x4_xoshiro256(b: &mut Bencher) {
+    let mut rng = Xoshiro256StarStar::from_seed_u64(1);
+    b.iter(|| {
+        for _ in 0..10 {
+            black_box(rng.next_u64x4());
+        }
+    });
+    b.bytes = 10 * size_of::<[u64; 4]>() as u64;
+}
+
+fn next_u32x8_xoshiro128(b: &mut Bencher) {
+    let mut rng = Xoshiro128StarStar::from_seed_u64(1);
+    b.iter(|| {
+        for _ in 0..10 {
+            black_box(rng.next_u32x8());
+        }
+    });
+    b.bytes = 10 * size_of::<[u32; 8]>() as u64;
+}
+
+fn fill_u64_interleaved(b: &mut Bencher) {
+    let mut rng: Interleaved<_> =
+        Interleaved::new(Xoshiro256StarStar::from_seed_u64(1));
+    let mut buf = [0u64; 128];
+    b.iter(|| {
+        rng.fill_u64(&mut buf);
+        black_box(&buf);
+    });
+    b.bytes = size_of::<[u64; 128]>() as u64;
+}
+
+fn fill_u64_xoshiro256(b: &mut Bencher) {
+    let mut rng = Xoshiro256StarStar::from_seed_u64(1);
+    let mut buf = [0u64; 128];
+    b.iter(|| {
+        rng.fill_u64(&mut buf);
+        black_box(&buf);
+    });
+    b.bytes = size_of::<[u64; 128]>() as u64;
+}
 
-benchmark_group!(benches, rand_u32_xoshiro);
+benchmark_group!(benches, rand_u32_xoshiro, fill_bytes_xoshiro128,
+                 fill_bytes_xoshiro256, fill_states_splitmix64,
+                 next_u64_splitmix64, next_u64x4_xoshiro256,
+                 next_u32x8_xoshiro128, fill_u64_interleaved,
+                 fill_u64_xoshiro256);
 benchmark_main!(benches);
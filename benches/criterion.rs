@@ -0,0 +1,70 @@
+#[macro_use]
+extern crate criterion;
+extern crate rand_core;
+extern crate xoshiro;
+
+use criterion::{black_box, Criterion, Throughput};
+use rand_core::RngCore;
+use xoshiro::{SplitMix64, Xoroshiro64Star, Xoroshiro64StarStar,
+              Xoroshiro128Plus, Xoroshiro128PlusPlus, Xoroshiro128StarStar,
+              Xoshiro128Plus, Xoshiro128PlusPlus, Xoshiro128StarStar,
+              Xoshiro256Plus, Xoshiro256PlusPlus, Xoshiro256StarStar,
+              Xoshiro512Plus, Xoshiro512PlusPlus, Xoshiro512StarStar};
+
+/// Benchmark the output methods of a generator.
+macro_rules! bench_output {
+    ($c:expr, $name:expr, $rng:ident, $seed:expr) => {{
+        let mut group = $c.benchmark_group($name);
+        let mut rng = $rng::from_seed_u64($seed);
+        group.throughput(Throughput::Bytes(4));
+        group.bench_function("next_u32", |b| b.iter(|| rng.next_u32()));
+        group.throughput(Throughput::Bytes(8));
+        group.bench_function("next_u64", |b| b.iter(|| rng.next_u64()));
+        let mut buf = [0u8; 1024];
+        group.throughput(Throughput::Bytes(buf.len() as u64));
+        group.bench_function("fill_bytes", |b| b.iter(|| {
+            rng.fill_bytes(&mut buf);
+            black_box(&buf);
+        }));
+        group.finish();
+    }}
+}
+
+/// Benchmark the output and jump methods of a generator.
+macro_rules! bench_rng {
+    ($c:expr, $name:expr, $rng:ident, $seed:expr) => {{
+        bench_output!($c, $name, $rng, $seed);
+        let mut rng = $rng::from_seed_u64($seed);
+        $c.bench_function(concat!($name, "/jump"), |b| b.iter(|| {
+            rng.jump();
+            black_box(&rng);
+        }));
+    }}
+}
+
+fn xoroshiro(c: &mut Criterion) {
+    bench_rng!(c, "xoroshiro64star", Xoroshiro64Star, 1);
+    bench_rng!(c, "xoroshiro64starstar", Xoroshiro64StarStar, 1);
+    bench_rng!(c, "xoroshiro128plus", Xoroshiro128Plus, 1);
+    bench_rng!(c, "xoroshiro128plusplus", Xoroshiro128PlusPlus, 1);
+    bench_rng!(c, "xoroshiro128starstar", Xoroshiro128StarStar, 1);
+}
+
+fn xoshiro(c: &mut Criterion) {
+    bench_rng!(c, "xoshiro128plus", Xoshiro128Plus, 1);
+    bench_rng!(c, "xoshiro128plusplus", Xoshiro128PlusPlus, 1);
+    bench_rng!(c, "xoshiro128starstar", Xoshiro128StarStar, 1);
+    bench_rng!(c, "xoshiro256plus", Xoshiro256Plus, 1);
+    bench_rng!(c, "xoshiro256plusplus", Xoshiro256PlusPlus, 1);
+    bench_rng!(c, "xoshiro256starstar", Xoshiro256StarStar, 1);
+    bench_rng!(c, "xoshiro512plus", Xoshiro512Plus, 1);
+    bench_rng!(c, "xoshiro512plusplus", Xoshiro512PlusPlus, 1);
+    bench_rng!(c, "xoshiro512starstar", Xoshiro512StarStar, 1);
+}
+
+fn splitmix(c: &mut Criterion) {
+    bench_output!(c, "splitmix64", SplitMix64, 1);
+}
+
+criterion_group!(benches, xoroshiro, xoshiro, splitmix);
+criterion_main!(benches);
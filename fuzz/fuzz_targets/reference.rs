@@ -0,0 +1,83 @@
+//! Compare the generators with the C reference implementations for arbitrary
+//! states and sequences of operations.
+//!
+//! The first byte selects the generator, the following bytes are its state
+//! as returned by `to_bytes()`, and each remaining byte `b` is an operation:
+//! `jump()` if `b % 4 == 0`, `long_jump()` if `b % 4 == 1`, and otherwise
+//! `b / 4 + 1` outputs, which must be equal.
+
+#![no_main]
+#[macro_use]
+extern crate libfuzzer_sys;
+extern crate rand_core;
+extern crate xoshiro;
+extern crate xoshiro_reference as reference;
+
+use rand_core::RngCore;
+use xoshiro::*;
+
+macro_rules! compare {
+    ($data:expr, [$($i:pat => ($rng:ident, $n:expr, $next:ident
+                               $(, $jump:ident)*)),*]) => {
+        let data: &[u8] = $data;
+        if data.is_empty() {
+            return;
+        }
+        match data[0] % 14 {
+            $(
+                $i => {
+                    let mut bytes = [0; $n];
+                    if data.len() <= $n {
+                        return;
+                    }
+                    bytes.copy_from_slice(&data[1..$n + 1]);
+                    if bytes.iter().all(|&b| b == 0) {
+                        return;
+                    }
+                    let mut rng = $rng::from_bytes(bytes);
+                    let mut c = reference::$rng::new(rng.state());
+                    for &b in &data[$n + 1..] {
+                        match b % 4 {
+                            $(
+                                0 => {
+                                    rng.$jump();
+                                    c.$jump();
+                                }
+                                1 => {
+                                    rng.long_jump();
+                                    c.long_jump();
+                                }
+                            )*
+                            _ => {
+                                for _ in 0..b / 4 + 1 {
+                                    assert_eq!(rng.$next(), c.next_word());
+                                }
+                            }
+                        }
+                    }
+                    assert_eq!(rng.state(), c.state());
+                }
+            )*
+            _ => unreachable!(),
+        }
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    compare!(data, [
+        0 => (Xoroshiro64Star, 8, next_u32),
+        1 => (Xoroshiro64StarStar, 8, next_u32),
+        2 => (Xoroshiro128Plus, 16, next_u64, jump),
+        3 => (Xoroshiro128StarStar, 16, next_u64, jump),
+        4 => (Xoshiro128Plus, 16, next_u32, jump),
+        5 => (Xoshiro128PlusPlus, 16, next_u32, jump),
+        6 => (Xoshiro128StarStar, 16, next_u32, jump),
+        7 => (Xoshiro256Plus, 32, next_u64, jump),
+        8 => (Xoshiro256PlusPlus, 32, next_u64, jump),
+        9 => (Xoshiro256StarStar, 32, next_u64, jump),
+        10 => (Xoshiro512Plus, 64, next_u64, jump),
+        11 => (Xoshiro512PlusPlus, 64, next_u64, jump),
+        12 => (Xoshiro512StarStar, 64, next_u64, jump),
+        13 => (Xoroshiro128PlusPlus, 16, next_u64, jump)
+    ]);
+});
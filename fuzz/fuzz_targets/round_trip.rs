@@ -0,0 +1,70 @@
+//! Restore a generator from an arbitrary state and check that every way of
+//! saving it, and every invertible operation, round-trips.
+//!
+//! The first byte selects the generator and the following bytes are its
+//! state as returned by `to_bytes()`.
+
+#![no_main]
+#[macro_use]
+extern crate libfuzzer_sys;
+extern crate rand_core;
+extern crate xoshiro;
+
+use rand_core::RngCore;
+use xoshiro::*;
+
+macro_rules! round_trip {
+    ($data:expr, [$($i:pat => $rng:ident($n:expr)),*]) => {
+        let data: &[u8] = $data;
+        if data.is_empty() {
+            return;
+        }
+        match data[0] % 14 {
+            $(
+                $i => {
+                    let mut bytes = [0; $n];
+                    if data.len() <= $n {
+                        return;
+                    }
+                    bytes.copy_from_slice(&data[1..$n + 1]);
+                    if bytes.iter().all(|&b| b == 0) {
+                        return;
+                    }
+                    let rng = $rng::from_bytes(bytes);
+                    assert_eq!(rng.to_bytes(), bytes);
+                    assert_eq!($rng::from_c_state(rng.to_c_state()), rng);
+                    assert_eq!(rng.to_string().parse::<$rng>().unwrap(), rng);
+                    assert_eq!($rng::new_unchecked(rng.state()), rng);
+
+                    let mut r = rng.clone();
+                    let x = r.next_u64();
+                    assert_eq!(r.prev_u64(), x);
+                    assert_eq!(r, rng);
+                    r.jump();
+                    r.jump_back();
+                    assert_eq!(r, rng);
+                }
+            )*
+            _ => unreachable!(),
+        }
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    round_trip!(data, [
+        0 => Xoroshiro64Star(8),
+        1 => Xoroshiro64StarStar(8),
+        2 => Xoroshiro128Plus(16),
+        3 => Xoroshiro128StarStar(16),
+        4 => Xoshiro128Plus(16),
+        5 => Xoshiro128PlusPlus(16),
+        6 => Xoshiro128StarStar(16),
+        7 => Xoshiro256Plus(32),
+        8 => Xoshiro256PlusPlus(32),
+        9 => Xoshiro256StarStar(32),
+        10 => Xoshiro512Plus(64),
+        11 => Xoshiro512PlusPlus(64),
+        12 => Xoshiro512StarStar(64),
+        13 => Xoroshiro128PlusPlus(16)
+    ]);
+});
@@ -0,0 +1,57 @@
+//! Seed every generator from arbitrary bytes in every way that accepts them,
+//! and check that the resulting state is valid.
+
+#![no_main]
+#[macro_use]
+extern crate libfuzzer_sys;
+extern crate rand_core;
+extern crate xoshiro;
+
+use std::str;
+
+use rand_core::SeedableRng;
+use xoshiro::*;
+
+/// Assert that the state of `rng` is not entirely zero.
+fn assert_valid<C: Core, S: Scrambler<C>>(rng: &Xoshiro<C, S>) {
+    assert!(rng.state().as_ref().iter().any(|&w| Into::<u64>::into(w) != 0),
+            "{} has an all zero state", Xoshiro::<C, S>::name());
+}
+
+macro_rules! seed {
+    ($data:expr, $($rng:ident),*) => {
+        $({
+            let data: &[u8] = $data;
+            assert_valid(&$rng::from_seed_bytes(data));
+            assert_valid(&$rng::from_seed_str(&String::from_utf8_lossy(data)));
+
+            let mut seed = <$rng as SeedableRng>::Seed::default();
+            let n = seed.as_mut().len();
+            if data.len() >= n {
+                seed.as_mut().copy_from_slice(&data[..n]);
+                let rng = $rng::from_seed(seed.clone());
+                assert_valid(&rng);
+                let zero = data[..n].iter().all(|&b| b == 0);
+                match $rng::try_from_seed(seed) {
+                    Ok(r) => assert!(!zero && r == rng),
+                    Err(_) => assert!(zero),
+                }
+            }
+
+            if let Ok(s) = str::from_utf8(data) {
+                if let Ok(rng) = s.parse::<$rng>() {
+                    assert_valid(&rng);
+                    assert_eq!(rng.to_string().parse::<$rng>().unwrap(), rng);
+                }
+            }
+        })*
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    seed!(data, Xoroshiro64Star, Xoroshiro64StarStar, Xoroshiro128Plus,
+          Xoroshiro128StarStar, Xoshiro128Plus, Xoshiro128PlusPlus,
+          Xoshiro128StarStar, Xoshiro256Plus, Xoshiro256PlusPlus,
+          Xoshiro256StarStar, Xoshiro512Plus, Xoshiro512PlusPlus,
+          Xoshiro512StarStar, Xoroshiro128PlusPlus);
+});
@@ -0,0 +1,600 @@
+/* C bindings of the xoshiro crate, enabled by its `ffi` feature. */
+
+#ifndef XOSHIRO_H
+#define XOSHIRO_H
+
+/* Generated with cbindgen; do not edit by hand. */
+
+#include <stddef.h>
+#include <stdint.h>
+
+/**
+ * The state of the xoroshiro64 generators.
+ */
+typedef struct Xoroshiro64State {
+  uint32_t s[2];
+} Xoroshiro64State;
+
+/**
+ * The function table of a NumPy bit generator.
+ *
+ * This has the layout of `bitgen_t` in `numpy/random/bitgen.h`, through
+ * which `numpy.random.Generator` draws from a custom bit generator. Each
+ * function is called with `state`:
+ *
+ * * `next_uint64` and `next_uint32` return `next_u64()` and `next_u32()`,
+ * * `next_double` returns `next_f64()`, a float in [0, 1) generated from the
+ *   upper 53 bits of `next_u64()`,
+ * * `next_raw` returns the native output, zero-extended for the generators
+ *   with 32-bit output.
+ *
+ * The table does not own the generator, which must outlive any use of it.
+ */
+typedef struct BitGen {
+  void *state;
+  uint64_t (*next_uint64)(void*);
+  uint32_t (*next_uint32)(void*);
+  double (*next_double)(void*);
+  uint64_t (*next_raw)(void*);
+} BitGen;
+
+/**
+ * The state of the xoroshiro128 generators.
+ */
+typedef struct Xoroshiro128State {
+  uint64_t s[2];
+} Xoroshiro128State;
+
+/**
+ * The state of the xoshiro128 generators.
+ */
+typedef struct Xoshiro128State {
+  uint32_t s[4];
+} Xoshiro128State;
+
+/**
+ * The state of the xoshiro256 generators.
+ */
+typedef struct Xoshiro256State {
+  uint64_t s[4];
+} Xoshiro256State;
+
+/**
+ * The state of the xoshiro512 generators.
+ */
+typedef struct Xoshiro512State {
+  uint64_t s[8];
+} Xoshiro512State;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ *Seed a `Xoroshiro64Star` from a `u64` like `from_seed_u64()`.
+ */
+struct Xoroshiro64State xoroshiro64s_new(uint64_t seed);
+
+/**
+ *Return the next output of a `Xoroshiro64Star`.
+ */
+uint32_t xoroshiro64s_next(struct Xoroshiro64State *state);
+
+/**
+ *Return a random `double` in [0, 1) from a `Xoroshiro64Star`, like `next_f64()`.
+ */
+double xoroshiro64s_next_double(struct Xoroshiro64State *state);
+
+/**
+ *Fill `len` bytes at `dest` from a `Xoroshiro64Star`, like `fill_bytes()`.
+ *
+ * # Safety
+ *
+ * `dest` must be valid for writing `len` bytes, or `len` must be 0.
+ */
+void xoroshiro64s_fill_bytes(struct Xoroshiro64State *state, uint8_t *dest, size_t len);
+
+/**
+ *Return the NumPy function table of a `Xoroshiro64Star`.
+ *
+ * The state must outlive any use of the table.
+ */
+struct BitGen xoroshiro64s_bitgen(struct Xoroshiro64State *state);
+
+/**
+ *Jump a `Xoroshiro64Star` forward like `jump()`.
+ */
+void xoroshiro64s_jump(struct Xoroshiro64State *state);
+
+/**
+ *Seed a `Xoroshiro64StarStar` from a `u64` like `from_seed_u64()`.
+ */
+struct Xoroshiro64State xoroshiro64ss_new(uint64_t seed);
+
+/**
+ *Return the next output of a `Xoroshiro64StarStar`.
+ */
+uint32_t xoroshiro64ss_next(struct Xoroshiro64State *state);
+
+/**
+ *Return a random `double` in [0, 1) from a `Xoroshiro64StarStar`, like `next_f64()`.
+ */
+double xoroshiro64ss_next_double(struct Xoroshiro64State *state);
+
+/**
+ *Fill `len` bytes at `dest` from a `Xoroshiro64StarStar`, like `fill_bytes()`.
+ *
+ * # Safety
+ *
+ * `dest` must be valid for writing `len` bytes, or `len` must be 0.
+ */
+void xoroshiro64ss_fill_bytes(struct Xoroshiro64State *state, uint8_t *dest, size_t len);
+
+/**
+ *Return the NumPy function table of a `Xoroshiro64StarStar`.
+ *
+ * The state must outlive any use of the table.
+ */
+struct BitGen xoroshiro64ss_bitgen(struct Xoroshiro64State *state);
+
+/**
+ *Jump a `Xoroshiro64StarStar` forward like `jump()`.
+ */
+void xoroshiro64ss_jump(struct Xoroshiro64State *state);
+
+/**
+ *Seed a `Xoroshiro128Plus` from a `u64` like `from_seed_u64()`.
+ */
+struct Xoroshiro128State xoroshiro128p_new(uint64_t seed);
+
+/**
+ *Return the next output of a `Xoroshiro128Plus`.
+ */
+uint64_t xoroshiro128p_next(struct Xoroshiro128State *state);
+
+/**
+ *Return a random `double` in [0, 1) from a `Xoroshiro128Plus`, like `next_f64()`.
+ */
+double xoroshiro128p_next_double(struct Xoroshiro128State *state);
+
+/**
+ *Fill `len` bytes at `dest` from a `Xoroshiro128Plus`, like `fill_bytes()`.
+ *
+ * # Safety
+ *
+ * `dest` must be valid for writing `len` bytes, or `len` must be 0.
+ */
+void xoroshiro128p_fill_bytes(struct Xoroshiro128State *state, uint8_t *dest, size_t len);
+
+/**
+ *Return the NumPy function table of a `Xoroshiro128Plus`.
+ *
+ * The state must outlive any use of the table.
+ */
+struct BitGen xoroshiro128p_bitgen(struct Xoroshiro128State *state);
+
+/**
+ *Jump a `Xoroshiro128Plus` forward like `jump()`.
+ */
+void xoroshiro128p_jump(struct Xoroshiro128State *state);
+
+/**
+ *Jump a `Xoroshiro128Plus` forward like `long_jump()`.
+ */
+void xoroshiro128p_long_jump(struct Xoroshiro128State *state);
+
+/**
+ *Seed a `Xoroshiro128StarStar` from a `u64` like `from_seed_u64()`.
+ */
+struct Xoroshiro128State xoroshiro128ss_new(uint64_t seed);
+
+/**
+ *Return the next output of a `Xoroshiro128StarStar`.
+ */
+uint64_t xoroshiro128ss_next(struct Xoroshiro128State *state);
+
+/**
+ *Return a random `double` in [0, 1) from a `Xoroshiro128StarStar`, like `next_f64()`.
+ */
+double xoroshiro128ss_next_double(struct Xoroshiro128State *state);
+
+/**
+ *Fill `len` bytes at `dest` from a `Xoroshiro128StarStar`, like `fill_bytes()`.
+ *
+ * # Safety
+ *
+ * `dest` must be valid for writing `len` bytes, or `len` must be 0.
+ */
+void xoroshiro128ss_fill_bytes(struct Xoroshiro128State *state, uint8_t *dest, size_t len);
+
+/**
+ *Return the NumPy function table of a `Xoroshiro128StarStar`.
+ *
+ * The state must outlive any use of the table.
+ */
+struct BitGen xoroshiro128ss_bitgen(struct Xoroshiro128State *state);
+
+/**
+ *Jump a `Xoroshiro128StarStar` forward like `jump()`.
+ */
+void xoroshiro128ss_jump(struct Xoroshiro128State *state);
+
+/**
+ *Jump a `Xoroshiro128StarStar` forward like `long_jump()`.
+ */
+void xoroshiro128ss_long_jump(struct Xoroshiro128State *state);
+
+/**
+ *Seed a `Xoshiro128Plus` from a `u64` like `from_seed_u64()`.
+ */
+struct Xoshiro128State xoshiro128p_new(uint64_t seed);
+
+/**
+ *Return the next output of a `Xoshiro128Plus`.
+ */
+uint32_t xoshiro128p_next(struct Xoshiro128State *state);
+
+/**
+ *Return a random `double` in [0, 1) from a `Xoshiro128Plus`, like `next_f64()`.
+ */
+double xoshiro128p_next_double(struct Xoshiro128State *state);
+
+/**
+ *Fill `len` bytes at `dest` from a `Xoshiro128Plus`, like `fill_bytes()`.
+ *
+ * # Safety
+ *
+ * `dest` must be valid for writing `len` bytes, or `len` must be 0.
+ */
+void xoshiro128p_fill_bytes(struct Xoshiro128State *state, uint8_t *dest, size_t len);
+
+/**
+ *Return the NumPy function table of a `Xoshiro128Plus`.
+ *
+ * The state must outlive any use of the table.
+ */
+struct BitGen xoshiro128p_bitgen(struct Xoshiro128State *state);
+
+/**
+ *Jump a `Xoshiro128Plus` forward like `jump()`.
+ */
+void xoshiro128p_jump(struct Xoshiro128State *state);
+
+/**
+ *Jump a `Xoshiro128Plus` forward like `long_jump()`.
+ */
+void xoshiro128p_long_jump(struct Xoshiro128State *state);
+
+/**
+ *Seed a `Xoshiro128PlusPlus` from a `u64` like `from_seed_u64()`.
+ */
+struct Xoshiro128State xoshiro128pp_new(uint64_t seed);
+
+/**
+ *Return the next output of a `Xoshiro128PlusPlus`.
+ */
+uint32_t xoshiro128pp_next(struct Xoshiro128State *state);
+
+/**
+ *Return a random `double` in [0, 1) from a `Xoshiro128PlusPlus`, like `next_f64()`.
+ */
+double xoshiro128pp_next_double(struct Xoshiro128State *state);
+
+/**
+ *Fill `len` bytes at `dest` from a `Xoshiro128PlusPlus`, like `fill_bytes()`.
+ *
+ * # Safety
+ *
+ * `dest` must be valid for writing `len` bytes, or `len` must be 0.
+ */
+void xoshiro128pp_fill_bytes(struct Xoshiro128State *state, uint8_t *dest, size_t len);
+
+/**
+ *Return the NumPy function table of a `Xoshiro128PlusPlus`.
+ *
+ * The state must outlive any use of the table.
+ */
+struct BitGen xoshiro128pp_bitgen(struct Xoshiro128State *state);
+
+/**
+ *Jump a `Xoshiro128PlusPlus` forward like `jump()`.
+ */
+void xoshiro128pp_jump(struct Xoshiro128State *state);
+
+/**
+ *Jump a `Xoshiro128PlusPlus` forward like `long_jump()`.
+ */
+void xoshiro128pp_long_jump(struct Xoshiro128State *state);
+
+/**
+ *Seed a `Xoshiro128StarStar` from a `u64` like `from_seed_u64()`.
+ */
+struct Xoshiro128State xoshiro128ss_new(uint64_t seed);
+
+/**
+ *Return the next output of a `Xoshiro128StarStar`.
+ */
+uint32_t xoshiro128ss_next(struct Xoshiro128State *state);
+
+/**
+ *Return a random `double` in [0, 1) from a `Xoshiro128StarStar`, like `next_f64()`.
+ */
+double xoshiro128ss_next_double(struct Xoshiro128State *state);
+
+/**
+ *Fill `len` bytes at `dest` from a `Xoshiro128StarStar`, like `fill_bytes()`.
+ *
+ * # Safety
+ *
+ * `dest` must be valid for writing `len` bytes, or `len` must be 0.
+ */
+void xoshiro128ss_fill_bytes(struct Xoshiro128State *state, uint8_t *dest, size_t len);
+
+/**
+ *Return the NumPy function table of a `Xoshiro128StarStar`.
+ *
+ * The state must outlive any use of the table.
+ */
+struct BitGen xoshiro128ss_bitgen(struct Xoshiro128State *state);
+
+/**
+ *Jump a `Xoshiro128StarStar` forward like `jump()`.
+ */
+void xoshiro128ss_jump(struct Xoshiro128State *state);
+
+/**
+ *Jump a `Xoshiro128StarStar` forward like `long_jump()`.
+ */
+void xoshiro128ss_long_jump(struct Xoshiro128State *state);
+
+/**
+ *Seed a `Xoshiro256Plus` from a `u64` like `from_seed_u64()`.
+ */
+struct Xoshiro256State xoshiro256p_new(uint64_t seed);
+
+/**
+ *Return the next output of a `Xoshiro256Plus`.
+ */
+uint64_t xoshiro256p_next(struct Xoshiro256State *state);
+
+/**
+ *Return a random `double` in [0, 1) from a `Xoshiro256Plus`, like `next_f64()`.
+ */
+double xoshiro256p_next_double(struct Xoshiro256State *state);
+
+/**
+ *Fill `len` bytes at `dest` from a `Xoshiro256Plus`, like `fill_bytes()`.
+ *
+ * # Safety
+ *
+ * `dest` must be valid for writing `len` bytes, or `len` must be 0.
+ */
+void xoshiro256p_fill_bytes(struct Xoshiro256State *state, uint8_t *dest, size_t len);
+
+/**
+ *Return the NumPy function table of a `Xoshiro256Plus`.
+ *
+ * The state must outlive any use of the table.
+ */
+struct BitGen xoshiro256p_bitgen(struct Xoshiro256State *state);
+
+/**
+ *Jump a `Xoshiro256Plus` forward like `jump()`.
+ */
+void xoshiro256p_jump(struct Xoshiro256State *state);
+
+/**
+ *Jump a `Xoshiro256Plus` forward like `long_jump()`.
+ */
+void xoshiro256p_long_jump(struct Xoshiro256State *state);
+
+/**
+ *Seed a `Xoshiro256PlusPlus` from a `u64` like `from_seed_u64()`.
+ */
+struct Xoshiro256State xoshiro256pp_new(uint64_t seed);
+
+/**
+ *Return the next output of a `Xoshiro256PlusPlus`.
+ */
+uint64_t xoshiro256pp_next(struct Xoshiro256State *state);
+
+/**
+ *Return a random `double` in [0, 1) from a `Xoshiro256PlusPlus`, like `next_f64()`.
+ */
+double xoshiro256pp_next_double(struct Xoshiro256State *state);
+
+/**
+ *Fill `len` bytes at `dest` from a `Xoshiro256PlusPlus`, like `fill_bytes()`.
+ *
+ * # Safety
+ *
+ * `dest` must be valid for writing `len` bytes, or `len` must be 0.
+ */
+void xoshiro256pp_fill_bytes(struct Xoshiro256State *state, uint8_t *dest, size_t len);
+
+/**
+ *Return the NumPy function table of a `Xoshiro256PlusPlus`.
+ *
+ * The state must outlive any use of the table.
+ */
+struct BitGen xoshiro256pp_bitgen(struct Xoshiro256State *state);
+
+/**
+ *Jump a `Xoshiro256PlusPlus` forward like `jump()`.
+ */
+void xoshiro256pp_jump(struct Xoshiro256State *state);
+
+/**
+ *Jump a `Xoshiro256PlusPlus` forward like `long_jump()`.
+ */
+void xoshiro256pp_long_jump(struct Xoshiro256State *state);
+
+/**
+ *Seed a `Xoshiro256StarStar` from a `u64` like `from_seed_u64()`.
+ */
+struct Xoshiro256State xoshiro256ss_new(uint64_t seed);
+
+/**
+ *Return the next output of a `Xoshiro256StarStar`.
+ */
+uint64_t xoshiro256ss_next(struct Xoshiro256State *state);
+
+/**
+ *Return a random `double` in [0, 1) from a `Xoshiro256StarStar`, like `next_f64()`.
+ */
+double xoshiro256ss_next_double(struct Xoshiro256State *state);
+
+/**
+ *Fill `len` bytes at `dest` from a `Xoshiro256StarStar`, like `fill_bytes()`.
+ *
+ * # Safety
+ *
+ * `dest` must be valid for writing `len` bytes, or `len` must be 0.
+ */
+void xoshiro256ss_fill_bytes(struct Xoshiro256State *state, uint8_t *dest, size_t len);
+
+/**
+ *Return the NumPy function table of a `Xoshiro256StarStar`.
+ *
+ * The state must outlive any use of the table.
+ */
+struct BitGen xoshiro256ss_bitgen(struct Xoshiro256State *state);
+
+/**
+ *Jump a `Xoshiro256StarStar` forward like `jump()`.
+ */
+void xoshiro256ss_jump(struct Xoshiro256State *state);
+
+/**
+ *Jump a `Xoshiro256StarStar` forward like `long_jump()`.
+ */
+void xoshiro256ss_long_jump(struct Xoshiro256State *state);
+
+/**
+ *Seed a `Xoshiro512Plus` from a `u64` like `from_seed_u64()`.
+ */
+struct Xoshiro512State xoshiro512p_new(uint64_t seed);
+
+/**
+ *Return the next output of a `Xoshiro512Plus`.
+ */
+uint64_t xoshiro512p_next(struct Xoshiro512State *state);
+
+/**
+ *Return a random `double` in [0, 1) from a `Xoshiro512Plus`, like `next_f64()`.
+ */
+double xoshiro512p_next_double(struct Xoshiro512State *state);
+
+/**
+ *Fill `len` bytes at `dest` from a `Xoshiro512Plus`, like `fill_bytes()`.
+ *
+ * # Safety
+ *
+ * `dest` must be valid for writing `len` bytes, or `len` must be 0.
+ */
+void xoshiro512p_fill_bytes(struct Xoshiro512State *state, uint8_t *dest, size_t len);
+
+/**
+ *Return the NumPy function table of a `Xoshiro512Plus`.
+ *
+ * The state must outlive any use of the table.
+ */
+struct BitGen xoshiro512p_bitgen(struct Xoshiro512State *state);
+
+/**
+ *Jump a `Xoshiro512Plus` forward like `jump()`.
+ */
+void xoshiro512p_jump(struct Xoshiro512State *state);
+
+/**
+ *Jump a `Xoshiro512Plus` forward like `long_jump()`.
+ */
+void xoshiro512p_long_jump(struct Xoshiro512State *state);
+
+/**
+ *Seed a `Xoshiro512PlusPlus` from a `u64` like `from_seed_u64()`.
+ */
+struct Xoshiro512State xoshiro512pp_new(uint64_t seed);
+
+/**
+ *Return the next output of a `Xoshiro512PlusPlus`.
+ */
+uint64_t xoshiro512pp_next(struct Xoshiro512State *state);
+
+/**
+ *Return a random `double` in [0, 1) from a `Xoshiro512PlusPlus`, like `next_f64()`.
+ */
+double xoshiro512pp_next_double(struct Xoshiro512State *state);
+
+/**
+ *Fill `len` bytes at `dest` from a `Xoshiro512PlusPlus`, like `fill_bytes()`.
+ *
+ * # Safety
+ *
+ * `dest` must be valid for writing `len` bytes, or `len` must be 0.
+ */
+void xoshiro512pp_fill_bytes(struct Xoshiro512State *state, uint8_t *dest, size_t len);
+
+/**
+ *Return the NumPy function table of a `Xoshiro512PlusPlus`.
+ *
+ * The state must outlive any use of the table.
+ */
+struct BitGen xoshiro512pp_bitgen(struct Xoshiro512State *state);
+
+/**
+ *Jump a `Xoshiro512PlusPlus` forward like `jump()`.
+ */
+void xoshiro512pp_jump(struct Xoshiro512State *state);
+
+/**
+ *Jump a `Xoshiro512PlusPlus` forward like `long_jump()`.
+ */
+void xoshiro512pp_long_jump(struct Xoshiro512State *state);
+
+/**
+ *Seed a `Xoshiro512StarStar` from a `u64` like `from_seed_u64()`.
+ */
+struct Xoshiro512State xoshiro512ss_new(uint64_t seed);
+
+/**
+ *Return the next output of a `Xoshiro512StarStar`.
+ */
+uint64_t xoshiro512ss_next(struct Xoshiro512State *state);
+
+/**
+ *Return a random `double` in [0, 1) from a `Xoshiro512StarStar`, like `next_f64()`.
+ */
+double xoshiro512ss_next_double(struct Xoshiro512State *state);
+
+/**
+ *Fill `len` bytes at `dest` from a `Xoshiro512StarStar`, like `fill_bytes()`.
+ *
+ * # Safety
+ *
+ * `dest` must be valid for writing `len` bytes, or `len` must be 0.
+ */
+void xoshiro512ss_fill_bytes(struct Xoshiro512State *state, uint8_t *dest, size_t len);
+
+/**
+ *Return the NumPy function table of a `Xoshiro512StarStar`.
+ *
+ * The state must outlive any use of the table.
+ */
+struct BitGen xoshiro512ss_bitgen(struct Xoshiro512State *state);
+
+/**
+ *Jump a `Xoshiro512StarStar` forward like `jump()`.
+ */
+void xoshiro512ss_jump(struct Xoshiro512State *state);
+
+/**
+ *Jump a `Xoshiro512StarStar` forward like `long_jump()`.
+ */
+void xoshiro512ss_long_jump(struct Xoshiro512State *state);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* XOSHIRO_H */
@@ -0,0 +1,45 @@
+extern crate cc;
+
+/// The reference implementations, their state variables and whether they
+/// define `jump()` and `long_jump()`.
+const GENERATORS: &[(&str, &str, bool)] = &[
+    ("splitmix64", "x", false),
+    ("xoroshiro64star", "s", false),
+    ("xoroshiro64starstar", "s", false),
+    ("xoroshiro128plus", "s", true),
+    ("xoroshiro128plusplus", "s", true),
+    ("xoroshiro128starstar", "s", true),
+    ("xoshiro128plus", "s", true),
+    ("xoshiro128plusplus", "s", true),
+    ("xoshiro128starstar", "s", true),
+    ("xoshiro256plus", "s", true),
+    ("xoshiro256plusplus", "s", true),
+    ("xoshiro256starstar", "s", true),
+    ("xoshiro512plus", "s", true),
+    ("xoshiro512plusplus", "s", true),
+    ("xoshiro512starstar", "s", true),
+];
+
+fn main() {
+    println!("cargo:rerun-if-changed=c");
+    // Every source defines the same global names, so each one is compiled
+    // separately with the names prefixed by the generator.
+    for &(name, state, jumps) in GENERATORS {
+        let mut build = cc::Build::new();
+        build.file("c/wrapper.c")
+            .include("c")
+            .define("SOURCE", format!("\"{}.c\"", name).as_str())
+            .define("STATE", state)
+            .opt_level(2)
+            .warnings(false);
+        let mut functions = vec!["next", "set_state", "get_state"];
+        if jumps {
+            functions.push("jump");
+            functions.push("long_jump");
+        }
+        for f in functions {
+            build.define(f, format!("{}_{}", name, f).as_str());
+        }
+        build.compile(name);
+    }
+}
@@ -0,0 +1,15 @@
+/* Compiled once for each generator by `build.rs`, with `SOURCE` set to the
+   reference implementation, `STATE` to its state variable and `next`, `jump`,
+   `long_jump`, `set_state` and `get_state` renamed to prefixed symbols. */
+
+#include <string.h>
+
+#include SOURCE
+
+void set_state(const void *state) {
+	memcpy(&STATE, state, sizeof STATE);
+}
+
+void get_state(void *state) {
+	memcpy(state, &STATE, sizeof STATE);
+}
@@ -0,0 +1,105 @@
+//! The C reference implementations of the generators, for testing.
+//!
+//! The C implementations keep their state in a global variable. Each type
+//! here holds a lock on the state of one implementation, so at most one
+//! instance of each type exists at a time; creating another one blocks until
+//! the first is dropped.
+
+use std::sync::{Mutex, MutexGuard};
+
+macro_rules! reference {
+    ($name:ident, $word:ty, $n:expr, $next:ident, $set_state:ident,
+     $get_state:ident $(, $jump:ident, $long_jump:ident)*) => {
+        pub struct $name {
+            _lock: MutexGuard<'static, ()>,
+        }
+
+        impl $name {
+            /// Lock the C implementation and set its state.
+            pub fn new(state: [$word; $n]) -> $name {
+                static LOCK: Mutex<()> = Mutex::new(());
+                extern "C" {
+                    fn $set_state(state: *const $word);
+                }
+                let lock = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+                unsafe { $set_state(state.as_ptr()) };
+                $name { _lock: lock }
+            }
+
+            pub fn state(&self) -> [$word; $n] {
+                extern "C" {
+                    fn $get_state(state: *mut $word);
+                }
+                let mut state = [0; $n];
+                unsafe { $get_state(state.as_mut_ptr()) };
+                state
+            }
+
+            pub fn next_word(&mut self) -> $word {
+                extern "C" {
+                    fn $next() -> $word;
+                }
+                unsafe { $next() }
+            }
+
+            $(
+                pub fn jump(&mut self) {
+                    extern "C" {
+                        fn $jump();
+                    }
+                    unsafe { $jump() }
+                }
+
+                pub fn long_jump(&mut self) {
+                    extern "C" {
+                        fn $long_jump();
+                    }
+                    unsafe { $long_jump() }
+                }
+            )*
+        }
+    }
+}
+
+reference!(SplitMix64, u64, 1, splitmix64_next, splitmix64_set_state,
+           splitmix64_get_state);
+reference!(Xoroshiro64Star, u32, 2, xoroshiro64star_next,
+           xoroshiro64star_set_state, xoroshiro64star_get_state);
+reference!(Xoroshiro64StarStar, u32, 2, xoroshiro64starstar_next,
+           xoroshiro64starstar_set_state, xoroshiro64starstar_get_state);
+reference!(Xoroshiro128Plus, u64, 2, xoroshiro128plus_next,
+           xoroshiro128plus_set_state, xoroshiro128plus_get_state,
+           xoroshiro128plus_jump, xoroshiro128plus_long_jump);
+reference!(Xoroshiro128PlusPlus, u64, 2, xoroshiro128plusplus_next,
+           xoroshiro128plusplus_set_state, xoroshiro128plusplus_get_state,
+           xoroshiro128plusplus_jump, xoroshiro128plusplus_long_jump);
+reference!(Xoroshiro128StarStar, u64, 2, xoroshiro128starstar_next,
+           xoroshiro128starstar_set_state, xoroshiro128starstar_get_state,
+           xoroshiro128starstar_jump, xoroshiro128starstar_long_jump);
+reference!(Xoshiro128Plus, u32, 4, xoshiro128plus_next,
+           xoshiro128plus_set_state, xoshiro128plus_get_state,
+           xoshiro128plus_jump, xoshiro128plus_long_jump);
+reference!(Xoshiro128PlusPlus, u32, 4, xoshiro128plusplus_next,
+           xoshiro128plusplus_set_state, xoshiro128plusplus_get_state,
+           xoshiro128plusplus_jump, xoshiro128plusplus_long_jump);
+reference!(Xoshiro128StarStar, u32, 4, xoshiro128starstar_next,
+           xoshiro128starstar_set_state, xoshiro128starstar_get_state,
+           xoshiro128starstar_jump, xoshiro128starstar_long_jump);
+reference!(Xoshiro256Plus, u64, 4, xoshiro256plus_next,
+           xoshiro256plus_set_state, xoshiro256plus_get_state,
+           xoshiro256plus_jump, xoshiro256plus_long_jump);
+reference!(Xoshiro256PlusPlus, u64, 4, xoshiro256plusplus_next,
+           xoshiro256plusplus_set_state, xoshiro256plusplus_get_state,
+           xoshiro256plusplus_jump, xoshiro256plusplus_long_jump);
+reference!(Xoshiro256StarStar, u64, 4, xoshiro256starstar_next,
+           xoshiro256starstar_set_state, xoshiro256starstar_get_state,
+           xoshiro256starstar_jump, xoshiro256starstar_long_jump);
+reference!(Xoshiro512Plus, u64, 8, xoshiro512plus_next,
+           xoshiro512plus_set_state, xoshiro512plus_get_state,
+           xoshiro512plus_jump, xoshiro512plus_long_jump);
+reference!(Xoshiro512PlusPlus, u64, 8, xoshiro512plusplus_next,
+           xoshiro512plusplus_set_state, xoshiro512plusplus_get_state,
+           xoshiro512plusplus_jump, xoshiro512plusplus_long_jump);
+reference!(Xoshiro512StarStar, u64, 8, xoshiro512starstar_next,
+           xoshiro512starstar_set_state, xoshiro512starstar_get_state,
+           xoshiro512starstar_jump, xoshiro512starstar_long_jump);
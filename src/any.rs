@@ -0,0 +1,285 @@
+use std::fmt;
+use std::str::FromStr;
+
+use rand_core::{RngCore, SeedableRng, Error};
+
+use splitmix64::hash_bytes;
+use text::ParseStateError;
+use SplitMix64;
+#[cfg(feature = "xoroshiro64")]
+use {Xoroshiro64Star, Xoroshiro64StarStar};
+#[cfg(feature = "xoroshiro128")]
+use {Xoroshiro128Plus, Xoroshiro128PlusPlus, Xoroshiro128StarStar};
+#[cfg(feature = "xoshiro128")]
+use {Xoshiro128Plus, Xoshiro128PlusPlus, Xoshiro128StarStar};
+#[cfg(feature = "xoshiro256")]
+use {Xoshiro256Plus, Xoshiro256PlusPlus, Xoshiro256StarStar};
+#[cfg(feature = "xoshiro512")]
+use {Xoshiro512Plus, Xoshiro512PlusPlus, Xoshiro512StarStar};
+
+/// The operations of the generators needed for selecting them at runtime.
+trait Generator: SeedableRng {
+    /// Seed from a `u64` like `from_seed_u64()`.
+    fn seed_u64(seed: u64) -> Self;
+
+    /// Seed from bytes like `Xoshiro::from_seed_bytes()`.
+    fn seed_bytes(bytes: &[u8]) -> Self {
+        let mut seed = Self::Seed::default();
+        hash_bytes(bytes, seed.as_mut());
+        Self::from_seed(seed)
+    }
+
+    /// Jump `i` times, returning `false` if the generator cannot jump.
+    fn jump_stream(&mut self, i: u64) -> bool;
+}
+
+macro_rules! impl_generator {
+    ($($t:ident),*) => {
+        $(
+            impl Generator for $t {
+                fn seed_u64(seed: u64) -> $t {
+                    $t::from_seed_u64(seed)
+                }
+
+                fn jump_stream(&mut self, i: u64) -> bool {
+                    self.jump_n(i);
+                    true
+                }
+            }
+        )*
+    }
+}
+
+#[cfg(feature = "xoroshiro64")]
+impl_generator!(Xoroshiro64Star, Xoroshiro64StarStar);
+#[cfg(feature = "xoroshiro128")]
+impl_generator!(Xoroshiro128Plus, Xoroshiro128PlusPlus, Xoroshiro128StarStar);
+#[cfg(feature = "xoshiro128")]
+impl_generator!(Xoshiro128Plus, Xoshiro128PlusPlus, Xoshiro128StarStar);
+#[cfg(feature = "xoshiro256")]
+impl_generator!(Xoshiro256Plus, Xoshiro256PlusPlus, Xoshiro256StarStar);
+#[cfg(feature = "xoshiro512")]
+impl_generator!(Xoshiro512Plus, Xoshiro512PlusPlus, Xoshiro512StarStar);
+
+impl Generator for SplitMix64 {
+    fn seed_u64(seed: u64) -> SplitMix64 {
+        SplitMix64::from_seed_u64(seed)
+    }
+
+    fn jump_stream(&mut self, i: u64) -> bool {
+        i == 0
+    }
+}
+
+macro_rules! any_xoshiro {
+    ($($(#[$m:meta])* $t:ident),*) => {
+        /// A generator of this crate selected at runtime.
+        ///
+        /// The generators are identified by their names, e.g. `"xoshiro256++"`
+        /// or `"splitmix64"`, as returned by `name()`. `FromStr` accepts a
+        /// bare name, giving the generator seeded with `from_seed_u64(name,
+        /// 0)`, or a state written by `Display`, e.g.
+        /// `"xoshiro256++/v1:<hex>"`, restoring it exactly.
+        ///
+        /// ```
+        /// # extern crate rand_core;
+        /// # extern crate xoshiro;
+        /// # fn main() {
+        /// use rand_core::RngCore;
+        /// use xoshiro::AnyXoshiro;
+        ///
+        /// let mut rng = AnyXoshiro::from_seed_u64("xoshiro256++", 42).unwrap();
+        /// let x = rng.next_u64();
+        /// let mut restored: AnyXoshiro = rng.to_string().parse().unwrap();
+        /// assert_eq!(restored.next_u64(), rng.next_u64());
+        /// # let _ = x;
+        /// # }
+        /// ```
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum AnyXoshiro {
+            $($(#[$m])* $t($t),)*
+        }
+
+        impl AnyXoshiro {
+            /// Return the names of all generators.
+            #[allow(clippy::vec_init_then_push)]
+            pub fn names() -> Vec<String> {
+                let mut names = Vec::new();
+                $(
+                    $(#[$m])*
+                    names.push($t::name());
+                )*
+                names
+            }
+
+            /// Return the name of the generator.
+            pub fn name(&self) -> String {
+                match *self {
+                    $($(#[$m])* AnyXoshiro::$t(_) => $t::name(),)*
+                }
+            }
+
+            /// Create the generator called `name` with `from_seed_u64(seed)`.
+            ///
+            /// This fails if there is no generator called `name`.
+            pub fn from_seed_u64(name: &str, seed: u64)
+                -> Result<AnyXoshiro, ParseStateError>
+            {
+                $(
+                    $(#[$m])*
+                    if name == $t::name() {
+                        return Ok(AnyXoshiro::$t($t::seed_u64(seed)));
+                    }
+                )*
+                Err(ParseStateError::Name)
+            }
+
+            /// Create the generator called `name` with `from_seed_bytes()`.
+            ///
+            /// `SplitMix64` hashes the bytes the same way. This fails if there
+            /// is no generator called `name`.
+            pub fn from_seed_bytes(name: &str, bytes: &[u8])
+                -> Result<AnyXoshiro, ParseStateError>
+            {
+                $(
+                    $(#[$m])*
+                    if name == $t::name() {
+                        return Ok(AnyXoshiro::$t($t::seed_bytes(bytes)));
+                    }
+                )*
+                Err(ParseStateError::Name)
+            }
+
+            /// Create the generator called `name`, seeded from the operating
+            /// system's random number source.
+            ///
+            /// This fails if there is no generator called `name`, and panics
+            /// if the operating system fails to provide randomness.
+            #[cfg(feature = "getrandom")]
+            pub fn from_entropy(name: &str)
+                -> Result<AnyXoshiro, ParseStateError>
+            {
+                $(
+                    $(#[$m])*
+                    if name == $t::name() {
+                        return Ok(AnyXoshiro::$t(::common::from_entropy()));
+                    }
+                )*
+                Err(ParseStateError::Name)
+            }
+
+            /// Jump `i` times, returning `false` for `SplitMix64`, which
+            /// cannot jump.
+            pub(crate) fn jump_stream(&mut self, i: u64) -> bool {
+                match *self {
+                    $($(#[$m])* AnyXoshiro::$t(ref mut rng) => rng.jump_stream(i),)*
+                }
+            }
+        }
+
+        impl RngCore for AnyXoshiro {
+            #[inline]
+            fn next_u32(&mut self) -> u32 {
+                match *self {
+                    $($(#[$m])* AnyXoshiro::$t(ref mut rng) => rng.next_u32(),)*
+                }
+            }
+
+            #[inline]
+            fn next_u64(&mut self) -> u64 {
+                match *self {
+                    $($(#[$m])* AnyXoshiro::$t(ref mut rng) => rng.next_u64(),)*
+                }
+            }
+
+            #[inline]
+            fn fill_bytes(&mut self, dest: &mut [u8]) {
+                match *self {
+                    $($(#[$m])* AnyXoshiro::$t(ref mut rng) => rng.fill_bytes(dest),)*
+                }
+            }
+
+            #[inline]
+            fn try_fill_bytes(&mut self, dest: &mut [u8])
+                -> Result<(), Error>
+            {
+                match *self {
+                    $($(#[$m])* AnyXoshiro::$t(ref mut rng) => rng.try_fill_bytes(dest),)*
+                }
+            }
+        }
+
+        impl fmt::Display for AnyXoshiro {
+            /// Write the state of the generator like its own `Display`.
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                match *self {
+                    $($(#[$m])* AnyXoshiro::$t(ref rng) => rng.fmt(f),)*
+                }
+            }
+        }
+
+        impl FromStr for AnyXoshiro {
+            type Err = ParseStateError;
+
+            fn from_str(s: &str) -> Result<AnyXoshiro, ParseStateError> {
+                let name = match s.split_once('/') {
+                    None => return AnyXoshiro::from_seed_u64(s, 0),
+                    Some((name, _)) => name,
+                };
+                $(
+                    $(#[$m])*
+                    if name == $t::name() {
+                        return s.parse().map(AnyXoshiro::$t);
+                    }
+                )*
+                Err(ParseStateError::Name)
+            }
+        }
+    }
+}
+
+any_xoshiro!(
+    SplitMix64,
+    #[cfg(feature = "xoroshiro64")] Xoroshiro64Star,
+    #[cfg(feature = "xoroshiro64")] Xoroshiro64StarStar,
+    #[cfg(feature = "xoroshiro128")] Xoroshiro128Plus,
+    #[cfg(feature = "xoroshiro128")] Xoroshiro128PlusPlus,
+    #[cfg(feature = "xoroshiro128")] Xoroshiro128StarStar,
+    #[cfg(feature = "xoshiro128")] Xoshiro128Plus,
+    #[cfg(feature = "xoshiro128")] Xoshiro128PlusPlus,
+    #[cfg(feature = "xoshiro128")] Xoshiro128StarStar,
+    #[cfg(feature = "xoshiro256")] Xoshiro256Plus,
+    #[cfg(feature = "xoshiro256")] Xoshiro256PlusPlus,
+    #[cfg(feature = "xoshiro256")] Xoshiro256StarStar,
+    #[cfg(feature = "xoshiro512")] Xoshiro512Plus,
+    #[cfg(feature = "xoshiro512")] Xoshiro512PlusPlus,
+    #[cfg(feature = "xoshiro512")] Xoshiro512StarStar
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn select() {
+        assert_eq!(AnyXoshiro::names().len(), 15);
+        for name in AnyXoshiro::names() {
+            let mut rng = AnyXoshiro::from_seed_u64(&name, 1).unwrap();
+            assert_eq!(rng.name(), name);
+            rng.next_u64();
+            let restored: AnyXoshiro = rng.to_string().parse().unwrap();
+            assert_eq!(restored, rng);
+            assert!(name.parse::<AnyXoshiro>().is_ok());
+        }
+        let mut rng: AnyXoshiro = "xoshiro256++".parse().unwrap();
+        let mut expected = Xoshiro256PlusPlus::from_seed_u64(0);
+        assert_eq!(rng.next_u64(), expected.next_u64());
+        assert_eq!(AnyXoshiro::from_seed_u64("xoroshiro64**", 5).unwrap(),
+                   AnyXoshiro::Xoroshiro64StarStar(
+                       Xoroshiro64StarStar::from_seed_u64(5)));
+        assert_eq!("xoshiro1024**".parse::<AnyXoshiro>(),
+                   Err(ParseStateError::Name));
+        assert_eq!("xoshiro256++/v1:00".parse::<AnyXoshiro>(),
+                   Err(ParseStateError::Length));
+    }
+}
@@ -0,0 +1,90 @@
+use rand_core::RngCore;
+
+/// The threshold representing a probability of one, which cannot be
+/// represented by a comparison with a `u64`.
+const ALWAYS_TRUE: u64 = !0;
+
+/// The number of distinct `u64` values, 2^64.
+const SCALE: f64 = 2.0 * (1u64 << 63) as f64;
+
+/// A sampler of booleans that are `true` with a fixed probability.
+///
+/// The probability is converted once to a threshold, so each sample is a
+/// single comparison of `next_u64()`. The probability is rounded down to a
+/// multiple of 2^-64, except that a probability of one is exact.
+///
+/// ```
+/// # extern crate xoshiro;
+/// # fn main() {
+/// use xoshiro::{Bernoulli, Xoshiro256PlusPlus};
+///
+/// let mut rng = Xoshiro256PlusPlus::from_seed_u64(1);
+/// let dropout = Bernoulli::new(0.1);
+/// let kept = (0..1000).filter(|_| !dropout.sample(&mut rng)).count();
+/// # assert!(kept > 850 && kept < 950);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bernoulli {
+    threshold: u64,
+}
+
+impl Bernoulli {
+    /// Create a sampler returning `true` with probability `p`.
+    ///
+    /// # Panics
+    ///
+    /// If `p` is not in [0, 1].
+    pub fn new(p: f64) -> Bernoulli {
+        assert!((0.0..=1.0).contains(&p), "probability must be in [0, 1]");
+        let threshold = if p == 1.0 { ALWAYS_TRUE } else { (p * SCALE) as u64 };
+        Bernoulli { threshold }
+    }
+
+    /// Return `true` with the probability of the sampler, generated from
+    /// `rng`.
+    #[inline]
+    pub fn sample<R: RngCore + ?Sized>(&self, rng: &mut R) -> bool {
+        // A probability of one still consumes an output, so that the stream
+        // does not depend on the probability.
+        let x = rng.next_u64();
+        self.threshold == ALWAYS_TRUE || x < self.threshold
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Xoshiro256Plus;
+
+    #[test]
+    fn extremes() {
+        let mut rng = Xoshiro256Plus::from_seed_u64(1);
+        let never = Bernoulli::new(0.0);
+        let always = Bernoulli::new(1.0);
+        for _ in 0..1000 {
+            assert!(!never.sample(&mut rng));
+            assert!(always.sample(&mut rng));
+        }
+        assert_eq!(Bernoulli::new(0.5).threshold, 1 << 63);
+    }
+
+    #[test]
+    fn frequency() {
+        let mut rng = Xoshiro256Plus::from_seed_u64(1);
+        let n = (0..10000).filter(|_| rng.next_bool_with_prob(0.3)).count();
+        assert!(n > 2800 && n < 3200);
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid() {
+        Bernoulli::new(1.5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn nan() {
+        Bernoulli::new(f64::NAN);
+    }
+}
@@ -0,0 +1,51 @@
+//! Run a TestU01 battery on a generator:
+//!
+//! ```text
+//! cargo run --release --features testu01 --bin crush -- xoshiro256starstar big 42
+//! ```
+//!
+//! The battery is one of `small`, `crush` and `big`. The report is printed to
+//! stdout.
+
+extern crate rand_core;
+extern crate xoshiro;
+
+use std::env;
+use std::process;
+
+use xoshiro::testu01::{run, Battery};
+
+mod generators;
+
+use generators::{generator, GENERATORS};
+
+fn usage() -> ! {
+    eprintln!("usage: crush <generator> <small|crush|big> [seed]");
+    eprintln!("generators: {}", GENERATORS.join(", "));
+    process::exit(2);
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.len() < 2 || args.len() > 3 {
+        usage();
+    }
+    let battery = match &*args[1] {
+        "small" => Battery::SmallCrush,
+        "crush" => Battery::Crush,
+        "big" => Battery::BigCrush,
+        _ => usage(),
+    };
+    let seed = match args.get(2) {
+        None => 1,
+        Some(s) => s.parse().unwrap_or_else(|_| {
+            eprintln!("invalid seed: {}", s);
+            usage()
+        }),
+    };
+    let rng = generator(&args[0], seed).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        usage()
+    });
+    run(&args[0], rng, battery);
+}
@@ -0,0 +1,39 @@
+//! Generator selection by name, shared by the binaries.
+
+use rand_core::RngCore;
+use xoshiro::{SplitMix64, Xoroshiro64Star, Xoroshiro64StarStar,
+              Xoroshiro128Plus, Xoroshiro128PlusPlus, Xoroshiro128StarStar,
+              Xoshiro128Plus, Xoshiro128PlusPlus, Xoshiro128StarStar,
+              Xoshiro256Plus, Xoshiro256PlusPlus, Xoshiro256StarStar,
+              Xoshiro512Plus, Xoshiro512PlusPlus, Xoshiro512StarStar};
+
+pub const GENERATORS: [&str; 15] = [
+    "splitmix64",
+    "xoroshiro64star", "xoroshiro64starstar",
+    "xoroshiro128plus", "xoroshiro128plusplus", "xoroshiro128starstar",
+    "xoshiro128plus", "xoshiro128plusplus", "xoshiro128starstar",
+    "xoshiro256plus", "xoshiro256plusplus", "xoshiro256starstar",
+    "xoshiro512plus", "xoshiro512plusplus", "xoshiro512starstar",
+];
+
+/// Create the generator called `name`, or return an error message.
+pub fn generator(name: &str, seed: u64) -> Result<Box<dyn RngCore>, String> {
+    Ok(match name {
+        "splitmix64" => Box::new(SplitMix64::from_seed_u64(seed)),
+        "xoroshiro64star" => Box::new(Xoroshiro64Star::from_seed_u64(seed)),
+        "xoroshiro64starstar" => Box::new(Xoroshiro64StarStar::from_seed_u64(seed)),
+        "xoroshiro128plus" => Box::new(Xoroshiro128Plus::from_seed_u64(seed)),
+        "xoroshiro128plusplus" => Box::new(Xoroshiro128PlusPlus::from_seed_u64(seed)),
+        "xoroshiro128starstar" => Box::new(Xoroshiro128StarStar::from_seed_u64(seed)),
+        "xoshiro128plus" => Box::new(Xoshiro128Plus::from_seed_u64(seed)),
+        "xoshiro128plusplus" => Box::new(Xoshiro128PlusPlus::from_seed_u64(seed)),
+        "xoshiro128starstar" => Box::new(Xoshiro128StarStar::from_seed_u64(seed)),
+        "xoshiro256plus" => Box::new(Xoshiro256Plus::from_seed_u64(seed)),
+        "xoshiro256plusplus" => Box::new(Xoshiro256PlusPlus::from_seed_u64(seed)),
+        "xoshiro256starstar" => Box::new(Xoshiro256StarStar::from_seed_u64(seed)),
+        "xoshiro512plus" => Box::new(Xoshiro512Plus::from_seed_u64(seed)),
+        "xoshiro512plusplus" => Box::new(Xoshiro512PlusPlus::from_seed_u64(seed)),
+        "xoshiro512starstar" => Box::new(Xoshiro512StarStar::from_seed_u64(seed)),
+        _ => return Err(format!("unknown generator: {}", name)),
+    })
+}
@@ -0,0 +1,59 @@
+//! Write the raw output of a generator to stdout, for piping into PractRand:
+//!
+//! ```text
+//! cargo run --release --bin stream -- xoshiro256starstar 42 | RNG_test stdin64
+//! ```
+//!
+//! The bytes are the little-endian outputs of `next_u64()` for the 64-bit
+//! generators (use `stdin64`) and of `next_u32()` for the 32-bit generators
+//! (use `stdin32`). The stream is infinite; the tool exits when the reader
+//! closes the pipe.
+
+extern crate rand_core;
+extern crate xoshiro;
+
+use std::env;
+use std::io::{self, Write};
+use std::process;
+
+mod generators;
+
+use generators::{generator, GENERATORS};
+
+fn usage() -> ! {
+    eprintln!("usage: stream <generator> [seed]");
+    eprintln!("generators: {}", GENERATORS.join(", "));
+    process::exit(2);
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.is_empty() || args.len() > 2 {
+        usage();
+    }
+    let seed = match args.get(1) {
+        None => 1,
+        Some(s) => s.parse().unwrap_or_else(|_| {
+            eprintln!("invalid seed: {}", s);
+            usage()
+        }),
+    };
+    let mut rng = generator(&args[0], seed).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        usage()
+    });
+
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    let mut buf = vec![0u8; 1 << 16];
+    loop {
+        rng.fill_bytes(&mut buf);
+        if let Err(e) = out.write_all(&buf) {
+            if e.kind() == io::ErrorKind::BrokenPipe {
+                return;
+            }
+            eprintln!("error writing to stdout: {}", e);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use rand_core::{RngCore, Error};
+
+/// A wrapper serving single random bits and small integers from buffered
+/// outputs.
+///
+/// Drawing a boolean with `next_u64()` wastes 63 of the 64 generated bits.
+/// This wrapper keeps the current output of the inner generator and serves
+/// `next_bool()`, `next_u8()`, `next_u16()` and `gen_bits()` from its bits,
+/// starting with the most significant one, so no generated bits are wasted.
+///
+/// Note that this also uses the lowest bits of the `+` generators, which have
+/// a low linear complexity.
+///
+/// `next_u32()`, `next_u64()` and `fill_bytes()` are forwarded to the inner
+/// generator and leave the buffered bits untouched.
+///
+/// ```
+/// # extern crate xoshiro;
+/// # fn main() {
+/// use xoshiro::{BitBuffer, Xoshiro256StarStar};
+///
+/// let mut rng = BitBuffer::new(Xoshiro256StarStar::from_seed_u64(0));
+/// let heads = (0..100).filter(|_| rng.next_bool()).count();
+/// # assert!(heads <= 100);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitBuffer<R> {
+    rng: R,
+    bits: u64,
+    len: u32,
+}
+
+impl<R: RngCore> BitBuffer<R> {
+    /// Wrap a generator.
+    pub fn new(rng: R) -> BitBuffer<R> {
+        BitBuffer { rng, bits: 0, len: 0 }
+    }
+
+    /// Return a random boolean.
+    #[inline]
+    pub fn next_bool(&mut self) -> bool {
+        self.gen_bits(1) != 0
+    }
+
+    /// Return a random `u8`.
+    #[inline]
+    pub fn next_u8(&mut self) -> u8 {
+        self.gen_bits(8) as u8
+    }
+
+    /// Return a random `u16`.
+    #[inline]
+    pub fn next_u16(&mut self) -> u16 {
+        self.gen_bits(16) as u16
+    }
+
+    /// Return a uniformly random integer of `n` bits.
+    ///
+    /// The bits are taken from the buffer, and only as many outputs of the
+    /// inner generator are consumed as needed. The first bit taken is the
+    /// most significant one of the result. This panics if `n` is greater
+    /// than 64.
+    #[inline]
+    pub fn gen_bits(&mut self, n: u32) -> u64 {
+        assert!(n <= 64, "BitBuffer::gen_bits called with n = {}", n);
+        if n > self.len {
+            // Use the remaining bits as the upper part of the result.
+            let m = n - self.len;
+            let hi = take(&mut self.bits, self.len);
+            self.bits = self.rng.next_u64();
+            self.len = 64 - m;
+            return hi.checked_shl(m).unwrap_or(0) | take(&mut self.bits, m);
+        }
+        self.len -= n;
+        take(&mut self.bits, n)
+    }
+
+    /// Return the inner generator, discarding the buffered bits.
+    pub fn into_inner(self) -> R {
+        self.rng
+    }
+}
+
+/// Remove the upper `n` bits from `bits` and return them.
+#[inline]
+fn take(bits: &mut u64, n: u32) -> u64 {
+    let r = bits.checked_shr(64 - n).unwrap_or(0);
+    *bits = bits.checked_shl(n).unwrap_or(0);
+    r
+}
+
+impl<R: RngCore> RngCore for BitBuffer<R> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest);
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Xoshiro256StarStar;
+
+    #[test]
+    fn bools() {
+        let mut rng = Xoshiro256StarStar::from_seed_u64(0);
+        let mut buffered = BitBuffer::new(rng.clone());
+        for _ in 0..3 {
+            let x = rng.next_u64();
+            for i in (0..64).rev() {
+                assert_eq!(buffered.next_bool(), (x >> i) & 1 != 0);
+            }
+        }
+    }
+
+    #[test]
+    fn gen_bits() {
+        let mut rng = Xoshiro256StarStar::from_seed_u64(0);
+        let mut buffered = BitBuffer::new(rng.clone());
+        let x = rng.next_u64();
+        let y = rng.next_u64();
+        let z = rng.next_u64();
+        assert_eq!(buffered.gen_bits(0), 0);
+        assert_eq!(buffered.gen_bits(5), x >> 59);
+        assert_eq!(buffered.gen_bits(64), x << 5 | y >> 59);
+        assert_eq!(buffered.gen_bits(59), y & ((1 << 59) - 1));
+        assert_eq!(buffered.gen_bits(64), z);
+        assert_eq!(buffered.gen_bits(0), 0);
+        assert_eq!(buffered.next_bool(), rng.next_u64() >> 63 != 0);
+    }
+
+    #[test]
+    fn small_ints() {
+        let mut rng = Xoshiro256StarStar::from_seed_u64(0);
+        let mut buffered = BitBuffer::new(rng.clone());
+        let x = rng.next_u64();
+        assert_eq!(buffered.next_u8(), (x >> 56) as u8);
+        assert_eq!(buffered.next_u16(), (x >> 40) as u16);
+        for i in (0..5).rev() {
+            assert_eq!(buffered.next_u8(), (x >> (8 * i)) as u8);
+        }
+        let y = rng.next_u64();
+        for i in (0..4).rev() {
+            assert_eq!(buffered.next_u16(), (y >> (16 * i)) as u16);
+        }
+    }
+}
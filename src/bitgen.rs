@@ -0,0 +1,93 @@
+use std::os::raw::c_void;
+
+use common::u64_to_f64;
+use rng::XoshiroRng;
+
+/// The function table of a NumPy bit generator.
+///
+/// This has the layout of `bitgen_t` in `numpy/random/bitgen.h`, through
+/// which `numpy.random.Generator` draws from a custom bit generator. Each
+/// function is called with `state`:
+///
+/// * `next_uint64` and `next_uint32` return `next_u64()` and `next_u32()`,
+/// * `next_double` returns `next_f64()`, a float in [0, 1) generated from the
+///   upper 53 bits of `next_u64()`,
+/// * `next_raw` returns the native output, zero-extended for the generators
+///   with 32-bit output.
+///
+/// The table does not own the generator, which must outlive any use of it.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct BitGen {
+    pub state: *mut c_void,
+    pub next_uint64: unsafe extern "C" fn(*mut c_void) -> u64,
+    pub next_uint32: unsafe extern "C" fn(*mut c_void) -> u32,
+    pub next_double: unsafe extern "C" fn(*mut c_void) -> f64,
+    pub next_raw: unsafe extern "C" fn(*mut c_void) -> u64,
+}
+
+impl BitGen {
+    /// Create the function table for the generator at `rng`.
+    pub(crate) fn new<R: XoshiroRng>(rng: *mut R) -> BitGen {
+        BitGen {
+            state: rng as *mut c_void,
+            next_uint64: next_uint64::<R>,
+            next_uint32: next_uint32::<R>,
+            next_double: next_double::<R>,
+            next_raw: next_raw::<R>,
+        }
+    }
+}
+
+unsafe extern "C" fn next_uint64<R: XoshiroRng>(state: *mut c_void) -> u64 {
+    (*(state as *mut R)).next_u64()
+}
+
+unsafe extern "C" fn next_uint32<R: XoshiroRng>(state: *mut c_void) -> u32 {
+    (*(state as *mut R)).next_u32()
+}
+
+unsafe extern "C" fn next_double<R: XoshiroRng>(state: *mut c_void) -> f64 {
+    u64_to_f64((*(state as *mut R)).next_u64())
+}
+
+unsafe extern "C" fn next_raw<R: XoshiroRng>(state: *mut c_void) -> u64 {
+    let rng = &mut *(state as *mut R);
+    if R::OUTPUT_BITS == 32 {
+        u64::from(rng.next_u32())
+    } else {
+        rng.next_u64()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::RngCore;
+    use super::*;
+    use {Xoroshiro64StarStar, Xoshiro256PlusPlus};
+
+    #[test]
+    fn table() {
+        let mut rng = Xoshiro256PlusPlus::from_seed_u64(3);
+        let mut expected = rng.clone();
+        let bitgen = BitGen::new(&mut rng);
+        let state = bitgen.state;
+        unsafe {
+            assert_eq!((bitgen.next_uint64)(state), expected.next_u64());
+            assert_eq!((bitgen.next_uint32)(state), expected.next_u32());
+            assert_eq!((bitgen.next_double)(state), expected.next_f64());
+            assert_eq!((bitgen.next_raw)(state), expected.next_u64());
+        }
+        assert_eq!(rng, expected);
+
+        let mut rng = Xoroshiro64StarStar::from_seed_u64(3);
+        let mut expected = rng.clone();
+        let bitgen = BitGen::new(&mut rng);
+        let state = bitgen.state;
+        unsafe {
+            assert_eq!((bitgen.next_raw)(state),
+                       u64::from(expected.next_u32()));
+            assert_eq!((bitgen.next_uint64)(state), expected.next_u64());
+        }
+    }
+}
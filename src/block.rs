@@ -0,0 +1,136 @@
+//! Block-based variants of the generators.
+//!
+//! The types in this module implement `rand_core::block::BlockRngCore` and
+//! generate 64 bytes per round. They are meant to be wrapped in
+//! `rand_core::block::BlockRng` (32-bit generators) or
+//! `rand_core::block::BlockRng64` (64-bit generators), which buffer the
+//! results. This allows using them with `rand::rngs::adapter::ReseedingRng`.
+//!
+//! The block variants produce the same `next_u64()`, respectively
+//! `next_u32()`, sequence as the underlying generators.
+
+use rand_core::block::BlockRngCore;
+use rand_core::{RngCore, SeedableRng};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "xoroshiro64")]
+use {Xoroshiro64Star, Xoroshiro64StarStar};
+#[cfg(feature = "xoroshiro128")]
+use {Xoroshiro128Plus, Xoroshiro128StarStar};
+#[cfg(feature = "xoshiro128")]
+use {Xoshiro128Plus, Xoshiro128PlusPlus, Xoshiro128StarStar};
+#[cfg(feature = "xoshiro256")]
+use {Xoshiro256Plus, Xoshiro256PlusPlus, Xoshiro256StarStar};
+#[cfg(feature = "xoshiro512")]
+use {Xoshiro512Plus, Xoshiro512PlusPlus, Xoshiro512StarStar};
+
+/// Implement a block variant of a generator.
+macro_rules! impl_block {
+    ($name:ident, $rng:ident, $wrapper:ident, $item:ty, $next:ident,
+     $results:ty) => {
+        #[doc = concat!("A block variant of `", stringify!($rng),
+                        "`, to be wrapped in `", stringify!($wrapper), "`.")]
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        pub struct $name {
+            rng: $rng,
+        }
+
+        impl BlockRngCore for $name {
+            type Item = $item;
+            type Results = $results;
+
+            #[inline]
+            fn generate(&mut self, results: &mut $results) {
+                for x in results.iter_mut() {
+                    *x = self.rng.$next();
+                }
+            }
+        }
+
+        impl SeedableRng for $name {
+            type Seed = <$rng as SeedableRng>::Seed;
+
+            #[inline]
+            fn from_seed(seed: Self::Seed) -> $name {
+                $name { rng: $rng::from_seed(seed) }
+            }
+        }
+
+        impl From<$rng> for $name {
+            fn from(rng: $rng) -> $name {
+                $name { rng }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "xoroshiro64")]
+impl_block!(Xoroshiro64StarBlock, Xoroshiro64Star,
+            BlockRng, u32, next_u32, [u32; 16]);
+#[cfg(feature = "xoroshiro64")]
+impl_block!(Xoroshiro64StarStarBlock, Xoroshiro64StarStar,
+            BlockRng, u32, next_u32, [u32; 16]);
+#[cfg(feature = "xoshiro128")]
+impl_block!(Xoshiro128PlusBlock, Xoshiro128Plus,
+            BlockRng, u32, next_u32, [u32; 16]);
+#[cfg(feature = "xoshiro128")]
+impl_block!(Xoshiro128PlusPlusBlock, Xoshiro128PlusPlus,
+            BlockRng, u32, next_u32, [u32; 16]);
+#[cfg(feature = "xoshiro128")]
+impl_block!(Xoshiro128StarStarBlock, Xoshiro128StarStar,
+            BlockRng, u32, next_u32, [u32; 16]);
+#[cfg(feature = "xoroshiro128")]
+impl_block!(Xoroshiro128PlusBlock, Xoroshiro128Plus,
+            BlockRng64, u64, next_u64, [u64; 8]);
+#[cfg(feature = "xoroshiro128")]
+impl_block!(Xoroshiro128StarStarBlock, Xoroshiro128StarStar,
+            BlockRng64, u64, next_u64, [u64; 8]);
+#[cfg(feature = "xoshiro256")]
+impl_block!(Xoshiro256PlusBlock, Xoshiro256Plus,
+            BlockRng64, u64, next_u64, [u64; 8]);
+#[cfg(feature = "xoshiro256")]
+impl_block!(Xoshiro256PlusPlusBlock, Xoshiro256PlusPlus,
+            BlockRng64, u64, next_u64, [u64; 8]);
+#[cfg(feature = "xoshiro256")]
+impl_block!(Xoshiro256StarStarBlock, Xoshiro256StarStar,
+            BlockRng64, u64, next_u64, [u64; 8]);
+#[cfg(feature = "xoshiro512")]
+impl_block!(Xoshiro512PlusBlock, Xoshiro512Plus,
+            BlockRng64, u64, next_u64, [u64; 8]);
+#[cfg(feature = "xoshiro512")]
+impl_block!(Xoshiro512PlusPlusBlock, Xoshiro512PlusPlus,
+            BlockRng64, u64, next_u64, [u64; 8]);
+#[cfg(feature = "xoshiro512")]
+impl_block!(Xoshiro512StarStarBlock, Xoshiro512StarStar,
+            BlockRng64, u64, next_u64, [u64; 8]);
+
+#[cfg(test)]
+mod tests {
+    use rand_core::block::{BlockRng, BlockRng64};
+    use super::*;
+
+    #[test]
+    fn block64() {
+        let mut rng = Xoshiro256StarStar::from_seed_u64(0);
+        let mut block = BlockRng64::new(Xoshiro256StarStarBlock::from(rng.clone()));
+        for _ in 0..20 {
+            assert_eq!(block.next_u64(), rng.next_u64());
+        }
+        let mut a = [0; 200];
+        let mut b = [0; 200];
+        block.fill_bytes(&mut a);
+        rng.fill_bytes(&mut b);
+        assert_eq!(a[..], b[..]);
+    }
+
+    #[test]
+    fn block32() {
+        let mut rng = Xoshiro128StarStar::from_seed_u64(0);
+        let mut block = BlockRng::new(Xoshiro128StarStarBlock::from(rng.clone()));
+        for _ in 0..40 {
+            assert_eq!(block.next_u32(), rng.next_u32());
+        }
+    }
+}
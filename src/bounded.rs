@@ -0,0 +1,84 @@
+use rand_core::RngCore;
+
+/// A sampler of uniformly distributed `u64` below a fixed bound.
+///
+/// This produces the same values as `next_u64_below()`, but computes the
+/// rejection threshold of Lemire's method once in `new()` instead of on every
+/// call that might reject. This pays off in loops sampling many values with
+/// the same bound.
+///
+/// ```
+/// # extern crate xoshiro;
+/// # fn main() {
+/// use xoshiro::{Bounded, Xoshiro256PlusPlus};
+///
+/// let mut rng = Xoshiro256PlusPlus::from_seed_u64(1);
+/// let index = Bounded::new(1000);
+/// let v: Vec<u64> = (0..100).map(|_| index.sample(&mut rng)).collect();
+/// # assert!(v.iter().all(|&x| x < 1000));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounded {
+    bound: u64,
+    threshold: u64,
+}
+
+impl Bounded {
+    /// Create a sampler of values in [0, `bound`).
+    ///
+    /// # Panics
+    ///
+    /// If `bound` is zero.
+    pub fn new(bound: u64) -> Bounded {
+        assert!(bound != 0, "bound must be nonzero");
+        Bounded {
+            bound,
+            threshold: bound.wrapping_neg() % bound,
+        }
+    }
+
+    /// Return the exclusive upper bound of the values.
+    #[inline]
+    pub fn bound(&self) -> u64 {
+        self.bound
+    }
+
+    /// Return a uniformly distributed value in [0, `bound`), generated from
+    /// `rng`.
+    #[inline]
+    pub fn sample<R: RngCore + ?Sized>(&self, rng: &mut R) -> u64 {
+        loop {
+            let m = u128::from(rng.next_u64()) * u128::from(self.bound);
+            if m as u64 >= self.threshold {
+                return (m >> 64) as u64;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Xoshiro256Plus;
+
+    #[test]
+    fn same_as_below() {
+        let mut rng1 = Xoshiro256Plus::from_seed_u64(1);
+        let mut rng2 = rng1.clone();
+        for &bound in &[1, 2, 3, 6, 1000, (1 << 63) + 1, !0] {
+            let b = Bounded::new(bound);
+            assert_eq!(b.bound(), bound);
+            for _ in 0..100 {
+                assert_eq!(b.sample(&mut rng1), rng2.next_u64_below(bound));
+            }
+        }
+        assert_eq!(rng1, rng2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero() {
+        Bounded::new(0);
+    }
+}
@@ -0,0 +1,78 @@
+use rand_core::{RngCore, Error};
+
+/// A wrapper returning both halves of each `u64` as `u32` outputs.
+///
+/// The 64-bit generators discard half of their output in `next_u32()`. This
+/// wrapper caches the upper half and returns it on the next call, doubling
+/// the throughput for consumers of `u32` values. The low half is returned
+/// first, so the sequence of `u32` values corresponds to the little-endian
+/// byte stream of the inner generator.
+///
+/// Note that this exposes the lowest bits of the `+` generators, which have
+/// a low linear complexity, in the `u32` outputs.
+///
+/// `next_u64()` and `fill_bytes()` are forwarded to the inner generator and
+/// leave a cached half untouched.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Buffered32<R> {
+    rng: R,
+    half: Option<u32>,
+}
+
+impl<R: RngCore> Buffered32<R> {
+    /// Wrap a generator.
+    pub fn new(rng: R) -> Buffered32<R> {
+        Buffered32 { rng, half: None }
+    }
+
+    /// Return the inner generator, discarding a cached half.
+    pub fn into_inner(self) -> R {
+        self.rng
+    }
+}
+
+impl<R: RngCore> RngCore for Buffered32<R> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        match self.half.take() {
+            Some(x) => x,
+            None => {
+                let x = self.rng.next_u64();
+                self.half = Some((x >> 32) as u32);
+                x as u32
+            }
+        }
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest);
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Xoshiro256StarStar;
+
+    #[test]
+    fn halves() {
+        let mut rng = Xoshiro256StarStar::from_seed_u64(0);
+        let mut buffered = Buffered32::new(rng.clone());
+        for _ in 0..10 {
+            let x = rng.next_u64();
+            assert_eq!(buffered.next_u32(), x as u32);
+            assert_eq!(buffered.next_u32(), (x >> 32) as u32);
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use std::error::Error;
+use std::fmt;
+
+use {AnyXoshiro, Buffered32};
+
+/// The source of the seed of a `XoshiroBuilder`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Seed {
+    U64(u64),
+    Bytes(Vec<u8>),
+    #[cfg(feature = "getrandom")]
+    Entropy,
+}
+
+/// A builder for generators selected at runtime.
+///
+/// The generator is selected by name like in `AnyXoshiro`. It is seeded with
+/// `from_seed_u64(0)` unless another seed is configured, and is then jumped
+/// to the configured stream.
+///
+/// ```
+/// # extern crate rand_core;
+/// # extern crate xoshiro;
+/// # fn main() {
+/// use rand_core::RngCore;
+/// use xoshiro::XoshiroBuilder;
+///
+/// let mut rng = XoshiroBuilder::new("xoshiro256**")
+///     .seed_str("run-2024-42")
+///     .stream(3)
+///     .build()
+///     .unwrap();
+/// let x = rng.next_u64();
+/// # let _ = x;
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct XoshiroBuilder {
+    name: String,
+    seed: Seed,
+    stream: u64,
+}
+
+impl XoshiroBuilder {
+    /// Start building the generator called `name`.
+    pub fn new(name: &str) -> XoshiroBuilder {
+        XoshiroBuilder { name: name.to_string(), seed: Seed::U64(0), stream: 0 }
+    }
+
+    /// Seed the generator with `from_seed_u64(seed)`.
+    pub fn seed_u64(mut self, seed: u64) -> XoshiroBuilder {
+        self.seed = Seed::U64(seed);
+        self
+    }
+
+    /// Seed the generator with `from_seed_bytes(bytes)`.
+    pub fn seed_bytes(mut self, bytes: &[u8]) -> XoshiroBuilder {
+        self.seed = Seed::Bytes(bytes.to_vec());
+        self
+    }
+
+    /// Seed the generator with `from_seed_str(s)`.
+    pub fn seed_str(self, s: &str) -> XoshiroBuilder {
+        self.seed_bytes(s.as_bytes())
+    }
+
+    /// Seed the generator from the operating system's random number source.
+    ///
+    /// Building then panics if the operating system fails to provide
+    /// randomness.
+    #[cfg(feature = "getrandom")]
+    pub fn entropy(mut self) -> XoshiroBuilder {
+        self.seed = Seed::Entropy;
+        self
+    }
+
+    /// Select the `i`-th subsequence, by jumping the seeded generator `i`
+    /// times with `jump_n(i)`.
+    ///
+    /// `SplitMix64` only supports stream 0.
+    pub fn stream(mut self, i: u64) -> XoshiroBuilder {
+        self.stream = i;
+        self
+    }
+
+    /// Build the generator.
+    pub fn build(&self) -> Result<AnyXoshiro, BuildError> {
+        let mut rng = match self.seed {
+            Seed::U64(seed) => AnyXoshiro::from_seed_u64(&self.name, seed),
+            Seed::Bytes(ref bytes) => {
+                AnyXoshiro::from_seed_bytes(&self.name, bytes)
+            }
+            #[cfg(feature = "getrandom")]
+            Seed::Entropy => AnyXoshiro::from_entropy(&self.name),
+        }.map_err(|_| BuildError::UnknownGenerator)?;
+        if !rng.jump_stream(self.stream) {
+            return Err(BuildError::NotJumpable);
+        }
+        Ok(rng)
+    }
+
+    /// Build the generator, wrapped in `Buffered32` to return both halves of
+    /// each `u64` from `next_u32()`.
+    pub fn build_buffered(&self)
+        -> Result<Buffered32<AnyXoshiro>, BuildError>
+    {
+        self.build().map(Buffered32::new)
+    }
+}
+
+/// An error returned by `XoshiroBuilder::build()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BuildError {
+    /// There is no generator with the given name.
+    UnknownGenerator,
+    /// A stream other than 0 was selected for a generator without jumps.
+    NotJumpable,
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            BuildError::UnknownGenerator => "unknown generator",
+            BuildError::NotJumpable => "generator does not support streams",
+        })
+    }
+}
+
+impl Error for BuildError {}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::RngCore;
+
+    use super::*;
+    use {Xoroshiro64Star, Xoshiro256StarStar};
+
+    #[test]
+    fn build() {
+        let rng = XoshiroBuilder::new("xoshiro256**").build().unwrap();
+        assert_eq!(rng, AnyXoshiro::Xoshiro256StarStar(
+            Xoshiro256StarStar::from_seed_u64(0)));
+
+        let mut expected = Xoshiro256StarStar::from_seed_str("abc");
+        expected.jump();
+        expected.jump();
+        let rng = XoshiroBuilder::new("xoshiro256**").seed_str("abc")
+            .stream(2).build().unwrap();
+        assert_eq!(rng, AnyXoshiro::Xoshiro256StarStar(expected));
+
+        let rng = XoshiroBuilder::new("xoshiro256**").seed_u64(7)
+            .stream(1 << 40).build().unwrap();
+        assert_eq!(rng, AnyXoshiro::Xoshiro256StarStar(
+            Xoshiro256StarStar::nth_stream(7, 1 << 40)));
+
+        let mut rng = XoshiroBuilder::new("xoroshiro64*").seed_u64(5)
+            .build_buffered().unwrap();
+        let mut expected = Xoroshiro64Star::from_seed_u64(5);
+        let x = expected.next_u64();
+        assert_eq!(rng.next_u32(), x as u32);
+        assert_eq!(rng.next_u32(), (x >> 32) as u32);
+
+        assert!(XoshiroBuilder::new("splitmix64").build().is_ok());
+        assert_eq!(XoshiroBuilder::new("splitmix64").stream(1).build(),
+                   Err(BuildError::NotJumpable));
+        assert_eq!(XoshiroBuilder::new("pcg32").build(),
+                   Err(BuildError::UnknownGenerator));
+    }
+}
@@ -1,10 +1,8 @@
-/// Initialize a RNG from a `u64` seed using `SplitMix64`.
-macro_rules! from_splitmix {
-    ($seed:expr) => { {
-        let mut rng = ::SplitMix64::from_seed_u64($seed);
-        Self::from_rng(&mut rng).unwrap()
-    } }
-}
+use std::mem::MaybeUninit;
+
+use rand_core::RngCore;
+#[cfg(feature = "getrandom")]
+use rand_core::SeedableRng;
 
 /// Apply the ** scrambler used by some RNGs from the xoshiro family.
 macro_rules! starstar_u64 {
@@ -20,122 +18,298 @@ macro_rules! starstar_u32 {
     }
 }
 
-/// Implement a jump function for an RNG from the xoshiro family.
-macro_rules! impl_jump {
-    (u32, $self:expr, [$j0:expr, $j1:expr]) => {
-        const JUMP: [u32; 2] = [$j0, $j1];
-        let mut s0 = 0;
-        let mut s1 = 0;
-        for j in &JUMP {
-            for b in 0..32 {
-                if (j & 1 << b) != 0 {
-                    s0 ^= $self.s0;
-                    s1 ^= $self.s1;
-                }
-                $self.next_u32();
+/// Fill a byte slice with the little-endian bytes of consecutive outputs.
+///
+/// This produces the same bytes as `rand_core::impls::fill_bytes_via_next`,
+/// but writes whole words directly into the destination.
+macro_rules! impl_fill_bytes {
+    (u32, $self:expr, $dest:expr) => {
+        let mut blocks = $dest.chunks_exact_mut(16);
+        for block in &mut blocks {
+            for chunk in block.chunks_exact_mut(4) {
+                chunk.copy_from_slice(&$self.next_u32().to_le_bytes());
             }
         }
-        $self.s0 = s0;
-        $self.s1 = s1;
+        let mut chunks = blocks.into_remainder().chunks_exact_mut(4);
+        for chunk in &mut chunks {
+            chunk.copy_from_slice(&$self.next_u32().to_le_bytes());
+        }
+        let rest = chunks.into_remainder();
+        if !rest.is_empty() {
+            let n = rest.len();
+            rest.copy_from_slice(&$self.next_u32().to_le_bytes()[..n]);
+        }
     };
-    (u64, $self:expr, [$j0:expr, $j1:expr]) => {
-        const JUMP: [u64; 2] = [$j0, $j1];
-        let mut s0 = 0;
-        let mut s1 = 0;
-        for j in &JUMP {
-            for b in 0..64 {
-                if (j & 1 << b) != 0 {
-                    s0 ^= $self.s0;
-                    s1 ^= $self.s1;
-                }
-                $self.next_u64();
+    (u64, $self:expr, $dest:expr) => {
+        let mut blocks = $dest.chunks_exact_mut(32);
+        for block in &mut blocks {
+            for chunk in block.chunks_exact_mut(8) {
+                chunk.copy_from_slice(&$self.next_u64().to_le_bytes());
             }
         }
-        $self.s0 = s0;
-        $self.s1 = s1;
+        let mut chunks = blocks.into_remainder().chunks_exact_mut(8);
+        for chunk in &mut chunks {
+            chunk.copy_from_slice(&$self.next_u64().to_le_bytes());
+        }
+        let rest = chunks.into_remainder();
+        let n = rest.len();
+        if n > 4 {
+            rest.copy_from_slice(&$self.next_u64().to_le_bytes()[..n]);
+        } else if n > 0 {
+            rest.copy_from_slice(&$self.next_u32().to_le_bytes()[..n]);
+        }
     };
-    (u32, $self:expr, [$j0:expr, $j1:expr, $j2:expr, $j3:expr]) => {
-        const JUMP: [u32; 4] = [$j0, $j1, $j2, $j3];
-        let mut s0 = 0;
-        let mut s1 = 0;
-        let mut s2 = 0;
-        let mut s3 = 0;
-        for j in &JUMP {
-            for b in 0..32 {
-                if (j & 1 << b) != 0 {
-                    s0 ^= $self.s[0];
-                    s1 ^= $self.s[1];
-                    s2 ^= $self.s[2];
-                    s3 ^= $self.s[3];
-                }
-                $self.next_u32();
+}
+
+/// Implement the convenience methods shared by all generators, e.g.
+/// `fill_u64()`, `next_u64_below()`, `gen_range()`, `next_f64()` and
+/// `iter_u64()`, inside an inherent `impl` block.
+///
+/// They are built on `RngCore` and a private method `upper_u32()`, which
+/// has to return the upper 32 bits of the next native output, i.e. of
+/// `next_u64()` for generators with 64-bit output. `$example` is the
+/// generator used in the examples.
+macro_rules! impl_convenience {
+    ($example:ident) => {
+        /// Fill `dest` with random `u64`, equivalently to calling
+        /// `next_u64()` for each element.
+        #[inline]
+        pub fn fill_u64(&mut self, dest: &mut [u64]) {
+            for x in dest {
+                *x = self.next_u64();
             }
         }
-        $self.s[0] = s0;
-        $self.s[1] = s1;
-        $self.s[2] = s2;
-        $self.s[3] = s3;
-    };
-    (u64, $self:expr, [$j0:expr, $j1:expr, $j2:expr, $j3:expr]) => {
-        const JUMP: [u64; 4] = [$j0, $j1, $j2, $j3];
-        let mut s0 = 0;
-        let mut s1 = 0;
-        let mut s2 = 0;
-        let mut s3 = 0;
-        for j in &JUMP {
-            for b in 0..64 {
-                if (j & 1 << b) != 0 {
-                    s0 ^= $self.s[0];
-                    s1 ^= $self.s[1];
-                    s2 ^= $self.s[2];
-                    s3 ^= $self.s[3];
-                }
-                $self.next_u64();
+
+        /// Fill `dest` with random `u32`, equivalently to calling
+        /// `next_u32()` for each element.
+        #[inline]
+        pub fn fill_u32(&mut self, dest: &mut [u32]) {
+            for x in dest {
+                *x = self.next_u32();
             }
         }
-        $self.s[0] = s0;
-        $self.s[1] = s1;
-        $self.s[2] = s2;
-        $self.s[3] = s3;
-    };
-    (u64, $self:expr, [$j0:expr, $j1:expr, $j2:expr, $j3:expr,
-                       $j4:expr, $j5:expr, $j6:expr, $j7:expr]) => {
-        const JUMP: [u64; 8] = [$j0, $j1, $j2, $j3, $j4, $j5, $j6, $j7];
-        let mut s = [0; 8];
-        for j in &JUMP {
-            for b in 0..64 {
-                if (j & 1 << b) != 0 {
-                    s[0] ^= $self.s[0];
-                    s[1] ^= $self.s[1];
-                    s[2] ^= $self.s[2];
-                    s[3] ^= $self.s[3];
-                    s[4] ^= $self.s[4];
-                    s[5] ^= $self.s[5];
-                    s[6] ^= $self.s[6];
-                    s[7] ^= $self.s[7];
-                }
-                $self.next_u64();
+
+        /// Return a uniformly distributed `u64` in [0, `bound`), without
+        /// modulo bias.
+        ///
+        /// This uses Lemire's multiply-shift method, which rarely needs more
+        /// than one output and avoids a division in most cases. Use `Bounded`
+        /// to sample many values with the same bound.
+        ///
+        /// # Panics
+        ///
+        /// If `bound` is zero.
+        #[inline]
+        pub fn next_u64_below(&mut self, bound: u64) -> u64 {
+            ::common::u64_below(self, bound)
+        }
+
+        /// Return a uniformly distributed `u32` in [0, `bound`), like
+        /// `next_u64_below()` but using `next_u32()`.
+        ///
+        /// # Panics
+        ///
+        /// If `bound` is zero.
+        #[inline]
+        pub fn next_u32_below(&mut self, bound: u32) -> u32 {
+            ::common::u32_below(self, bound)
+        }
+
+        /// Return `true` with probability `p`.
+        ///
+        /// This is a single comparison of `next_u64()` with a threshold. Use
+        /// `Bernoulli` to avoid recomputing the threshold when sampling many
+        /// values with the same probability.
+        ///
+        /// # Panics
+        ///
+        /// If `p` is not in [0, 1].
+        #[inline]
+        pub fn next_bool_with_prob(&mut self, p: f64) -> bool {
+            ::bernoulli::Bernoulli::new(p).sample(self)
+        }
+
+        /// Return a uniformly distributed integer from `range`, e.g. `1..=6`
+        /// or `0..len`.
+        ///
+        /// The range is sampled without bias like in `next_u64_below()`.
+        ///
+        /// # Panics
+        ///
+        /// If the range is empty.
+        ///
+        /// ```
+        /// # extern crate xoshiro;
+        /// # fn main() {
+        #[doc = concat!(" use xoshiro::", stringify!($example), ";")]
+        ///
+        #[doc = concat!(" let mut rng = ", stringify!($example),
+                        "::from_seed_u64(1);")]
+        /// let die = rng.gen_range(1..=6);
+        /// let index = rng.gen_range(0..10usize);
+        /// # assert!(die >= 1 && die <= 6 && index < 10);
+        /// # }
+        /// ```
+        #[inline]
+        pub fn gen_range<T, R>(&mut self, range: R) -> T
+            where R: ::range::SampleRange<T>
+        {
+            range.sample(self)
+        }
+
+        /// Return a random `f64` in [0, 1), generated from the upper 53 bits
+        /// of `next_u64()`.
+        ///
+        /// Only the upper bits are used, so the weak low bits of the `+`
+        /// scramblers do not affect the result.
+        #[inline]
+        pub fn next_f64(&mut self) -> f64 {
+            ::common::u64_to_f64(self.next_u64())
+        }
+
+        /// Return a random `f64` in the open interval (0, 1).
+        ///
+        /// The result is `(k + 0.5) / 2^52` for the upper 52 bits `k` of
+        /// `next_u64()`. It is never zero, so it is safe to take its
+        /// logarithm.
+        #[inline]
+        pub fn next_f64_open01(&mut self) -> f64 {
+            ::common::u64_to_f64_open01(self.next_u64())
+        }
+
+        /// Return a random `f64` in the closed interval [0, 1].
+        ///
+        /// The result is `k / (2^53 - 1)`, rounded to the nearest `f64`, for
+        /// the upper 53 bits `k` of `next_u64()`.
+        #[inline]
+        pub fn next_f64_closed01(&mut self) -> f64 {
+            ::common::u64_to_f64_closed01(self.next_u64())
+        }
+
+        /// Return a random `f64` in the half-open interval (0, 1].
+        ///
+        /// The result is `(k + 1) / 2^53` for the upper 53 bits `k` of
+        /// `next_u64()`, i.e. the result of `next_f64()` plus `2^-53`.
+        #[inline]
+        pub fn next_f64_open_closed(&mut self) -> f64 {
+            ::common::u64_to_f64_open_closed(self.next_u64())
+        }
+
+        /// Return a random `f32` in [0, 1), generated from the upper 24 bits
+        /// of `next_u64()`, or of `next_u32()` for generators with 32-bit
+        /// output.
+        #[inline]
+        pub fn next_f32(&mut self) -> f32 {
+            ::common::u32_to_f32(self.upper_u32())
+        }
+
+        /// Fill `dest` with random `f64` in [0, 1), generated from the upper
+        /// 53 bits of `next_u64()`.
+        #[inline]
+        pub fn fill_f64(&mut self, dest: &mut [f64]) {
+            for x in dest {
+                *x = self.next_f64();
             }
         }
-        $self.s = s;
-    };
+
+        /// Fill `dest` with random `f32` in [0, 1), generated like
+        /// `next_f32()`.
+        #[inline]
+        pub fn fill_f32(&mut self, dest: &mut [f32]) {
+            for x in dest {
+                *x = self.next_f32();
+            }
+        }
+
+        /// Fill `dest` with random `f16` in [0, 1), generated from the upper
+        /// 11 bits of the outputs, like `next_f32()`.
+        #[cfg(feature = "half")]
+        #[inline]
+        pub fn fill_f16(&mut self, dest: &mut [::half::f16]) {
+            for x in dest {
+                *x = ::common::u32_to_f16(self.upper_u32());
+            }
+        }
+
+        /// Fill `dest` with random `bf16` in [0, 1), generated from the upper
+        /// 8 bits of the outputs, like `next_f32()`.
+        #[cfg(feature = "half")]
+        #[inline]
+        pub fn fill_bf16(&mut self, dest: &mut [::half::bf16]) {
+            for x in dest {
+                *x = ::common::u32_to_bf16(self.upper_u32());
+            }
+        }
+
+        /// Fill an uninitialized buffer with random bytes and return it as
+        /// initialized.
+        ///
+        /// This produces the same bytes as `fill_bytes()`, without requiring
+        /// the buffer to be zeroed first.
+        #[inline]
+        pub fn fill_uninit_bytes<'a>(
+            &mut self,
+            dest: &'a mut [::std::mem::MaybeUninit<u8>],
+        ) -> &'a mut [u8] {
+            ::common::fill_uninit_bytes(self, dest)
+        }
+
+        /// Return an infinite iterator over the outputs of `next_u64()`.
+        ///
+        /// ```
+        /// # extern crate xoshiro;
+        /// # fn main() {
+        #[doc = concat!(" use xoshiro::", stringify!($example), ";")]
+        ///
+        #[doc = concat!(" let mut rng = ", stringify!($example),
+                        "::from_seed_u64(1);")]
+        /// let v: Vec<f64> = rng.iter_f64().take(10).collect();
+        /// # assert_eq!(v.len(), 10);
+        /// # }
+        /// ```
+        #[inline]
+        pub fn iter_u64(&mut self) -> impl Iterator<Item = u64> + '_ {
+            ::std::iter::repeat_with(move || self.next_u64())
+        }
+
+        /// Return an infinite iterator over the outputs of `next_u32()`.
+        #[inline]
+        pub fn iter_u32(&mut self) -> impl Iterator<Item = u32> + '_ {
+            ::std::iter::repeat_with(move || self.next_u32())
+        }
+
+        /// Return an infinite iterator over random `f64` in [0, 1), generated
+        /// like in `fill_f64()`.
+        #[inline]
+        pub fn iter_f64(&mut self) -> impl Iterator<Item = f64> + '_ {
+            ::std::iter::repeat_with(move || self.next_f64())
+        }
+
+        /// Return an infinite iterator over random `f32` in [0, 1), generated
+        /// like in `fill_f32()`.
+        #[inline]
+        pub fn iter_f32(&mut self) -> impl Iterator<Item = f32> + '_ {
+            ::std::iter::repeat_with(move || self.next_f32())
+        }
+    }
 }
 
 /// Implement the xoroshiro iteration.
 macro_rules! impl_xoroshiro_u32 {
     ($self:expr) => {
-        $self.s1 ^= $self.s0;
-        $self.s0 = $self.s0.rotate_left(26) ^ $self.s1 ^ ($self.s1 << 9);
-        $self.s1 = $self.s1.rotate_left(13);
+        $self.s[1] ^= $self.s[0];
+        $self.s[0] = $self.s[0].rotate_left(26) ^ $self.s[1] ^ ($self.s[1] << 9);
+        $self.s[1] = $self.s[1].rotate_left(13);
     }
 }
 
-/// Implement the xoroshiro iteration.
+/// Implement the xoroshiro iteration with the rotations `$a` and `$c` and the
+/// shift `$b`.
 macro_rules! impl_xoroshiro_u64 {
-    ($self:expr) => {
-        $self.s1 ^= $self.s0;
-        $self.s0 = $self.s0.rotate_left(24) ^ $self.s1 ^ ($self.s1 << 16);
-        $self.s1 = $self.s1.rotate_left(37);
+    ($self:expr, $a:expr, $b:expr, $c:expr) => {
+        $self.s[1] ^= $self.s[0];
+        $self.s[0] = $self.s[0].rotate_left($a) ^ $self.s[1] ^ ($self.s[1] << $b);
+        $self.s[1] = $self.s[1].rotate_left($c);
     }
 }
 
@@ -191,16 +365,89 @@ macro_rules! impl_xoshiro_large {
     }
 }
 
+/// Implement the inverse of the xoroshiro iteration.
+macro_rules! impl_xoroshiro_u32_inv {
+    ($self:expr) => {
+        let t = $self.s[1].rotate_right(13);
+        $self.s[0] = ($self.s[0] ^ t ^ (t << 9)).rotate_right(26);
+        $self.s[1] = t ^ $self.s[0];
+    }
+}
+
+/// Implement the inverse of `impl_xoroshiro_u64!` with the same parameters.
+macro_rules! impl_xoroshiro_u64_inv {
+    ($self:expr, $a:expr, $b:expr, $c:expr) => {
+        let t = $self.s[1].rotate_right($c);
+        $self.s[0] = ($self.s[0] ^ t ^ (t << $b)).rotate_right($a);
+        $self.s[1] = t ^ $self.s[0];
+    }
+}
+
+/// Implement the inverse of the xoshiro iteration for `u32` output.
+macro_rules! impl_xoshiro_u32_inv {
+    ($self:expr) => {
+        let t = $self.s[3].rotate_right(11);
+        let u = $self.s[1] ^ $self.s[2];
+        let s1 = u ^ (u << 9) ^ (u << 18) ^ (u << 27);
+        let s0 = $self.s[0] ^ t;
+
+        $self.s[2] = $self.s[1] ^ s1 ^ s0;
+        $self.s[3] = t ^ s1;
+        $self.s[1] = s1;
+        $self.s[0] = s0;
+    }
+}
+
+/// Implement the inverse of the xoshiro iteration for `u64` output.
+macro_rules! impl_xoshiro_u64_inv {
+    ($self:expr) => {
+        let t = $self.s[3].rotate_right(45);
+        let u = $self.s[1] ^ $self.s[2];
+        let s1 = u ^ (u << 17) ^ (u << 34) ^ (u << 51);
+        let s0 = $self.s[0] ^ t;
+
+        $self.s[2] = $self.s[1] ^ s1 ^ s0;
+        $self.s[3] = t ^ s1;
+        $self.s[1] = s1;
+        $self.s[0] = s0;
+    }
+}
+
+/// Implement the inverse of the large-state xoshiro iteration.
+macro_rules! impl_xoshiro_large_inv {
+    ($self:expr) => {
+        let t = $self.s[7].rotate_right(21);
+        let s1 = $self.s[1] ^ $self.s[2];
+        let s4 = $self.s[4] ^ $self.s[5];
+        let s6 = $self.s[6] ^ t ^ (s1 << 11);
+        let s0 = $self.s[0] ^ s6;
+        let s3 = $self.s[3] ^ s4;
+
+        $self.s[2] ^= s0;
+        $self.s[5] ^= s1;
+        $self.s[7] = t ^ s3;
+        $self.s[0] = s0;
+        $self.s[1] = s1;
+        $self.s[3] = s3;
+        $self.s[4] = s4;
+        $self.s[6] = s6;
+    }
+}
+
 /// 512-bit seed for a generator.
 ///
 /// This wrapper is necessary, because some traits required for a seed are not
-/// implemented on large arrays.
-#[derive(Clone)]
+/// implemented on large arrays. It can be created from 64 bytes or from 8
+/// `u64`, which are stored in little-endian order, like the state words.
+///
+/// With the `serde` feature, it is serialized as 64 bytes and can be
+/// deserialized from either 64 bytes or 8 `u64`.
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Seed512(pub [u8; 64]);
 
 impl ::std::fmt::Debug for Seed512 {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-        self.0[..].fmt(f)
+        f.debug_tuple("Seed512").field(&&self.0[..]).finish()
     }
 }
 
@@ -216,3 +463,436 @@ impl AsMut<[u8]> for Seed512 {
     }
 }
 
+impl AsRef<[u8]> for Seed512 {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<[u8; 64]> for Seed512 {
+    fn from(bytes: [u8; 64]) -> Seed512 {
+        Seed512(bytes)
+    }
+}
+
+impl From<[u64; 8]> for Seed512 {
+    fn from(words: [u64; 8]) -> Seed512 {
+        let mut seed = Seed512([0; 64]);
+        write_u64s(&words, &mut seed.0);
+        seed
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl ::zeroize::Zeroize for Seed512 {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Seed512 {
+    fn drop(&mut self) {
+        ::zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl ::zeroize::ZeroizeOnDrop for Seed512 {}
+
+#[cfg(feature = "serde")]
+impl ::serde::Serialize for Seed512 {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S)
+        -> Result<S::Ok, S::Error>
+    {
+        self.0[..].serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> ::serde::Deserialize<'de> for Seed512 {
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D)
+        -> Result<Seed512, D::Error>
+    {
+        use serde::de::{Error, Unexpected};
+
+        let v = Vec::<u64>::deserialize(deserializer)?;
+        match v.len() {
+            8 => {
+                let mut words = [0; 8];
+                words.copy_from_slice(&v);
+                Ok(Seed512::from(words))
+            }
+            64 => {
+                let mut seed = Seed512([0; 64]);
+                for (b, &x) in seed.0.iter_mut().zip(&v) {
+                    if x > 0xff {
+                        return Err(D::Error::invalid_value(
+                            Unexpected::Unsigned(x), &"a byte"));
+                    }
+                    *b = x as u8;
+                }
+                Ok(seed)
+            }
+            n => Err(D::Error::invalid_length(n, &"64 bytes or 8 u64")),
+        }
+    }
+}
+
+/// An error returned by `try_from_seed()` when the seed is entirely zero.
+///
+/// The state of a linear engine must not be entirely zero, since it would
+/// then never change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ZeroSeedError;
+
+impl ::std::fmt::Display for ZeroSeedError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.write_str("seed is entirely zero")
+    }
+}
+
+impl ::std::error::Error for ZeroSeedError {}
+
+/// Read `N` little-endian `u64` from `bytes`, which must hold `8 * N` bytes.
+#[inline]
+pub const fn read_u64s<const N: usize>(bytes: &[u8]) -> [u64; N] {
+    assert!(bytes.len() == 8 * N);
+    let mut words = [0; N];
+    let mut rest = bytes;
+    let mut i = 0;
+    while let Some((chunk, tail)) = rest.split_first_chunk::<8>() {
+        words[i] = u64::from_le_bytes(*chunk);
+        rest = tail;
+        i += 1;
+    }
+    words
+}
+
+/// Read `N` little-endian `u32` from `bytes`, which must hold `4 * N` bytes.
+#[inline]
+pub const fn read_u32s<const N: usize>(bytes: &[u8]) -> [u32; N] {
+    assert!(bytes.len() == 4 * N);
+    let mut words = [0; N];
+    let mut rest = bytes;
+    let mut i = 0;
+    while let Some((chunk, tail)) = rest.split_first_chunk::<4>() {
+        words[i] = u32::from_le_bytes(*chunk);
+        rest = tail;
+        i += 1;
+    }
+    words
+}
+
+/// Write `words` as little-endian bytes to `bytes`, which must hold
+/// `8 * words.len()` bytes.
+#[inline]
+pub fn write_u64s(words: &[u64], bytes: &mut [u8]) {
+    assert_eq!(bytes.len(), 8 * words.len());
+    for (chunk, w) in bytes.chunks_exact_mut(8).zip(words) {
+        chunk.copy_from_slice(&w.to_le_bytes());
+    }
+}
+
+/// Write `words` as little-endian bytes to `bytes`, which must hold
+/// `4 * words.len()` bytes.
+#[inline]
+pub fn write_u32s(words: &[u32], bytes: &mut [u8]) {
+    assert_eq!(bytes.len(), 4 * words.len());
+    for (chunk, w) in bytes.chunks_exact_mut(4).zip(words) {
+        chunk.copy_from_slice(&w.to_le_bytes());
+    }
+}
+
+/// Convert `bytes` holding little-endian words of `size` bytes to native
+/// byte order, or back.
+#[inline]
+pub fn swap_le_native(bytes: &mut [u8], size: usize) {
+    if cfg!(target_endian = "big") {
+        for word in bytes.chunks_exact_mut(size) {
+            word.reverse();
+        }
+    }
+}
+
+/// Seed a generator from the operating system's random number source.
+///
+/// This panics if the operating system fails to provide randomness.
+#[cfg(feature = "getrandom")]
+pub fn from_entropy<R: SeedableRng>() -> R {
+    let mut seed = R::Seed::default();
+    if let Err(e) = ::getrandom::fill(seed.as_mut()) {
+        panic!("from_entropy failed to get randomness: {}", e);
+    }
+    R::from_seed(seed)
+}
+
+/// Fill an uninitialized buffer using `fill_bytes` and return it as
+/// initialized.
+///
+/// The bytes are generated into a stack buffer whose length is a multiple of
+/// the word size, so they are the same as for a single `fill_bytes` call.
+pub fn fill_uninit_bytes<'a, R: RngCore>(rng: &mut R,
+                                         dest: &'a mut [MaybeUninit<u8>])
+    -> &'a mut [u8]
+{
+    let mut buf = [0u8; 256];
+    for chunk in dest.chunks_mut(buf.len()) {
+        let buf = &mut buf[..chunk.len()];
+        rng.fill_bytes(buf);
+        for (d, &b) in chunk.iter_mut().zip(buf.iter()) {
+            *d = MaybeUninit::new(b);
+        }
+    }
+    // Safe, because all bytes were initialized above and `MaybeUninit<u8>`
+    // has the same layout as `u8`.
+    unsafe { &mut *(dest as *mut [MaybeUninit<u8>] as *mut [u8]) }
+}
+
+/// Return a uniformly distributed `u64` in [0, `bound`) using Lemire's
+/// multiply-shift method.
+///
+/// The result is the upper half of the product of `next_u64()` and `bound`.
+/// Products whose lower half falls below `2^64 mod bound` are rejected, which
+/// removes the bias. The division to compute this threshold is only done
+/// when the lower half is small enough that a rejection is possible.
+///
+/// # Panics
+///
+/// If `bound` is zero.
+#[inline]
+pub fn u64_below<R: RngCore + ?Sized>(rng: &mut R, bound: u64) -> u64 {
+    assert!(bound != 0, "bound must be nonzero");
+    let mut m = u128::from(rng.next_u64()) * u128::from(bound);
+    if (m as u64) < bound {
+        let threshold = bound.wrapping_neg() % bound;
+        while (m as u64) < threshold {
+            m = u128::from(rng.next_u64()) * u128::from(bound);
+        }
+    }
+    (m >> 64) as u64
+}
+
+/// Return a uniformly distributed `u32` in [0, `bound`), like `u64_below()`
+/// but using `next_u32()`.
+///
+/// # Panics
+///
+/// If `bound` is zero.
+#[inline]
+pub fn u32_below<R: RngCore + ?Sized>(rng: &mut R, bound: u32) -> u32 {
+    assert!(bound != 0, "bound must be nonzero");
+    let mut m = u64::from(rng.next_u32()) * u64::from(bound);
+    if (m as u32) < bound {
+        let threshold = bound.wrapping_neg() % bound;
+        while (m as u32) < threshold {
+            m = u64::from(rng.next_u32()) * u64::from(bound);
+        }
+    }
+    (m >> 32) as u32
+}
+
+/// Convert a `u64` to a `f64` in [0, 1), using the upper 53 bits.
+#[inline]
+pub fn u64_to_f64(x: u64) -> f64 {
+    (x >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+}
+
+/// Convert a `u64` to a `f64` in (0, 1), using the upper 52 bits.
+///
+/// The results are the midpoints `(k + 0.5) / 2^52`, so both bounds are
+/// excluded exactly.
+#[inline]
+pub fn u64_to_f64_open01(x: u64) -> f64 {
+    ((x >> 12) as f64 + 0.5) * (1.0 / (1u64 << 52) as f64)
+}
+
+/// Convert a `u64` to a `f64` in [0, 1], using the upper 53 bits.
+///
+/// The results are `k / (2^53 - 1)` rounded to the nearest `f64`, so both
+/// bounds are reached.
+#[inline]
+pub fn u64_to_f64_closed01(x: u64) -> f64 {
+    (x >> 11) as f64 * (1.0 / ((1u64 << 53) - 1) as f64)
+}
+
+/// Convert a `u64` to a `f64` in (0, 1], using the upper 53 bits.
+///
+/// The results are `(k + 1) / 2^53`, the values of `u64_to_f64()` shifted
+/// up by one step.
+#[inline]
+pub fn u64_to_f64_open_closed(x: u64) -> f64 {
+    ((x >> 11) + 1) as f64 * (1.0 / (1u64 << 53) as f64)
+}
+
+/// Convert a `u32` to a `f32` in [0, 1), using the upper 24 bits.
+#[inline]
+pub fn u32_to_f32(x: u32) -> f32 {
+    (x >> 8) as f32 * (1.0 / (1u32 << 24) as f32)
+}
+
+/// Convert a `u32` to a `f16` in [0, 1), using the upper 11 bits.
+#[cfg(feature = "half")]
+#[inline]
+pub fn u32_to_f16(x: u32) -> ::half::f16 {
+    // Exact, because `f16` has 11 significant bits.
+    ::half::f16::from_f32((x >> 21) as f32 * (1.0 / (1u32 << 11) as f32))
+}
+
+/// Convert a `u32` to a `bf16` in [0, 1), using the upper 8 bits.
+#[cfg(feature = "half")]
+#[inline]
+pub fn u32_to_bf16(x: u32) -> ::half::bf16 {
+    // Exact, because `bf16` has 8 significant bits.
+    ::half::bf16::from_f32((x >> 24) as f32 * (1.0 / (1u32 << 8) as f32))
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::Error;
+    use rand_core::impls::fill_bytes_via_next;
+    use {Xoroshiro128Plus, Xoshiro128StarStar};
+    use super::*;
+
+    /// A generator returning the given outputs in order.
+    struct Sequence(Vec<u64>);
+
+    impl RngCore for Sequence {
+        fn next_u32(&mut self) -> u32 {
+            self.next_u64() as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.0.remove(0)
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            fill_bytes_via_next(self, dest)
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn below() {
+        // 2^64 mod 3 = 1, so only an output with a lower product half of 0
+        // is rejected.
+        let mut rng = Sequence(vec![0, 1 << 63, !0]);
+        assert_eq!(u64_below(&mut rng, 3), 1);
+        assert_eq!(u64_below(&mut rng, 3), 2);
+        let mut rng = Sequence(vec![0, 1 << 31, 0xffff_ffff]);
+        assert_eq!(u32_below(&mut rng, 3), 1);
+        assert_eq!(u32_below(&mut rng, 3), 2);
+        let mut rng = Sequence(vec![!0, !0]);
+        assert_eq!(u64_below(&mut rng, 1), 0);
+        assert_eq!(u32_below(&mut rng, 1), 0);
+
+        let mut rng = Xoroshiro128Plus::from_seed_u64(1);
+        let mut counts = [0; 6];
+        for _ in 0..6000 {
+            counts[u64_below(&mut rng, 6) as usize] += 1;
+            assert!(u32_below(&mut rng, 1000) < 1000);
+        }
+        for &c in &counts {
+            assert!(c > 800 && c < 1200);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn below_zero() {
+        u64_below(&mut Xoroshiro128Plus::from_seed_u64(1), 0);
+    }
+
+    #[test]
+    fn float_bounds() {
+        assert_eq!(u64_to_f64(0), 0.0);
+        assert!(u64_to_f64(!0) < 1.0);
+        assert!(u64_to_f64_open01(0) > 0.0);
+        assert!(u64_to_f64_open01(!0) < 1.0);
+        assert_eq!(u64_to_f64_closed01(0), 0.0);
+        assert_eq!(u64_to_f64_closed01(!0), 1.0);
+        assert_eq!(u64_to_f64_open_closed(0), 1.0 / (1u64 << 53) as f64);
+        assert_eq!(u64_to_f64_open_closed(!0), 1.0);
+    }
+
+    fn check<R: RngCore + Clone>(rng: R) {
+        for len in 0..100 {
+            let mut rng1 = rng.clone();
+            let mut rng2 = rng.clone();
+            let mut a = vec![0; len];
+            let mut b = vec![0; len];
+            rng1.fill_bytes(&mut a);
+            fill_bytes_via_next(&mut rng2, &mut b);
+            assert_eq!(a, b);
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn seed512() {
+        let mut words = [0; 8];
+        words[0] = 0x0102;
+        words[7] = 1 << 63;
+        let seed = Seed512::from(words);
+        assert_eq!(seed.0[..2], [2, 1]);
+        assert_eq!(seed.0[63], 0x80);
+        assert_eq!(seed, Seed512::from(seed.0));
+        assert!(seed != Seed512::default());
+        assert_eq!(read_u64s::<8>(seed.as_ref()), words);
+        assert!(format!("{:?}", seed).starts_with("Seed512([2, 1, 0,"));
+    }
+
+    #[test]
+    fn fill_bytes() {
+        check(Xoroshiro128Plus::from_seed_u64(0));
+        check(Xoshiro128StarStar::from_seed_u64(0));
+    }
+
+    fn check_uninit<R: RngCore + Clone>(rng: R) {
+        for &len in &[0, 1, 7, 255, 256, 257, 1000] {
+            let mut rng1 = rng.clone();
+            let mut rng2 = rng.clone();
+            let mut a = vec![MaybeUninit::uninit(); len];
+            let mut b = vec![0; len];
+            let a = fill_uninit_bytes(&mut rng1, &mut a);
+            rng2.fill_bytes(&mut b);
+            assert_eq!(*a, b[..]);
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn fill_uninit() {
+        check_uninit(Xoroshiro128Plus::from_seed_u64(0));
+        check_uninit(Xoshiro128StarStar::from_seed_u64(0));
+    }
+
+    #[test]
+    fn floats() {
+        assert_eq!(u64_to_f64(0), 0.0);
+        assert_eq!(u64_to_f64(1 << 11), 1.0 / (1u64 << 53) as f64);
+        assert_eq!(u64_to_f64(u64::MAX),
+                   1.0 - 1.0 / (1u64 << 53) as f64);
+        assert_eq!(u32_to_f32(0), 0.0);
+        assert_eq!(u32_to_f32(1 << 8), 1.0 / (1u32 << 24) as f32);
+        assert_eq!(u32_to_f32(u32::MAX),
+                   1.0 - 1.0 / (1u32 << 24) as f32);
+    }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn half_floats() {
+        assert_eq!(u32_to_f16(0).to_f32(), 0.0);
+        assert_eq!(u32_to_f16(1 << 21).to_f32(), 1.0 / (1u32 << 11) as f32);
+        assert_eq!(u32_to_f16(u32::MAX).to_f32(),
+                   1.0 - 1.0 / (1u32 << 11) as f32);
+        assert_eq!(u32_to_bf16(0).to_f32(), 0.0);
+        assert_eq!(u32_to_bf16(1 << 24).to_f32(), 1.0 / (1u32 << 8) as f32);
+        assert_eq!(u32_to_bf16(u32::MAX).to_f32(),
+                   1.0 - 1.0 / (1u32 << 8) as f32);
+    }
+}
@@ -0,0 +1,12 @@
+//! Support for newer versions of `rand_core`.
+//!
+//! The crate implements the traits of `rand_core` 0.2. With the
+//! `rand_core_06` and `rand_core_09` features, the generators additionally
+//! implement `RngCore` and `SeedableRng` of `rand_core` 0.6 and 0.9. The
+//! output is the same for all versions, and `seed_from_u64` is overridden to
+//! agree with `from_seed_u64`.
+
+#[cfg(feature = "rand_core_06")]
+mod v06;
+#[cfg(feature = "rand_core_09")]
+mod v09;
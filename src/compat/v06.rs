@@ -0,0 +1,98 @@
+use rand_core_06 as rc;
+
+use {SplitMix64, Xoshiro, XoshiroRng};
+
+impl<C, S> rc::RngCore for Xoshiro<C, S> where Xoshiro<C, S>: XoshiroRng {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        ::rand_core::RngCore::next_u32(self)
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        ::rand_core::RngCore::next_u64(self)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        ::rand_core::RngCore::fill_bytes(self, dest)
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rc::Error> {
+        ::rand_core::RngCore::fill_bytes(self, dest);
+        Ok(())
+    }
+}
+
+impl<C, S> rc::SeedableRng for Xoshiro<C, S> where Xoshiro<C, S>: XoshiroRng {
+    type Seed = <Xoshiro<C, S> as ::rand_core::SeedableRng>::Seed;
+
+    #[inline]
+    fn from_seed(seed: Self::Seed) -> Xoshiro<C, S> {
+        ::rand_core::SeedableRng::from_seed(seed)
+    }
+
+    /// Seed the generator like `from_seed_u64()`.
+    fn seed_from_u64(seed: u64) -> Xoshiro<C, S> {
+        XoshiroRng::from_seed_u64(seed)
+    }
+}
+
+impl rc::RngCore for SplitMix64 {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        ::rand_core::RngCore::next_u32(self)
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        ::rand_core::RngCore::next_u64(self)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        ::rand_core::RngCore::fill_bytes(self, dest)
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rc::Error> {
+        ::rand_core::RngCore::fill_bytes(self, dest);
+        Ok(())
+    }
+}
+
+impl rc::SeedableRng for SplitMix64 {
+    type Seed = [u8; 8];
+
+    #[inline]
+    fn from_seed(seed: [u8; 8]) -> SplitMix64 {
+        ::rand_core::SeedableRng::from_seed(seed)
+    }
+
+    /// Seed the generator like `from_seed_u64()`.
+    fn seed_from_u64(seed: u64) -> SplitMix64 {
+        SplitMix64::from_seed_u64(seed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::rc::{RngCore, SeedableRng};
+    use {SplitMix64, Xoroshiro64Star, Xoshiro256PlusPlus, Xoshiro512StarStar};
+
+    #[test]
+    fn rand_core_06() {
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(1);
+        let mut expected = Xoshiro256PlusPlus::from_seed_u64(1);
+        assert_eq!(RngCore::next_u64(&mut rng),
+                   ::rand_core::RngCore::next_u64(&mut expected));
+        let mut rng = Xoshiro512StarStar::from_rng(&mut rng).unwrap();
+        RngCore::next_u32(&mut rng);
+        assert_eq!(<Xoroshiro64Star as SeedableRng>::seed_from_u64(5),
+                   Xoroshiro64Star::from_seed_u64(5));
+        <Xoroshiro64Star as SeedableRng>::seed_from_u64(0);
+        assert_eq!(<SplitMix64 as SeedableRng>::seed_from_u64(5),
+                   SplitMix64::from_seed_u64(5));
+    }
+}
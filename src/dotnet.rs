@@ -0,0 +1,199 @@
+//! Compatibility with `System.Random` of .NET 6 and later.
+
+use rand_core::RngCore;
+
+use Xoshiro256StarStar;
+
+/// A reproduction of the `System.Random` methods of .NET 6 and later.
+///
+/// On 64-bit platforms, `new Random()` without a seed is implemented with
+/// xoshiro256**, seeded from the operating system. Given the same state, the
+/// methods of this type return exactly the same values as their .NET
+/// counterparts, e.g. `next_i32()` as `Next()` and `next_f64()` as
+/// `NextDouble()`. The state can be captured on the .NET side, e.g. from the
+/// fields `_s0` to `_s3` of the internal implementation.
+///
+/// `new Random(seed)` with an explicit seed and subclasses of `Random` use
+/// the legacy subtractive generator of .NET Framework instead, and 32-bit
+/// platforms use xoshiro128**, neither of which is reproduced here.
+///
+/// ```
+/// # extern crate xoshiro;
+/// # fn main() {
+/// use xoshiro::DotnetRandom;
+///
+/// let mut random = DotnetRandom::from_state([1, 2, 3, 4]);
+/// let die = random.next_i32_range(1, 7);
+/// let x = random.next_f64();
+/// # assert!(die >= 1 && die < 7 && x < 1.0);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DotnetRandom {
+    rng: Xoshiro256StarStar,
+}
+
+impl DotnetRandom {
+    /// Create a generator with the state `[_s0, _s1, _s2, _s3]`.
+    ///
+    /// # Panics
+    ///
+    /// If the state is entirely zero, which .NET never generates.
+    pub fn from_state(state: [u64; 4]) -> DotnetRandom {
+        let mut rng = Xoshiro256StarStar::from_seed_u64(0);
+        rng.set_state(state);
+        DotnetRandom { rng }
+    }
+
+    /// Create a generator continuing `rng`.
+    pub fn from_rng(rng: Xoshiro256StarStar) -> DotnetRandom {
+        DotnetRandom { rng }
+    }
+
+    /// Return the state `[_s0, _s1, _s2, _s3]`.
+    pub fn state(&self) -> [u64; 4] {
+        self.rng.state()
+    }
+
+    /// Return the underlying generator.
+    pub fn into_inner(self) -> Xoshiro256StarStar {
+        self.rng
+    }
+
+    /// Like `Next()`, return a value in [0, `i32::MAX`).
+    pub fn next_i32(&mut self) -> i32 {
+        loop {
+            let x = self.rng.next_u64() >> 33;
+            if x != i32::MAX as u64 {
+                return x as i32;
+            }
+        }
+    }
+
+    /// Like `Next(maxValue)`, return a value in [0, `max`), or 0 if `max`
+    /// is 0.
+    ///
+    /// # Panics
+    ///
+    /// If `max` is negative.
+    pub fn next_i32_below(&mut self, max: i32) -> i32 {
+        assert!(max >= 0, "max must be nonnegative");
+        self.below(max as u64) as i32
+    }
+
+    /// Like `Next(minValue, maxValue)`, return a value in [`min`, `max`), or
+    /// `min` if they are equal.
+    ///
+    /// # Panics
+    ///
+    /// If `min` is greater than `max`.
+    pub fn next_i32_range(&mut self, min: i32, max: i32) -> i32 {
+        assert!(min <= max, "min must not be greater than max");
+        let range = (i64::from(max) - i64::from(min)) as u64;
+        (self.below(range) as i64 + i64::from(min)) as i32
+    }
+
+    /// Like `NextInt64()`, return a value in [0, `i64::MAX`).
+    pub fn next_i64(&mut self) -> i64 {
+        loop {
+            let x = self.rng.next_u64() >> 1;
+            if x != i64::MAX as u64 {
+                return x as i64;
+            }
+        }
+    }
+
+    /// Like `NextInt64(maxValue)`, return a value in [0, `max`), or 0 if
+    /// `max` is 0.
+    ///
+    /// # Panics
+    ///
+    /// If `max` is negative.
+    pub fn next_i64_below(&mut self, max: i64) -> i64 {
+        assert!(max >= 0, "max must be nonnegative");
+        self.below(max as u64) as i64
+    }
+
+    /// Like `NextInt64(minValue, maxValue)`, return a value in [`min`,
+    /// `max`), or `min` if they are equal.
+    ///
+    /// # Panics
+    ///
+    /// If `min` is greater than `max`.
+    pub fn next_i64_range(&mut self, min: i64, max: i64) -> i64 {
+        assert!(min <= max, "min must not be greater than max");
+        let range = (max as u64).wrapping_sub(min as u64);
+        (self.below(range) as i64).wrapping_add(min)
+    }
+
+    /// Like `NextDouble()`, return a value in [0, 1) generated from the upper
+    /// 53 bits.
+    pub fn next_f64(&mut self) -> f64 {
+        self.rng.next_f64()
+    }
+
+    /// Like `NextSingle()`, return a value in [0, 1) generated from the
+    /// upper 24 bits.
+    pub fn next_f32(&mut self) -> f32 {
+        (self.rng.next_u64() >> 40) as f32 * (1.0 / (1u32 << 24) as f32)
+    }
+
+    /// Like `NextBytes(buffer)`, fill `dest` with the little-endian bytes of
+    /// successive outputs, using a final output partially.
+    pub fn next_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest)
+    }
+
+    /// Return a value in [0, `range`) by rejection sampling from the
+    /// smallest power of two containing `range`, like .NET.
+    fn below(&mut self, range: u64) -> u64 {
+        if range <= 1 {
+            return 0;
+        }
+        let bits = 64 - (range - 1).leading_zeros();
+        loop {
+            let x = self.rng.next_u64() >> (64 - bits);
+            if x < range {
+                return x;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn methods() {
+        let state = [1, 2, 3, 4];
+        let mut random = DotnetRandom::from_state(state);
+        let mut rng = Xoshiro256StarStar::from_seed_u64(0);
+        rng.set_state(state);
+        assert_eq!(random.state(), state);
+
+        assert_eq!(random.next_i32() as u64, rng.next_u64() >> 33);
+        assert_eq!(random.next_i64() as u64, rng.next_u64() >> 1);
+        assert_eq!(random.next_f64(), rng.next_f64());
+        // 6 needs 3 bits, so outputs are rejected until the upper 3 bits are
+        // below 6.
+        let die = random.next_i32_range(1, 7);
+        let mut x = rng.next_u64() >> 61;
+        while x >= 6 {
+            x = rng.next_u64() >> 61;
+        }
+        assert_eq!(die as u64, x + 1);
+        assert_eq!(random.next_i32_below(1), 0);
+        assert_eq!(random.next_i64_range(5, 5), 5);
+        assert_eq!(random.clone().into_inner(), rng);
+
+        for _ in 0..1000 {
+            let x = random.next_i64_range(i64::MIN, i64::MAX);
+            assert!(x < i64::MAX);
+            let x = random.next_i32_range(-10, 10);
+            assert!((-10..10).contains(&x));
+            assert!(random.next_i64_below(1000) < 1000);
+            assert!(random.next_f32() < 1.0);
+        }
+    }
+}
@@ -0,0 +1,276 @@
+//! C bindings.
+//!
+//! Each generator is exposed as a plain `#[repr(C)]` struct holding the state
+//! words, which C code can allocate, copy and store freely, and a set of
+//! `extern "C"` functions named after the generator, e.g. for
+//! `Xoshiro256StarStar`:
+//!
+//! ```c
+//! Xoshiro256State rng = xoshiro256ss_new(42);
+//! uint64_t x = xoshiro256ss_next(&rng);
+//! double d = xoshiro256ss_next_double(&rng);
+//! xoshiro256ss_jump(&rng);
+//! ```
+//!
+//! The prefixes are `xoroshiro64s`, `xoroshiro64ss`, `xoroshiro128p`,
+//! `xoroshiro128ss`, `xoshiro128p`, `xoshiro128pp`, `xoshiro128ss`,
+//! `xoshiro256p`, `xoshiro256pp`, `xoshiro256ss`, `xoshiro512p`,
+//! `xoshiro512pp` and `xoshiro512ss`. The header `include/xoshiro.h`
+//! declares all of them and can be regenerated with `cbindgen`.
+//!
+//! The state pointers must be valid and must not be null.
+//!
+//! To link C code against the crate, build it as a static or dynamic library:
+//!
+//! ```text
+//! cargo rustc --release --lib --features ffi --crate-type staticlib
+//! ```
+
+use std::slice;
+
+use rand_core::RngCore;
+
+pub use bitgen::BitGen;
+#[cfg(feature = "xoroshiro64")]
+use {Xoroshiro64Star, Xoroshiro64StarStar};
+#[cfg(feature = "xoroshiro128")]
+use {Xoroshiro128Plus, Xoroshiro128StarStar};
+#[cfg(feature = "xoshiro128")]
+use {Xoshiro128Plus, Xoshiro128PlusPlus, Xoshiro128StarStar};
+#[cfg(feature = "xoshiro256")]
+use {Xoshiro256Plus, Xoshiro256PlusPlus, Xoshiro256StarStar};
+#[cfg(feature = "xoshiro512")]
+use {Xoshiro512Plus, Xoshiro512PlusPlus, Xoshiro512StarStar};
+
+/// The state of the xoroshiro64 generators.
+#[cfg(feature = "xoroshiro64")]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Xoroshiro64State {
+    pub s: [u32; 2],
+}
+
+/// The state of the xoroshiro128 generators.
+#[cfg(feature = "xoroshiro128")]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Xoroshiro128State {
+    pub s: [u64; 2],
+}
+
+/// The state of the xoshiro128 generators.
+#[cfg(feature = "xoshiro128")]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Xoshiro128State {
+    pub s: [u32; 4],
+}
+
+/// The state of the xoshiro256 generators.
+#[cfg(feature = "xoshiro256")]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Xoshiro256State {
+    pub s: [u64; 4],
+}
+
+/// The state of the xoshiro512 generators.
+#[cfg(feature = "xoshiro512")]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Xoshiro512State {
+    pub s: [u64; 8],
+}
+
+/// Export the functions of the generator `$rng` with the state `$state`.
+///
+/// `$next` is the native output method, returning `$word`. The functions
+/// load the state into a generator, call it and store the state back.
+macro_rules! ffi_generator {
+    ($rng:ident, $state:ident, $next:ident -> $word:ty,
+     $new:ident, $next_fn:ident, $next_double:ident, $fill_bytes:ident,
+     $bitgen:ident, $jump:ident $(, $long_jump:ident)*) => {
+        #[doc = concat!("Seed a `", stringify!($rng), "` from a `u64` ",
+                        "like `from_seed_u64()`.")]
+        #[no_mangle]
+        pub extern "C" fn $new(seed: u64) -> $state {
+            $state { s: $rng::from_seed_u64(seed).state() }
+        }
+
+        #[doc = concat!("Return the next output of a `",
+                        stringify!($rng), "`.")]
+        #[no_mangle]
+        pub extern "C" fn $next_fn(state: &mut $state) -> $word {
+            let mut rng = $rng::new_unchecked(state.s);
+            let x = rng.$next();
+            state.s = rng.state();
+            x
+        }
+
+        #[doc = concat!("Return a random `double` in [0, 1) from a `",
+                        stringify!($rng), "`, like `next_f64()`.")]
+        #[no_mangle]
+        pub extern "C" fn $next_double(state: &mut $state) -> f64 {
+            let mut rng = $rng::new_unchecked(state.s);
+            let x = rng.next_f64();
+            state.s = rng.state();
+            x
+        }
+
+        #[doc = concat!("Fill `len` bytes at `dest` from a `",
+                        stringify!($rng), "`, like `fill_bytes()`.")]
+        ///
+        /// # Safety
+        ///
+        /// `dest` must be valid for writing `len` bytes, or `len` must be 0.
+        #[no_mangle]
+        pub unsafe extern "C" fn $fill_bytes(state: &mut $state,
+                                             dest: *mut u8, len: usize) {
+            if len == 0 {
+                return;
+            }
+            let mut rng = $rng::new_unchecked(state.s);
+            rng.fill_bytes(slice::from_raw_parts_mut(dest, len));
+            state.s = rng.state();
+        }
+
+        #[doc = concat!("Return the NumPy function table of a `",
+                        stringify!($rng), "`.")]
+        ///
+        /// The state must outlive any use of the table.
+        #[no_mangle]
+        pub extern "C" fn $bitgen(state: &mut $state) -> BitGen {
+            // The state has the same layout as the generator, a `repr(C)`
+            // struct of the state words.
+            BitGen::new(state as *mut $state as *mut $rng)
+        }
+
+        #[doc = concat!("Jump a `", stringify!($rng), "` forward like ",
+                        "`jump()`.")]
+        #[no_mangle]
+        pub extern "C" fn $jump(state: &mut $state) {
+            let mut rng = $rng::new_unchecked(state.s);
+            rng.jump();
+            state.s = rng.state();
+        }
+
+        $(
+            #[doc = concat!("Jump a `", stringify!($rng), "` forward like ",
+                            "`long_jump()`.")]
+            #[no_mangle]
+            pub extern "C" fn $long_jump(state: &mut $state) {
+                let mut rng = $rng::new_unchecked(state.s);
+                rng.long_jump();
+                state.s = rng.state();
+            }
+        )*
+    }
+}
+
+#[cfg(feature = "xoroshiro64")]
+ffi_generator!(Xoroshiro64Star, Xoroshiro64State, next_u32 -> u32,
+               xoroshiro64s_new, xoroshiro64s_next, xoroshiro64s_next_double,
+               xoroshiro64s_fill_bytes, xoroshiro64s_bitgen,
+               xoroshiro64s_jump);
+#[cfg(feature = "xoroshiro64")]
+ffi_generator!(Xoroshiro64StarStar, Xoroshiro64State, next_u32 -> u32,
+               xoroshiro64ss_new, xoroshiro64ss_next,
+               xoroshiro64ss_next_double, xoroshiro64ss_fill_bytes,
+               xoroshiro64ss_bitgen, xoroshiro64ss_jump);
+#[cfg(feature = "xoroshiro128")]
+ffi_generator!(Xoroshiro128Plus, Xoroshiro128State, next_u64 -> u64,
+               xoroshiro128p_new, xoroshiro128p_next,
+               xoroshiro128p_next_double, xoroshiro128p_fill_bytes,
+               xoroshiro128p_bitgen, xoroshiro128p_jump,
+               xoroshiro128p_long_jump);
+#[cfg(feature = "xoroshiro128")]
+ffi_generator!(Xoroshiro128StarStar, Xoroshiro128State, next_u64 -> u64,
+               xoroshiro128ss_new, xoroshiro128ss_next,
+               xoroshiro128ss_next_double, xoroshiro128ss_fill_bytes,
+               xoroshiro128ss_bitgen, xoroshiro128ss_jump,
+               xoroshiro128ss_long_jump);
+#[cfg(feature = "xoshiro128")]
+ffi_generator!(Xoshiro128Plus, Xoshiro128State, next_u32 -> u32,
+               xoshiro128p_new, xoshiro128p_next, xoshiro128p_next_double,
+               xoshiro128p_fill_bytes, xoshiro128p_bitgen, xoshiro128p_jump,
+               xoshiro128p_long_jump);
+#[cfg(feature = "xoshiro128")]
+ffi_generator!(Xoshiro128PlusPlus, Xoshiro128State, next_u32 -> u32,
+               xoshiro128pp_new, xoshiro128pp_next, xoshiro128pp_next_double,
+               xoshiro128pp_fill_bytes, xoshiro128pp_bitgen, xoshiro128pp_jump,
+               xoshiro128pp_long_jump);
+#[cfg(feature = "xoshiro128")]
+ffi_generator!(Xoshiro128StarStar, Xoshiro128State, next_u32 -> u32,
+               xoshiro128ss_new, xoshiro128ss_next, xoshiro128ss_next_double,
+               xoshiro128ss_fill_bytes, xoshiro128ss_bitgen, xoshiro128ss_jump,
+               xoshiro128ss_long_jump);
+#[cfg(feature = "xoshiro256")]
+ffi_generator!(Xoshiro256Plus, Xoshiro256State, next_u64 -> u64,
+               xoshiro256p_new, xoshiro256p_next, xoshiro256p_next_double,
+               xoshiro256p_fill_bytes, xoshiro256p_bitgen, xoshiro256p_jump,
+               xoshiro256p_long_jump);
+#[cfg(feature = "xoshiro256")]
+ffi_generator!(Xoshiro256PlusPlus, Xoshiro256State, next_u64 -> u64,
+               xoshiro256pp_new, xoshiro256pp_next, xoshiro256pp_next_double,
+               xoshiro256pp_fill_bytes, xoshiro256pp_bitgen, xoshiro256pp_jump,
+               xoshiro256pp_long_jump);
+#[cfg(feature = "xoshiro256")]
+ffi_generator!(Xoshiro256StarStar, Xoshiro256State, next_u64 -> u64,
+               xoshiro256ss_new, xoshiro256ss_next, xoshiro256ss_next_double,
+               xoshiro256ss_fill_bytes, xoshiro256ss_bitgen, xoshiro256ss_jump,
+               xoshiro256ss_long_jump);
+#[cfg(feature = "xoshiro512")]
+ffi_generator!(Xoshiro512Plus, Xoshiro512State, next_u64 -> u64,
+               xoshiro512p_new, xoshiro512p_next, xoshiro512p_next_double,
+               xoshiro512p_fill_bytes, xoshiro512p_bitgen, xoshiro512p_jump,
+               xoshiro512p_long_jump);
+#[cfg(feature = "xoshiro512")]
+ffi_generator!(Xoshiro512PlusPlus, Xoshiro512State, next_u64 -> u64,
+               xoshiro512pp_new, xoshiro512pp_next, xoshiro512pp_next_double,
+               xoshiro512pp_fill_bytes, xoshiro512pp_bitgen, xoshiro512pp_jump,
+               xoshiro512pp_long_jump);
+#[cfg(feature = "xoshiro512")]
+ffi_generator!(Xoshiro512StarStar, Xoshiro512State, next_u64 -> u64,
+               xoshiro512ss_new, xoshiro512ss_next, xoshiro512ss_next_double,
+               xoshiro512ss_fill_bytes, xoshiro512ss_bitgen, xoshiro512ss_jump,
+               xoshiro512ss_long_jump);
+
+#[cfg(test)]
+mod tests {
+    use rand_core::SeedableRng;
+    use super::*;
+
+    #[test]
+    fn xoshiro256ss() {
+        let mut state = xoshiro256ss_new(42);
+        let mut rng = Xoshiro256StarStar::from_seed_u64(42);
+        assert_eq!(xoshiro256ss_next(&mut state), rng.next_u64());
+        assert_eq!(xoshiro256ss_next_double(&mut state), rng.next_f64());
+        xoshiro256ss_jump(&mut state);
+        rng.jump();
+        xoshiro256ss_long_jump(&mut state);
+        rng.long_jump();
+        let mut a = [0u8; 13];
+        let mut b = [0u8; 13];
+        unsafe {
+            xoshiro256ss_fill_bytes(&mut state, a.as_mut_ptr(), a.len());
+            xoshiro256ss_fill_bytes(&mut state, ::std::ptr::null_mut(), 0);
+        }
+        rng.fill_bytes(&mut b);
+        assert_eq!(a, b);
+        assert_eq!(state.s, rng.state());
+        let table = xoshiro256ss_bitgen(&mut state);
+        assert_eq!(unsafe { (table.next_uint64)(table.state) }, rng.next_u64());
+        assert_eq!(state.s, rng.state());
+    }
+
+    #[test]
+    fn xoroshiro64() {
+        let mut state = xoroshiro64ss_new(0);
+        let mut rng = Xoroshiro64StarStar::from_seed([0; 8]);
+        assert_eq!(xoroshiro64ss_next(&mut state), rng.next_u32());
+        let mut state = xoroshiro64s_new(5);
+        assert_eq!(xoroshiro64s_next(&mut state),
+                   Xoroshiro64Star::from_seed_u64(5).next_u32());
+    }
+}
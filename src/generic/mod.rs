@@ -0,0 +1,1249 @@
+//! Generic generators built from a linear engine and a scrambler.
+//!
+//! All generators of the xoshiro family consist of a linear engine (the
+//! "core"), which updates the state, and a nonlinear scrambler, which computes
+//! the output from the state. `Xoshiro<C, S>` combines any core `C` with any
+//! scrambler `S` implemented for it, and the named generators of this crate
+//! are type aliases for such combinations.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::BitXorAssign;
+
+use rand_core::{RngCore, SeedableRng};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use common::ZeroSeedError;
+use poly;
+use splitmix64::hash_bytes;
+use XoshiroRng;
+
+/// Implement `RngCore` and the backward steps for a core with 64-bit words.
+macro_rules! impl_rng_core_u64 {
+    ($core:ident) => {
+        impl<S: Scrambler<$core>> Xoshiro<$core, S> {
+            /// Step the generator backwards, undoing the last call to
+            /// `next_u32()`, and return the value that call produced.
+            pub fn prev_u32(&mut self) -> u32 {
+                super::truncate::<$core, S>(self.prev_u64())
+            }
+
+            /// Step the generator backwards, undoing the last call to
+            /// `next_u64()`, and return the value that call produced.
+            pub fn prev_u64(&mut self) -> u64 {
+                self.core.step_back();
+                S::scramble(&self.core.s)
+            }
+        }
+
+        impl<S: Scrambler<$core>> RngCore for Xoshiro<$core, S> {
+            #[inline]
+            fn next_u32(&mut self) -> u32 {
+                super::truncate::<$core, S>(self.next_u64())
+            }
+
+            #[inline]
+            fn next_u64(&mut self) -> u64 {
+                let r = S::scramble(&self.core.s);
+                self.core.step();
+                r
+            }
+
+            #[inline]
+            fn fill_bytes(&mut self, dest: &mut [u8]) {
+                impl_fill_bytes!(u64, self, dest);
+            }
+
+            #[inline]
+            fn try_fill_bytes(&mut self, dest: &mut [u8])
+                -> Result<(), ::rand_core::Error>
+            {
+                self.fill_bytes(dest);
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Implement `RngCore` and the backward steps for a core with 32-bit words.
+macro_rules! impl_rng_core_u32 {
+    ($core:ident) => {
+        impl<S: Scrambler<$core>> Xoshiro<$core, S> {
+            /// Step the generator backwards, undoing the last call to
+            /// `next_u32()`, and return the value that call produced.
+            pub fn prev_u32(&mut self) -> u32 {
+                self.core.step_back();
+                S::scramble(&self.core.s)
+            }
+
+            /// Step the generator backwards, undoing the last call to
+            /// `next_u64()`, and return the value that call produced.
+            pub fn prev_u64(&mut self) -> u64 {
+                let y = u64::from(self.prev_u32());
+                let x = u64::from(self.prev_u32());
+                (y << 32) | x
+            }
+        }
+
+        impl<S: Scrambler<$core>> RngCore for Xoshiro<$core, S> {
+            #[inline]
+            fn next_u32(&mut self) -> u32 {
+                let r = S::scramble(&self.core.s);
+                self.core.step();
+                r
+            }
+
+            #[inline]
+            fn next_u64(&mut self) -> u64 {
+                ::rand_core::impls::next_u64_via_u32(self)
+            }
+
+            #[inline]
+            fn fill_bytes(&mut self, dest: &mut [u8]) {
+                impl_fill_bytes!(u32, self, dest);
+            }
+
+            #[inline]
+            fn try_fill_bytes(&mut self, dest: &mut [u8])
+                -> Result<(), ::rand_core::Error>
+            {
+                self.fill_bytes(dest);
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Implement the conversion of the state to and from `$n` bytes for a core.
+macro_rules! impl_bytes {
+    ($core:ident, $read:ident, $write:ident, $n:expr) => {
+        impl<S> Xoshiro<$core, S> {
+            /// The size of a state word in bytes.
+            const WORD_BYTES: usize =
+                ::std::mem::size_of::<<$core as Core>::Word>();
+
+            /// Return the state as little-endian bytes.
+            ///
+            /// This is a snapshot of the full state, which can be restored
+            /// with `from_bytes()`.
+            pub fn to_bytes(&self) -> [u8; $n] {
+                let mut bytes = [0; $n];
+                ::common::$write(&self.core.s, &mut bytes);
+                bytes
+            }
+
+            /// Restore a generator from a state returned by `to_bytes()`.
+            ///
+            /// This panics if the state is entirely zero. Use
+            /// `new_unchecked()` to skip the check.
+            pub fn from_bytes(bytes: [u8; $n]) -> Self {
+                assert!(bytes.iter().any(|&b| b != 0),
+                        "from_bytes called with an all zero state.");
+                Self::new_unchecked(::common::$read(&bytes))
+            }
+
+            /// Return the state in the memory layout of the reference
+            /// implementation.
+            ///
+            /// These are the bytes of the array `s` in the C code, i.e. the
+            /// state words in native byte order, as written by
+            /// `fwrite(s, sizeof s, 1, file)`.
+            pub fn to_c_state(&self) -> [u8; $n] {
+                let mut bytes = self.to_bytes();
+                ::common::swap_le_native(&mut bytes, Self::WORD_BYTES);
+                bytes
+            }
+
+            /// Restore a generator from the array `s` of the reference
+            /// implementation, in the layout returned by `to_c_state()`.
+            ///
+            /// This panics if the state is entirely zero.
+            pub fn from_c_state(mut bytes: [u8; $n]) -> Self {
+                assert!(bytes.iter().any(|&b| b != 0),
+                        "from_c_state called with an all zero state.");
+                ::common::swap_le_native(&mut bytes, Self::WORD_BYTES);
+                Self::from_bytes(bytes)
+            }
+        }
+
+        impl<S: Scrambler<$core>> ::std::fmt::Display for Xoshiro<$core, S> {
+            /// Write the state as `<name>/v1:<hex>`, e.g.
+            /// `xoshiro256**/v1:0100...`, where the hexadecimal digits encode
+            /// `to_bytes()`.
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                ::text::write_state(f, &Self::name(), &self.to_bytes())
+            }
+        }
+
+        impl<S: Scrambler<$core>> ::std::str::FromStr for Xoshiro<$core, S> {
+            type Err = ::text::ParseStateError;
+
+            /// Restore a generator from a state written by `Display`.
+            fn from_str(s: &str) -> Result<Self, ::text::ParseStateError> {
+                let mut bytes = [0; $n];
+                ::text::parse_state(s, &Self::name(), &mut bytes)?;
+                if bytes == [0; $n] {
+                    return Err(::text::ParseStateError::Zero);
+                }
+                Ok(Self::from_bytes(bytes))
+            }
+        }
+    }
+}
+
+/// Implement `Zeroize` and, unless the generators are `Copy`, zeroizing on
+/// drop for a core.
+macro_rules! impl_zeroize {
+    ($core:ident) => {
+        #[cfg(feature = "zeroize")]
+        impl ::zeroize::Zeroize for $core {
+            fn zeroize(&mut self) {
+                ::zeroize::Zeroize::zeroize(&mut self.s);
+            }
+        }
+
+        #[cfg(all(feature = "zeroize", not(feature = "copy")))]
+        impl Drop for $core {
+            fn drop(&mut self) {
+                ::zeroize::Zeroize::zeroize(self);
+            }
+        }
+
+        #[cfg(all(feature = "zeroize", not(feature = "copy")))]
+        impl ::zeroize::ZeroizeOnDrop for $core {}
+    }
+}
+
+/// Implement `Pod` and `Zeroable` for a core.
+macro_rules! impl_pod {
+    ($core:ident) => {
+        #[cfg(feature = "bytemuck")]
+        unsafe impl ::bytemuck::Zeroable for $core {}
+
+        #[cfg(feature = "bytemuck")]
+        unsafe impl ::bytemuck::Pod for $core {}
+    }
+}
+
+#[cfg(feature = "xoroshiro64")]
+mod xoroshiro64;
+#[cfg(feature = "xoroshiro128")]
+mod xoroshiro128;
+#[cfg(feature = "xoroshiro128")]
+mod xoroshiro128plusplus;
+#[cfg(feature = "xoshiro128")]
+mod xoshiro128;
+#[cfg(feature = "xoshiro256")]
+mod xoshiro256;
+#[cfg(feature = "xoshiro512")]
+mod xoshiro512;
+
+#[cfg(feature = "xoroshiro64")]
+pub use self::xoroshiro64::{Xoroshiro64Core, Xoroshiro64Star,
+                            Xoroshiro64StarStar};
+#[cfg(feature = "xoroshiro128")]
+pub use self::xoroshiro128::{Xoroshiro128Core, Xoroshiro128Plus,
+                             Xoroshiro128StarStar};
+#[cfg(feature = "xoroshiro128")]
+pub use self::xoroshiro128plusplus::{Xoroshiro128PlusPlusCore,
+                                     Xoroshiro128PlusPlus};
+#[cfg(feature = "xoshiro128")]
+pub use self::xoshiro128::{Xoshiro128Core, Xoshiro128Plus, Xoshiro128PlusPlus,
+                           Xoshiro128StarStar};
+#[cfg(feature = "xoshiro128")]
+#[allow(deprecated)]
+pub use self::xoshiro128::XorShiftRng;
+#[cfg(feature = "xoshiro256")]
+pub use self::xoshiro256::{Xoshiro256Core, Xoshiro256Plus, Xoshiro256PlusPlus,
+                           Xoshiro256StarStar};
+#[cfg(feature = "xoshiro512")]
+pub use self::xoshiro512::{Xoshiro512Core, Xoshiro512Plus, Xoshiro512PlusPlus,
+                           Xoshiro512StarStar};
+
+/// The linear engine of a generator, updating the state.
+pub trait Core {
+    /// The name of the engine, e.g. `"xoshiro256"`.
+    const NAME: &'static str;
+
+    /// The characteristic polynomial of the engine, as little-endian words
+    /// without the implicit leading term, bit `i` being the coefficient of
+    /// x^i.
+    const POLY: &'static [u64];
+
+    /// The base-2 logarithm of the number of steps of `jump()`.
+    const JUMP_LOG2: u32;
+
+    /// The type of the state words.
+    type Word: Copy + BitXorAssign + Into<u64>;
+
+    /// The state, an array of words.
+    type State: Copy + Default + AsRef<[Self::Word]> + AsMut<[Self::Word]>;
+
+    /// The current state.
+    fn state(&self) -> &Self::State;
+
+    /// The current state, mutably.
+    ///
+    /// The state must not become entirely zero.
+    fn state_mut(&mut self) -> &mut Self::State;
+
+    /// Advance the state by one step.
+    fn step(&mut self);
+
+    /// Undo one step of the state.
+    fn step_back(&mut self);
+}
+
+/// The output function of a generator, computing an output from the state.
+pub trait Scrambler<C: Core> {
+    /// The name of the scrambler, e.g. `"**"`.
+    const NAME: &'static str;
+
+    /// Whether the lowest bits of the output have linear dependencies.
+    ///
+    /// This is the case for the + scrambler. If so, `next_u32()` of the
+    /// generators with 64-bit words returns the upper half of the output
+    /// instead of the lower one. The floating-point methods like `next_f32()`
+    /// always use the upper bits.
+    const WEAK_LOW_BITS: bool = false;
+
+    /// Compute the output for the state `s`.
+    fn scramble(s: &C::State) -> C::Word;
+}
+
+/// The + scrambler, adding two state words.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Plus;
+
+/// The ++ scrambler, adding two state words, rotating and adding again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct PlusPlus;
+
+/// The * scrambler, multiplying a state word by a constant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Star;
+
+/// The ** scrambler, multiplying a state word, rotating and multiplying again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct StarStar;
+
+/// A generator of the xoshiro family, combining the core `C` with the
+/// scrambler `S`.
+///
+/// Usually, one of the type aliases like `Xoshiro256StarStar` is used
+/// instead of naming the combination directly.
+///
+/// The generators only implement `Copy` with the `copy` feature, because
+/// implicit copies make it easy to accidentally reuse a part of the stream.
+///
+/// With the `bytemuck` feature, which implies the `copy` feature, the
+/// generators implement `Pod`, so arrays of them can be cast to bytes without
+/// copying. The layout is the state words in native endianness.
+///
+/// With the `zeroize` feature, the state can be scrubbed with `zeroize()`,
+/// and it is overwritten when the generator is dropped, unless the `copy`
+/// feature is enabled as well. Copies made by moving the generator are not
+/// overwritten.
+///
+/// ```
+/// # extern crate rand_core;
+/// # extern crate xoshiro;
+/// # fn main() {
+/// use rand_core::RngCore;
+/// use xoshiro::{PlusPlus, Xoshiro, Xoshiro256Core};
+///
+/// let mut rng = Xoshiro::<Xoshiro256Core, PlusPlus>::from_seed_u64(0);
+/// let x = rng.next_u64();
+/// # let _ = x;
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "copy", derive(Copy))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(transparent)]
+pub struct Xoshiro<C, S> {
+    core: C,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    scrambler: PhantomData<S>,
+}
+
+/// Overwrite the state with zeros.
+///
+/// This leaves the generator in the all-zero state, in which it only outputs
+/// zeros, so it must not be used afterwards.
+#[cfg(feature = "zeroize")]
+impl<C: ::zeroize::Zeroize, S> ::zeroize::Zeroize for Xoshiro<C, S> {
+    fn zeroize(&mut self) {
+        self.core.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<C: ::zeroize::ZeroizeOnDrop, S> ::zeroize::ZeroizeOnDrop for Xoshiro<C, S> {}
+
+/// The all-zero state is not valid for generating numbers, see
+/// `new_unchecked()`.
+#[cfg(feature = "bytemuck")]
+unsafe impl<C: ::bytemuck::Zeroable, S> ::bytemuck::Zeroable for Xoshiro<C, S> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<C: ::bytemuck::Pod, S: Copy + 'static> ::bytemuck::Pod
+    for Xoshiro<C, S> {}
+
+impl<C, S> Xoshiro<C, S> {
+    /// Create a generator from a core.
+    const fn from_core(core: C) -> Xoshiro<C, S> {
+        Xoshiro { core, scrambler: PhantomData }
+    }
+}
+
+impl<C: Core, S> Xoshiro<C, S> {
+    /// Return the state words.
+    pub fn state(&self) -> C::State {
+        *self.core.state()
+    }
+
+    /// Replace the state words.
+    ///
+    /// This panics if `state` is entirely zero.
+    pub fn set_state(&mut self, state: C::State) {
+        assert!(state.as_ref().iter().any(|&w| Into::<u64>::into(w) != 0),
+                "set_state called with an all zero state.");
+        *self.core.state_mut() = state;
+    }
+
+    /// Jump forward by the polynomial `jump`, given as little-endian words.
+    ///
+    /// Bit `i` of `jump` is the coefficient of x^i. Jumping by x^n modulo the
+    /// characteristic polynomial of the generator is equivalent to `n`
+    /// outputs, i.e. `n` calls to `next_u32()` for the generators with 32-bit
+    /// output and to `next_u64()` for the others. This can be used to apply
+    /// precomputed jumps other than `JUMP`.
+    pub fn jump_with_polynomial(&mut self, jump: &[C::Word]) {
+        jump_core(&mut self.core, jump);
+    }
+
+    /// Advance the state, equivalently to `n` outputs.
+    ///
+    /// This evaluates x^n modulo the characteristic polynomial of the
+    /// generator and applies it like a jump, which takes O(log n) polynomial
+    /// multiplications instead of `n` steps.
+    pub fn advance(&mut self, n: u128) {
+        let jump = poly::x_pow_mod(&poly::u128_words(n), C::POLY);
+        jump_core(&mut self.core, &jump[..C::POLY.len()]);
+    }
+
+    /// Jump forward `n` times, equivalently to calling `jump()` `n` times.
+    ///
+    /// Like `advance()`, this takes O(log n) polynomial multiplications, so
+    /// the generator of the `n`-th subsequence can be computed directly.
+    pub fn jump_n(&mut self, n: u64) {
+        let jump = poly::x_pow_jumps_mod(n, C::JUMP_LOG2, C::POLY);
+        jump_core(&mut self.core, &jump[..C::POLY.len()]);
+    }
+}
+
+impl<C: Core, S> Xoshiro<C, S> where Xoshiro<C, S>: XoshiroRng {
+    /// Create the generator at the start of the `i`-th subsequence.
+    ///
+    /// This is equivalent to seeding with `from_seed_u64(seed)` and calling
+    /// `jump()` `i` times, but takes logarithmic time in `i`.
+    pub fn nth_stream(seed: u64, i: u64) -> Self {
+        let mut rng = <Self as XoshiroRng>::from_seed_u64(seed);
+        rng.jump_n(i);
+        rng
+    }
+}
+
+/// Write the state words of `core` in hexadecimal like `text::write_hex()`,
+/// each padded to its full width.
+fn fmt_hex<C: Core>(core: &C, f: &mut fmt::Formatter, upper: bool)
+    -> fmt::Result
+{
+    let width = 2 * ::std::mem::size_of::<C::Word>();
+    let words: Vec<u64> = core.state().as_ref().iter()
+        .map(|&w| w.into()).collect();
+    ::text::write_hex(f, &words, width, upper)
+}
+
+impl<C: Core, S> fmt::LowerHex for Xoshiro<C, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_hex(&self.core, f, false)
+    }
+}
+
+impl<C: Core, S> fmt::UpperHex for Xoshiro<C, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_hex(&self.core, f, true)
+    }
+}
+
+impl<C: Core, S: Scrambler<C>> Xoshiro<C, S> {
+    /// Return the name of the generator, e.g. `"xoshiro256**"`.
+    ///
+    /// This is the name used by the `Display` and `FromStr` implementations.
+    pub fn name() -> String {
+        format!("{}{}", C::NAME, S::NAME)
+    }
+}
+
+/// Generate a generator from a seed taken from the input.
+///
+/// An all-zero seed is replaced by a nonzero one, so the state is valid.
+#[cfg(feature = "arbitrary")]
+impl<'a, C, S> ::arbitrary::Arbitrary<'a> for Xoshiro<C, S>
+    where Xoshiro<C, S>: SeedableRng
+{
+    fn arbitrary(u: &mut ::arbitrary::Unstructured<'a>)
+        -> ::arbitrary::Result<Xoshiro<C, S>>
+    {
+        let mut seed = <Xoshiro<C, S> as SeedableRng>::Seed::default();
+        u.fill_buffer(seed.as_mut())?;
+        if seed.as_mut().iter().all(|&b| b == 0) {
+            seed.as_mut()[0] = 1;
+        }
+        Ok(Xoshiro::from_seed(seed))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        let n = ::std::mem::size_of::<<Xoshiro<C, S> as SeedableRng>::Seed>();
+        (n, Some(n))
+    }
+}
+
+/// Generate a generator from a random seed.
+///
+/// An all-zero seed is replaced by a nonzero one, so the state is valid.
+/// Generators are not shrunk.
+#[cfg(feature = "quickcheck")]
+impl<C, S> ::quickcheck::Arbitrary for Xoshiro<C, S>
+    where Xoshiro<C, S>: SeedableRng + Clone + 'static
+{
+    fn arbitrary(g: &mut ::quickcheck::Gen) -> Xoshiro<C, S> {
+        let mut seed = <Xoshiro<C, S> as SeedableRng>::Seed::default();
+        for b in seed.as_mut().iter_mut() {
+            *b = ::quickcheck::Arbitrary::arbitrary(g);
+        }
+        if seed.as_mut().iter().all(|&b| b == 0) {
+            seed.as_mut()[0] = 1;
+        }
+        Xoshiro::from_seed(seed)
+    }
+}
+
+impl<C, S> Xoshiro<C, S> where Xoshiro<C, S>: SeedableRng {
+    /// Create a new generator, failing if `seed` is entirely zero.
+    ///
+    /// Unlike `from_seed()`, which replaces an all-zero seed by a fixed
+    /// valid one, this reports it, so degenerate input can be detected.
+    pub fn try_from_seed(mut seed: <Xoshiro<C, S> as SeedableRng>::Seed)
+        -> Result<Xoshiro<C, S>, ZeroSeedError>
+    {
+        if seed.as_mut().iter().all(|&b| b == 0) {
+            return Err(ZeroSeedError);
+        }
+        Ok(Xoshiro::from_seed(seed))
+    }
+
+    /// Create a new generator from a byte string of any length.
+    ///
+    /// The bytes are hashed into the full seed, so related experiments can
+    /// be keyed by names instead of numbers. The hash is not cryptographic,
+    /// and the resulting streams are part of the stable output of the crate.
+    pub fn from_seed_bytes(bytes: &[u8]) -> Xoshiro<C, S> {
+        let mut seed = <Xoshiro<C, S> as SeedableRng>::Seed::default();
+        hash_bytes(bytes, seed.as_mut());
+        Xoshiro::from_seed(seed)
+    }
+
+    /// Create a new generator from a string, like `from_seed_bytes()`.
+    ///
+    /// ```
+    /// # extern crate rand_core;
+    /// # extern crate xoshiro;
+    /// # fn main() {
+    /// use rand_core::RngCore;
+    /// use xoshiro::Xoshiro256StarStar;
+    ///
+    /// let mut rng = Xoshiro256StarStar::from_seed_str("run-2024-42");
+    /// let x = rng.next_u64();
+    /// # let _ = x;
+    /// # }
+    /// ```
+    pub fn from_seed_str(s: &str) -> Xoshiro<C, S> {
+        Xoshiro::from_seed_bytes(s.as_bytes())
+    }
+
+    /// Create the generator of stream `stream_id` for `seed`.
+    ///
+    /// Both values are mixed into the full state with the `SplitMix64`
+    /// output function, like `from_seed_bytes()` with the 16 little-endian
+    /// bytes of `seed` and `stream_id`. This gives up to 2^64 streams per
+    /// seed without any jumps. Unlike jumped streams, they are not
+    /// guaranteed not to overlap, but for the larger generators an overlap
+    /// is as unlikely as for independently seeded ones.
+    ///
+    /// ```
+    /// # extern crate xoshiro;
+    /// # fn main() {
+    /// use xoshiro::Xoshiro256PlusPlus;
+    ///
+    /// let users: Vec<_> = (0..1000)
+    ///     .map(|id| Xoshiro256PlusPlus::new_stream(42, id))
+    ///     .collect();
+    /// # assert!(users[0] != users[1]);
+    /// # }
+    /// ```
+    pub fn new_stream(seed: u64, stream_id: u64) -> Xoshiro<C, S> {
+        let mut bytes = [0; 16];
+        bytes[..8].copy_from_slice(&seed.to_le_bytes());
+        bytes[8..].copy_from_slice(&stream_id.to_le_bytes());
+        Xoshiro::from_seed_bytes(&bytes)
+    }
+
+    /// Create a generator continuing from the state `[x, y, z, w]` of the
+    /// xorshift generator `XorShiftRng` of `rand`.
+    ///
+    /// This eases migrating reproducible pipelines which checkpointed such a
+    /// state: the same state always gives the same generator. The outputs do
+    /// not continue the xorshift stream, which this crate cannot generate.
+    /// The state is hashed like `from_seed_bytes()` with the bytes
+    /// `xorshift` followed by the little-endian bytes of the words, so it
+    /// does not give the same generator as any `new_stream()`.
+    ///
+    /// The state words are the fields `x`, `y`, `z` and `w` of a serialized
+    /// `XorShiftRng`, or the little-endian words of the seed passed to its
+    /// `from_seed()`.
+    ///
+    /// ```
+    /// # extern crate xoshiro;
+    /// # fn main() {
+    /// use xoshiro::Xoshiro128PlusPlus;
+    ///
+    /// // The initial state of `XorShiftRng::new_unseeded()`.
+    /// let state = [0x193a6754, 0xa8a7d469, 0x97830e05, 0x113ba7bb];
+    /// let rng = Xoshiro128PlusPlus::from_xorshift_state(state);
+    /// # assert_eq!(rng, Xoshiro128PlusPlus::from_xorshift_state(state));
+    /// # }
+    /// ```
+    pub fn from_xorshift_state(state: [u32; 4]) -> Xoshiro<C, S> {
+        let mut bytes = [0; 24];
+        bytes[..8].copy_from_slice(b"xorshift");
+        for (chunk, w) in bytes[8..].chunks_mut(4).zip(state.iter()) {
+            chunk.copy_from_slice(&w.to_le_bytes());
+        }
+        Xoshiro::from_seed_bytes(&bytes)
+    }
+}
+
+#[cfg(feature = "getrandom")]
+impl<C, S> Xoshiro<C, S> where Xoshiro<C, S>: SeedableRng {
+    /// Seed the generator from the operating system's random number source.
+    ///
+    /// This panics if the operating system fails to provide randomness.
+    pub fn from_entropy() -> Xoshiro<C, S> {
+        ::common::from_entropy()
+    }
+}
+
+impl<C: Core, S: Scrambler<C>> Xoshiro<C, S> where Xoshiro<C, S>: RngCore {
+    /// Return the next four `u64`, equivalently to calling `next_u64()` four
+    /// times.
+    ///
+    /// The calls are unrolled, so the state can stay in registers.
+    #[inline]
+    pub fn next_u64x4(&mut self) -> [u64; 4] {
+        [self.next_u64(), self.next_u64(), self.next_u64(), self.next_u64()]
+    }
+
+    /// Return the next eight `u32`, equivalently to calling `next_u32()`
+    /// eight times.
+    ///
+    /// The calls are unrolled, so the state can stay in registers.
+    #[inline]
+    pub fn next_u32x8(&mut self) -> [u32; 8] {
+        [
+            self.next_u32(), self.next_u32(), self.next_u32(), self.next_u32(),
+            self.next_u32(), self.next_u32(), self.next_u32(), self.next_u32(),
+        ]
+    }
+
+    /// Return the upper 32 bits of the next output.
+    #[inline]
+    fn upper_u32(&mut self) -> u32 {
+        if ::std::mem::size_of::<C::Word>() == 8 {
+            (self.next_u64() >> 32) as u32
+        } else {
+            self.next_u32()
+        }
+    }
+
+    impl_convenience!(Xoshiro256PlusPlus);
+}
+
+/// Truncate a 64-bit output to 32 bits, avoiding weak low bits.
+#[inline]
+fn truncate<C: Core, S: Scrambler<C>>(x: u64) -> u32 {
+    if S::WEAK_LOW_BITS {
+        // The lowest bits have some linear dependencies, so we use the
+        // upper bits instead.
+        (x >> 32) as u32
+    } else {
+        x as u32
+    }
+}
+
+/// Jump the core forward by a polynomial given as little-endian words.
+///
+/// Bit `i` of `jump` is the coefficient of x^i. The words of `jump` may be
+/// narrower than 64 bits.
+fn jump_core<C: Core, W: Copy + Into<u64>>(core: &mut C, jump: &[W]) {
+    let mut s = C::State::default();
+    for &j in jump {
+        let j: u64 = j.into();
+        for b in 0..8 * ::std::mem::size_of::<W>() {
+            if (j >> b) & 1 != 0 {
+                for (x, &y) in s.as_mut().iter_mut().zip(core.state().as_ref()) {
+                    *x ^= y;
+                }
+            }
+            core.step();
+        }
+    }
+    *core.state_mut() = s;
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::RngCore;
+    use common::{u32_to_f32, u64_to_f64};
+    #[cfg(feature = "half")]
+    use common::{u32_to_bf16, u32_to_f16};
+    use {Xoroshiro64Star, Xoroshiro64StarStar, Xoshiro256Plus,
+         Xoshiro512StarStar};
+
+    #[test]
+    fn fill_ints() {
+        let mut rng1 = Xoshiro256Plus::from_seed_u64(1);
+        let mut rng2 = rng1.clone();
+        let mut v64 = [0; 17];
+        rng1.fill_u64(&mut v64);
+        for &x in &v64 {
+            assert_eq!(x, rng2.next_u64());
+        }
+        let mut v32 = [0; 17];
+        rng1.fill_u32(&mut v32);
+        for &x in &v32 {
+            assert_eq!(x, rng2.next_u32());
+        }
+
+        let mut rng1 = Xoroshiro64StarStar::from_seed_u64(1);
+        let mut rng2 = rng1.clone();
+        rng1.fill_u64(&mut v64);
+        for &x in &v64 {
+            assert_eq!(x, rng2.next_u64());
+        }
+        rng1.fill_u32(&mut v32);
+        for &x in &v32 {
+            assert_eq!(x, rng2.next_u32());
+        }
+    }
+
+    #[test]
+    fn eq() {
+        let mut rng1 = Xoshiro256Plus::from_seed_u64(1);
+        let mut rng2 = rng1.clone();
+        assert_eq!(rng1, rng2);
+        rng1.next_u64();
+        assert!(rng1 != rng2);
+        rng2.next_u64();
+        assert_eq!(rng1, rng2);
+        rng1.jump();
+        rng1.jump_back();
+        assert_eq!(rng1, rng2);
+    }
+
+    #[test]
+    fn c_state() {
+        let rng = Xoshiro256Plus::from_seed_u64(1);
+        let state = rng.state();
+        // The bytes of the words in memory, as C would write them.
+        let memory: [u8; 32] = unsafe { ::std::mem::transmute(state) };
+        assert_eq!(rng.to_c_state(), memory);
+        assert_eq!(Xoshiro256Plus::from_c_state(memory), rng);
+
+        let rng = Xoroshiro64Star::from_seed_u64(1);
+        let memory: [u8; 8] = unsafe { ::std::mem::transmute(rng.state()) };
+        assert_eq!(rng.to_c_state(), memory);
+        assert_eq!(Xoroshiro64Star::from_c_state(memory), rng);
+    }
+
+    #[test]
+    #[should_panic]
+    fn c_state_zero() {
+        Xoshiro512StarStar::from_c_state([0; 64]);
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn zeroize() {
+        use zeroize::Zeroize;
+        use {Seed512, SplitMix64};
+
+        let mut rng = Xoshiro256Plus::from_seed_u64(1);
+        rng.zeroize();
+        assert_eq!(rng, Xoshiro256Plus::new_unchecked([0; 4]));
+        let mut rng = SplitMix64::from_seed_u64(1);
+        rng.zeroize();
+        assert_eq!(rng.next_u64(), SplitMix64::from_seed_u64(0).next_u64());
+        let mut seed = Seed512([1; 64]);
+        seed.zeroize();
+        assert_eq!(seed.0[..], [0; 64][..]);
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+        use rand_core::SeedableRng;
+        use {SplitMix64, Xoroshiro128StarStar};
+
+        let mut u = Unstructured::new(&[0; 16]);
+        let mut rng = Xoroshiro128StarStar::arbitrary(&mut u).unwrap();
+        let mut expected = Xoroshiro128StarStar::new_unchecked([1, 0]);
+        assert_eq!(rng.next_u64(), expected.next_u64());
+
+        let data: Vec<u8> = (1..=40).collect();
+        let mut u = Unstructured::new(&data);
+        let mut rng = Xoshiro256Plus::arbitrary(&mut u).unwrap();
+        let mut seed = [0; 32];
+        seed.copy_from_slice(&data[..32]);
+        assert_eq!(rng.next_u64(), Xoshiro256Plus::from_seed(seed).next_u64());
+        let mut rng = SplitMix64::arbitrary(&mut u).unwrap();
+        assert_eq!(rng.next_u64(), SplitMix64::from_seed_u64(
+            u64::from_le_bytes([33, 34, 35, 36, 37, 38, 39, 40])).next_u64());
+    }
+
+    #[cfg(feature = "quickcheck")]
+    #[test]
+    fn quickcheck() {
+        use quickcheck::quickcheck;
+        use Xoroshiro64Star;
+
+        fn prev_undoes_next(mut rng: Xoroshiro64Star) -> bool {
+            let x = rng.next_u32();
+            rng.prev_u32() == x
+        }
+        quickcheck(prev_undoes_next as fn(Xoroshiro64Star) -> bool);
+
+        fn jump_back_undoes_jump(rng: Xoshiro256Plus) -> bool {
+            let mut jumped = rng.clone();
+            jumped.jump();
+            jumped.jump_back();
+            jumped == rng
+        }
+        quickcheck(jump_back_undoes_jump as fn(Xoshiro256Plus) -> bool);
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn bytemuck() {
+        use Xoroshiro64StarStar;
+
+        let mut rngs = [Xoroshiro64StarStar::from_seed_u64(1); 4];
+        rngs[2].next_u32();
+        let words: &[u32] = ::bytemuck::cast_slice(&rngs);
+        assert_eq!(words.len(), 8);
+        assert_eq!(words[0], 1);
+        let restored: &[Xoroshiro64StarStar] = ::bytemuck::cast_slice(words);
+        assert_eq!(restored, &rngs[..]);
+    }
+
+    #[test]
+    fn bytes() {
+        use {Xoroshiro64Star, Xoshiro512StarStar};
+
+        let mut rng = Xoshiro256Plus::from_seed_u64(1);
+        rng.next_u64();
+        let mut restored = Xoshiro256Plus::from_bytes(rng.to_bytes());
+        assert_eq!(restored.next_u64(), rng.next_u64());
+
+        let rng = Xoshiro256Plus::new_unchecked([1, 2, 3, 4]);
+        assert_eq!(rng.to_bytes()[..9], [1, 0, 0, 0, 0, 0, 0, 0, 2]);
+
+        let rng = Xoshiro512StarStar::from_seed_u64(1);
+        assert_eq!(Xoshiro512StarStar::from_bytes(rng.to_bytes()), rng);
+        let rng = Xoroshiro64Star::from_seed_u64(0x0102);
+        assert_eq!(rng.to_bytes(), [2, 1, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(Xoroshiro64Star::from_bytes(rng.to_bytes()), rng);
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_bytes_zero() {
+        Xoshiro256Plus::from_bytes([0; 32]);
+    }
+
+    #[test]
+    fn text() {
+        use {ParseStateError, Xoroshiro64Star, Xoshiro256StarStar,
+             Xoshiro512PlusPlus};
+
+        let rng = Xoroshiro64Star::from_seed_u64(0x0102);
+        assert_eq!(rng.to_string(), "xoroshiro64*/v1:0201000000000000");
+        assert_eq!("xoroshiro64*/v1:0201000000000000".parse(), Ok(rng));
+        assert_eq!("xoroshiro64*/v1:02010000000000AB".parse(),
+                   Ok(Xoroshiro64Star::new_unchecked([0x0102, 0xab000000])));
+
+        let mut rng = Xoshiro256StarStar::from_seed_u64(1);
+        rng.next_u64();
+        let s = rng.to_string();
+        assert!(s.starts_with("xoshiro256**/v1:"));
+        let mut restored: Xoshiro256StarStar = s.parse().unwrap();
+        assert_eq!(restored.next_u64(), rng.next_u64());
+        assert_eq!(Xoshiro512PlusPlus::name(), "xoshiro512++");
+
+        let parse = |s: &str| s.parse::<Xoroshiro64Star>();
+        assert_eq!(parse("xoroshiro64*"), Err(ParseStateError::Format));
+        assert_eq!(parse("xoroshiro64**/v1:0201000000000000"),
+                   Err(ParseStateError::Name));
+        assert_eq!(parse("xoroshiro64*/v2:0201000000000000"),
+                   Err(ParseStateError::Version));
+        assert_eq!(parse("xoroshiro64*/v1:02010000000000"),
+                   Err(ParseStateError::Length));
+        assert_eq!(parse("xoroshiro64*/v1:020100000000000g"),
+                   Err(ParseStateError::Digit));
+        assert_eq!(parse("xoroshiro64*/v1:0000000000000000"),
+                   Err(ParseStateError::Zero));
+    }
+
+    #[test]
+    fn state() {
+        use Xoroshiro64Star;
+
+        let mut rng = Xoshiro256Plus::new_unchecked([1, 2, 3, 4]);
+        assert_eq!(rng.state(), [1, 2, 3, 4]);
+        rng.next_u64();
+        let state = rng.state();
+        let x = rng.next_u64();
+        rng.set_state(state);
+        assert_eq!(rng.next_u64(), x);
+
+        let mut rng = Xoroshiro64Star::from_seed_u64(1);
+        rng.set_state([0, 5]);
+        assert_eq!(rng, Xoroshiro64Star::new_unchecked([0, 5]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn set_zero_state() {
+        Xoshiro256Plus::from_seed_u64(1).set_state([0; 4]);
+    }
+
+    #[test]
+    fn hex() {
+        use Xoroshiro64Star;
+
+        let rng = Xoshiro256Plus::new_unchecked([1, 0xab, 3, 4 << 60]);
+        assert_eq!(format!("{:x}", rng),
+                   "0000000000000001 00000000000000ab \
+                    0000000000000003 4000000000000000");
+        let rng = Xoroshiro64Star::new_unchecked([0xab, 0xcd000000]);
+        assert_eq!(format!("{:X}", rng), "000000AB CD000000");
+        assert_eq!(format!("{:#x}", rng), "0x000000ab 0xcd000000");
+    }
+
+    #[test]
+    fn try_from_seed() {
+        use rand_core::SeedableRng;
+        use {Seed512, Xoroshiro128Plus, Xoshiro512StarStar, ZeroSeedError};
+
+        assert_eq!(Xoroshiro128Plus::try_from_seed([0; 16]),
+                   Err(ZeroSeedError));
+        let mut seed = [0; 16];
+        seed[15] = 1;
+        assert_eq!(Xoroshiro128Plus::try_from_seed(seed),
+                   Ok(Xoroshiro128Plus::from_seed(seed)));
+        assert!(Xoshiro512StarStar::try_from_seed(Seed512([0; 64])).is_err());
+        assert_eq!(ZeroSeedError.to_string(), "seed is entirely zero");
+    }
+
+    #[test]
+    fn zero_seed() {
+        use rand_core::SeedableRng;
+        use {Seed512, SplitMix64, Xoroshiro128Plus, Xoroshiro64Star,
+             Xoshiro512StarStar};
+
+        assert_eq!(Xoroshiro128Plus::from_seed([0; 16]),
+                   Xoroshiro128Plus::from_seed_u64(0));
+        assert_eq!(Xoshiro256Plus::from_seed([0; 32]),
+                   Xoshiro256Plus::from_seed_u64(0));
+        assert_eq!(Xoshiro512StarStar::from_seed(Seed512([0; 64])),
+                   Xoshiro512StarStar::from_seed_u64(0));
+        let x = SplitMix64::from_seed_u64(0).next_u64();
+        assert_eq!(Xoroshiro64Star::from_seed([0; 8]),
+                   Xoroshiro64Star::from_seed_u64(x));
+    }
+
+    #[test]
+    fn from_words() {
+        use {Xoroshiro128StarStar, Xoroshiro64Star, Xoshiro128PlusPlus,
+             Xoshiro512Plus};
+
+        assert_eq!(Xoroshiro64Star::from([1, 2]),
+                   Xoroshiro64Star::new_unchecked([1, 2]));
+        assert_eq!(Xoroshiro128StarStar::from([1, 2]),
+                   Xoroshiro128StarStar::new_unchecked([1, 2]));
+        assert_eq!(Xoshiro128PlusPlus::from([1, 2, 3, 4]),
+                   Xoshiro128PlusPlus::new_unchecked([1, 2, 3, 4]));
+        assert_eq!(Xoshiro256Plus::from([1, 2, 3, 4]),
+                   Xoshiro256Plus::new_unchecked([1, 2, 3, 4]));
+        assert_eq!(Xoshiro512Plus::from([1, 2, 3, 4, 5, 6, 7, 8]),
+                   Xoshiro512Plus::new_unchecked([1, 2, 3, 4, 5, 6, 7, 8]));
+        assert_eq!(Xoshiro256Plus::from([0; 4]),
+                   Xoshiro256Plus::from_seed_u64(0));
+    }
+
+    #[test]
+    fn seed_bytes() {
+        use {Xoroshiro64Star, Xoshiro512StarStar};
+
+        let rng = Xoshiro256Plus::from_seed_str("run-2024-42");
+        assert_eq!(rng, Xoshiro256Plus::from_seed_bytes(b"run-2024-42"));
+        assert!(rng != Xoshiro256Plus::from_seed_str("run-2024-43"));
+        assert!(Xoshiro256Plus::from_seed_bytes(b"a")
+                != Xoshiro256Plus::from_seed_bytes(b"a\0"));
+        assert!(Xoshiro256Plus::from_seed_bytes(b"")
+                != Xoshiro256Plus::from_seed_bytes(b"\0"));
+        // The seed words are independent hashes.
+        let s = Xoshiro512StarStar::from_seed_str("x").state();
+        for i in 0..8 {
+            for j in 0..i {
+                assert!(s[i] != s[j]);
+            }
+        }
+        let mut rng = Xoroshiro64Star::from_seed_str("");
+        rng.next_u32();
+    }
+
+    #[test]
+    fn hash() {
+        use std::collections::HashMap;
+
+        let mut rng = Xoshiro256Plus::from_seed_u64(1);
+        let mut results = HashMap::new();
+        results.insert(rng.clone(), 1);
+        rng.next_u64();
+        results.insert(rng.clone(), 2);
+        rng.prev_u64();
+        assert_eq!(results.get(&rng), Some(&1));
+        assert_eq!(results.len(), 2);
+    }
+
+    #[cfg(feature = "copy")]
+    #[test]
+    fn copy() {
+        #[derive(Clone, Copy)]
+        struct Particle {
+            rng: Xoroshiro64StarStar,
+        }
+
+        let mut p1 = Particle { rng: Xoroshiro64StarStar::from_seed_u64(1) };
+        let mut p2 = p1;
+        assert_eq!(p1.rng.next_u32(), p2.rng.next_u32());
+    }
+
+    #[test]
+    fn batches() {
+        let mut rng1 = Xoshiro256Plus::from_seed_u64(1);
+        let mut rng2 = rng1.clone();
+        for _ in 0..3 {
+            for &x in &rng1.next_u64x4() {
+                assert_eq!(x, rng2.next_u64());
+            }
+            for &x in &rng1.next_u32x8() {
+                assert_eq!(x, rng2.next_u32());
+            }
+        }
+
+        let mut rng1 = Xoroshiro64StarStar::from_seed_u64(1);
+        let mut rng2 = rng1.clone();
+        for &x in &rng1.next_u64x4() {
+            assert_eq!(x, rng2.next_u64());
+        }
+        for &x in &rng1.next_u32x8() {
+            assert_eq!(x, rng2.next_u32());
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use serde_json;
+        use {Seed512, SplitMix64, Xoroshiro64Star, Xoshiro512PlusPlus};
+
+        let mut rng = Xoshiro512PlusPlus::from_seed_u64(1);
+        rng.next_u64();
+        let json = serde_json::to_string(&rng).unwrap();
+        let mut restored: Xoshiro512PlusPlus = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.next_u64(), rng.next_u64());
+
+        let mut rng = Xoroshiro64Star::from_seed_u64(1);
+        let json = serde_json::to_string(&rng).unwrap();
+        let mut restored: Xoroshiro64Star = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.next_u64(), rng.next_u64());
+
+        let mut rng = SplitMix64::from_seed_u64(1);
+        let json = serde_json::to_string(&rng).unwrap();
+        let mut restored: SplitMix64 = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.next_u64(), rng.next_u64());
+
+        let mut seed = Seed512([0; 64]);
+        seed.0[63] = 1;
+        let json = serde_json::to_string(&seed).unwrap();
+        let restored: Seed512 = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, seed);
+        let restored: Seed512 =
+            serde_json::from_str("[0, 0, 0, 0, 0, 0, 0, 72057594037927936]")
+            .unwrap();
+        assert_eq!(restored, seed);
+        assert!(serde_json::from_str::<Seed512>("[1, 2, 3]").is_err());
+        let mut bytes = vec![0; 64];
+        bytes[0] = 256;
+        let json = serde_json::to_string(&bytes).unwrap();
+        assert!(serde_json::from_str::<Seed512>(&json).is_err());
+    }
+
+    #[cfg(feature = "getrandom")]
+    #[test]
+    fn from_entropy() {
+        use {SplitMix64, Xoroshiro64StarStar};
+
+        let mut rng1 = Xoshiro256Plus::from_entropy();
+        let mut rng2 = Xoshiro256Plus::from_entropy();
+        assert!(rng1.next_u64() != rng2.next_u64());
+        let mut rng = Xoroshiro64StarStar::from_entropy();
+        rng.next_u64();
+        let mut rng1 = SplitMix64::from_entropy();
+        let mut rng2 = SplitMix64::from_entropy();
+        assert!(rng1.next_u64() != rng2.next_u64());
+    }
+
+    #[test]
+    fn new_stream() {
+        let rng = Xoshiro512StarStar::new_stream(1, 2);
+        assert_eq!(rng, Xoshiro512StarStar::new_stream(1, 2));
+        assert!(rng != Xoshiro512StarStar::new_stream(1, 3));
+        assert!(rng != Xoshiro512StarStar::new_stream(2, 2));
+        let mut bytes = vec![1, 0, 0, 0, 0, 0, 0, 0];
+        bytes.extend_from_slice(&[2, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(rng, Xoshiro512StarStar::from_seed_bytes(&bytes));
+        assert!(Xoroshiro64Star::new_stream(0, 1)
+                != Xoroshiro64Star::new_stream(0, 2));
+    }
+
+    #[test]
+    fn iters() {
+        let mut rng1 = Xoroshiro64StarStar::from_seed_u64(1);
+        let mut rng2 = rng1.clone();
+        let v: Vec<u64> = rng1.iter_u64().take(3).collect();
+        for &x in &v {
+            assert_eq!(x, rng2.next_u64());
+        }
+        let v: Vec<u32> = rng1.iter_u32().take(3).collect();
+        for &x in &v {
+            assert_eq!(x, rng2.next_u32());
+        }
+        let v: Vec<f64> = rng1.iter_f64().take(3).collect();
+        for &x in &v {
+            assert_eq!(x, u64_to_f64(rng2.next_u64()));
+        }
+        let v: Vec<f32> = rng1.iter_f32().take(3).collect();
+        for &x in &v {
+            assert_eq!(x, u32_to_f32(rng2.next_u32()));
+        }
+        assert_eq!(rng1, rng2);
+    }
+
+    #[test]
+    fn next_floats() {
+        let mut rng1 = Xoshiro256Plus::from_seed_u64(1);
+        let mut rng2 = rng1.clone();
+        for _ in 0..100 {
+            let x = rng1.next_f64();
+            assert_eq!(x, (rng2.next_u64() >> 11) as f64 / (1u64 << 53) as f64);
+            assert!((0.0..1.0).contains(&x));
+            // The upper 24 bits of the 64-bit output.
+            let x = rng1.next_f32();
+            assert_eq!(x, (rng2.next_u64() >> 40) as f32 / (1u32 << 24) as f32);
+            assert!((0.0..1.0).contains(&x));
+            let x = rng1.next_f64_open01();
+            assert!(x > 0.0 && x < 1.0);
+            let x = rng1.next_f64_closed01();
+            assert!((0.0..=1.0).contains(&x));
+            rng2.next_u64();
+            rng2.next_u64();
+            let x = rng1.next_f64_open_closed();
+            assert_eq!(x, rng2.next_f64() + 1.0 / (1u64 << 53) as f64);
+            assert!(x > 0.0 && x <= 1.0);
+        }
+        assert_eq!(rng1, rng2);
+    }
+
+    #[test]
+    fn next_f32_upper_bits() {
+        macro_rules! check {
+            ($($rng:ident),*) => {$(
+                let mut rng1 = ::$rng::from_seed_u64(1);
+                let mut rng2 = rng1.clone();
+                for _ in 0..10 {
+                    let x = (rng2.next_u64() >> 40) as f32;
+                    assert_eq!(rng1.next_f32(), x / (1u32 << 24) as f32);
+                }
+            )*}
+        }
+        check!(Xoroshiro128Plus, Xoroshiro128PlusPlus, Xoroshiro128StarStar,
+               Xoshiro256Plus, Xoshiro256PlusPlus, Xoshiro256StarStar,
+               Xoshiro512Plus, Xoshiro512PlusPlus, Xoshiro512StarStar);
+    }
+
+    #[test]
+    fn fill_floats() {
+        let mut rng1 = Xoshiro256Plus::from_seed_u64(1);
+        let mut rng2 = rng1.clone();
+        let mut v64 = [0.0; 17];
+        rng1.fill_f64(&mut v64);
+        for &x in &v64 {
+            assert_eq!(x, u64_to_f64(rng2.next_u64()));
+            assert!((0.0..1.0).contains(&x));
+        }
+        let mut v32 = [0.0; 17];
+        rng1.fill_f32(&mut v32);
+        for &x in &v32 {
+            assert_eq!(x, u32_to_f32((rng2.next_u64() >> 32) as u32));
+            assert!((0.0..1.0).contains(&x));
+        }
+    }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn fill_half_floats() {
+        use half::{bf16, f16};
+
+        let mut rng1 = Xoshiro256Plus::from_seed_u64(1);
+        let mut rng2 = rng1.clone();
+        let mut v16 = [f16::ZERO; 17];
+        rng1.fill_f16(&mut v16);
+        for &x in &v16 {
+            assert_eq!(x, u32_to_f16((rng2.next_u64() >> 32) as u32));
+            assert!((0.0..1.0).contains(&x.to_f32()));
+        }
+        let mut vb16 = [bf16::ZERO; 17];
+        rng1.fill_bf16(&mut vb16);
+        for &x in &vb16 {
+            assert_eq!(x, u32_to_bf16((rng2.next_u64() >> 32) as u32));
+            assert!((0.0..1.0).contains(&x.to_f32()));
+        }
+    }
+}
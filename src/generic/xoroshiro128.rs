@@ -0,0 +1,507 @@
+use rand_core::{RngCore, SeedableRng};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use poly;
+use common::{read_u64s, write_u64s};
+use splitmix64::splitmix_states;
+use super::{Core, Scrambler, Xoshiro, Plus, StarStar};
+
+/// The xoroshiro128 linear engine.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "copy", derive(Copy))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Xoroshiro128Core {
+    pub(crate) s: [u64; 2],
+}
+
+impl Core for Xoroshiro128Core {
+    const NAME: &'static str = "xoroshiro128";
+    const POLY: &'static [u64] = &poly::XOROSHIRO128;
+    const JUMP_LOG2: u32 = 64;
+
+    type Word = u64;
+    type State = [u64; 2];
+
+    #[inline]
+    fn state(&self) -> &[u64; 2] {
+        &self.s
+    }
+
+    #[inline]
+    fn state_mut(&mut self) -> &mut [u64; 2] {
+        &mut self.s
+    }
+
+    #[inline]
+    fn step(&mut self) {
+        impl_xoroshiro_u64!(self, 24, 16, 37);
+    }
+
+    #[inline]
+    fn step_back(&mut self) {
+        impl_xoroshiro_u64_inv!(self, 24, 16, 37);
+    }
+}
+
+impl Scrambler<Xoroshiro128Core> for Plus {
+    const NAME: &'static str = "+";
+
+    const WEAK_LOW_BITS: bool = true;
+
+    #[inline]
+    fn scramble(s: &[u64; 2]) -> u64 {
+        s[0].wrapping_add(s[1])
+    }
+}
+
+impl Scrambler<Xoroshiro128Core> for StarStar {
+    const NAME: &'static str = "**";
+
+    #[inline]
+    fn scramble(s: &[u64; 2]) -> u64 {
+        starstar_u64!(s[0])
+    }
+}
+
+/// A xoroshiro128+ random number generator.
+///
+/// The xoroshiro128+ algorithm is not suitable for cryptographic purposes, but
+/// is very fast and has good statistical properties, besides a low linear
+/// complexity in the lowest bits.
+///
+/// The algorithm used here is translated from [the `xoroshiro128plus.c`
+/// reference source code](http://xoshiro.di.unimi.it/xoroshiro128plus.c) by
+/// David Blackman and Sebastiano Vigna.
+pub type Xoroshiro128Plus = Xoshiro<Xoroshiro128Core, Plus>;
+
+/// A xoroshiro128** random number generator.
+///
+/// The xoroshiro128** algorithm is not suitable for cryptographic purposes, but
+/// is very fast and has excellent statistical properties.
+///
+/// The algorithm used here is translated from [the `xoroshiro128starstar.c`
+/// reference source code](http://xoshiro.di.unimi.it/xoroshiro128starstar.c) by
+/// David Blackman and Sebastiano Vigna.
+pub type Xoroshiro128StarStar = Xoshiro<Xoroshiro128Core, StarStar>;
+
+impl<S> Xoshiro<Xoroshiro128Core, S> {
+    /// Polynomial used by `jump()`.
+    pub const JUMP: [u64; 2] = [0xdf900294d8f554a5, 0x170865df4b3201fc];
+
+    /// Polynomial used by `long_jump()`.
+    pub const LONG_JUMP: [u64; 2] = [0xd2a98b26625eee7b, 0xdddf9b1090aa7ac1];
+
+    /// Polynomial used by `jump_back()`.
+    pub const JUMP_BACK: [u64; 2] = [0x38790d3e32e2dc33, 0x175b5cd55018b10b];
+
+    /// Polynomial used by `long_jump_back()`.
+    pub const LONG_JUMP_BACK: [u64; 2] = [0x40fb5bf086c52a67, 0x0fe98d0ac8d793d3];
+
+    /// Create a generator from its state words.
+    ///
+    /// The state must not be entirely zero, which is not checked.
+    pub const fn new_unchecked(s: [u64; 2]) -> Self {
+        Xoshiro::from_core(Xoroshiro128Core { s })
+    }
+
+    /// Seed the generator from a `u64` using `SplitMix64`.
+    pub const fn from_seed_u64(seed: u64) -> Self {
+        Self::new_unchecked(splitmix_states(seed))
+    }
+
+    /// Jump forward, equivalently to 2^64 calls to `next_u64()`.
+    ///
+    /// This can be used to generate 2^64 non-overlapping subsequences for
+    /// parallel computations.
+    ///
+    /// ```
+    /// # extern crate rand;
+    /// # extern crate xoshiro;
+    /// # fn main() {
+    /// use rand::SeedableRng;
+    /// use xoshiro::Xoroshiro128StarStar;
+    ///
+    /// let rng1 = Xoroshiro128StarStar::from_seed_u64(0);
+    /// let mut rng2 = rng1.clone();
+    /// rng2.jump();
+    /// let mut rng3 = rng2.clone();
+    /// rng3.jump();
+    /// # }
+    /// ```
+    pub fn jump(&mut self) {
+        self.jump_with_polynomial(&Self::JUMP);
+    }
+
+    /// Jump forward, equivalently to 2^96 calls to `next_u64()`.
+    ///
+    /// This can be used to generate 2^32 starting points, from each of which
+    /// `jump()` will generate 2^32 non-overlapping subsequences for parallel
+    /// distributed computations.
+    pub fn long_jump(&mut self) {
+        self.jump_with_polynomial(&Self::LONG_JUMP);
+    }
+
+    /// Jump backward, equivalently to 2^64 calls to `next_u64()` in reverse.
+    ///
+    /// This undoes `jump()`. Because the period of the generator is 2^128 - 1,
+    /// this is the same as jumping forward by 2^128 - 1 - 2^64 steps.
+    pub fn jump_back(&mut self) {
+        self.jump_with_polynomial(&Self::JUMP_BACK);
+    }
+
+    /// Jump backward, equivalently to 2^96 calls to `next_u64()` in reverse.
+    ///
+    /// This undoes `long_jump()`. Because the period of the generator is
+    /// 2^128 - 1, this is the same as jumping forward by
+    /// 2^128 - 1 - 2^96 steps.
+    pub fn long_jump_back(&mut self) {
+        self.jump_with_polynomial(&Self::LONG_JUMP_BACK);
+    }
+}
+
+impl<S> SeedableRng for Xoshiro<Xoroshiro128Core, S> {
+    type Seed = [u8; 16];
+
+    /// Create a new generator.
+    ///
+    /// An all-zero seed, which is not a valid state, is replaced by the state
+    /// of `from_seed_u64(0)`.
+    fn from_seed(seed: [u8; 16]) -> Self {
+        if seed == [0; 16] {
+            return Self::from_seed_u64(0);
+        }
+        Self::new_unchecked(read_u64s(&seed))
+    }
+
+    /// Seed the generator like `from_seed_u64()`, instead of the PCG-based
+    /// expansion of the default implementation.
+    fn seed_from_u64(seed: u64) -> Self {
+        Self::from_seed_u64(seed)
+    }
+}
+
+impl<S> From<[u64; 2]> for Xoshiro<Xoroshiro128Core, S> {
+    /// Create a new generator from state words.
+    ///
+    /// This is equivalent to `from_seed()` with the words in little-endian
+    /// order, so an all-zero state is replaced as well.
+    fn from(words: [u64; 2]) -> Self {
+        let mut seed = [0; 16];
+        write_u64s(&words, &mut seed);
+        Self::from_seed(seed)
+    }
+}
+
+impl_rng_core_u64!(Xoroshiro128Core);
+impl_bytes!(Xoroshiro128Core, read_u64s, write_u64s, 16);
+impl_zeroize!(Xoroshiro128Core);
+impl_pod!(Xoroshiro128Core);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plus_reference() {
+        let mut rng = Xoroshiro128Plus::from_seed(
+            [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]);
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoshiro128starstar.c
+        let expected = [
+            3, 412333834243, 2360170716294286339, 9295852285959843169,
+            2797080929874688578, 6019711933173041966, 3076529664176959358,
+            3521761819100106140, 7493067640054542992, 920801338098114767,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn plus_jump() {
+        let mut rng = Xoroshiro128Plus::from_seed(
+            [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]);
+        rng.jump();
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoroshiro128plus.c
+        let expected = [
+            16863749256561482023, 15988492901402843592, 16860311396414380700,
+            3258968728841841858, 12110696225962959222, 1547892121749478067,
+            11407568694762216707, 14611797242126090097, 5939982374836420565,
+            9857412840466987223,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn plus_long_jump() {
+        let mut rng = Xoroshiro128Plus::from_seed(
+            [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]);
+        rng.long_jump();
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoroshiro128plus.c
+        let expected = [
+            7459827119013173373, 16629812729731364797, 17067482968129184606,
+            6083857043340806358, 10153483773391873044, 2961509668263645074,
+            13836706860199109749, 14299006275327519472, 18285281072021985984,
+            3571691428460267304,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn plus_advance() {
+        let mut rng1 = Xoroshiro128Plus::from_seed_u64(0);
+        let mut rng2 = rng1.clone();
+        for _ in 0..100 {
+            rng1.next_u64();
+        }
+        rng2.advance(100);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+
+        rng1.jump();
+        rng2.advance(1 << 64);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn plus_jump_back() {
+        let mut rng1 = Xoroshiro128Plus::from_seed_u64(0);
+        let mut rng2 = rng1.clone();
+        rng2.jump();
+        rng2.jump_back();
+        rng2.long_jump_back();
+        rng2.long_jump();
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn plus_prev() {
+        let mut rng = Xoroshiro128Plus::from_seed_u64(0);
+        let values: Vec<_> = (0..10).map(|_| rng.next_u64()).collect();
+        for &v in values.iter().rev() {
+            assert_eq!(rng.prev_u64(), v);
+        }
+        for &v in &values {
+            assert_eq!(rng.next_u64(), v);
+        }
+        let x = rng.next_u32();
+        assert_eq!(rng.prev_u32(), x);
+    }
+
+    #[test]
+    fn plus_nth_stream() {
+        let mut rng1 = Xoroshiro128Plus::from_seed_u64(0);
+        for _ in 0..3 {
+            rng1.jump();
+        }
+        let mut rng2 = Xoroshiro128Plus::nth_stream(0, 3);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn plus_jump_with_polynomial() {
+        let mut rng1 = Xoroshiro128Plus::from_seed_u64(0);
+        let mut rng2 = rng1.clone();
+        rng1.jump();
+        rng2.jump_with_polynomial(&Xoroshiro128Plus::JUMP);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+        // x^3 corresponds to three steps.
+        rng1.jump_with_polynomial(&[0b1000]);
+        for _ in 0..3 {
+            rng2.next_u64();
+        }
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn plus_const_construction() {
+        use SplitMix64;
+
+        const RNG: Xoroshiro128Plus = Xoroshiro128Plus::from_seed_u64(1);
+        let mut rng1 = RNG;
+        let mut rng2 = Xoroshiro128Plus::from_rng(SplitMix64::from_seed_u64(1)).unwrap();
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+
+        let mut seed = [0; 16];
+        for (i, chunk) in seed.chunks_mut(8).enumerate() {
+            chunk[0] = i as u8 + 1;
+        }
+        let mut rng1 = Xoroshiro128Plus::new_unchecked([1, 2]);
+        let mut rng2 = Xoroshiro128Plus::from_seed(seed);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn starstar_reference() {
+        let mut rng = Xoroshiro128StarStar::from_seed(
+            [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]);
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoshiro128starstar.c
+        let expected = [
+            5760, 97769243520, 9706862127477703552, 9223447511460779954,
+            8358291023205304566, 15695619998649302768, 8517900938696309774,
+            16586480348202605369, 6959129367028440372, 16822147227405758281,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn starstar_jump() {
+        let mut rng = Xoroshiro128StarStar::from_seed(
+            [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]);
+        rng.jump();
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoroshiro128starstar.c
+        let expected = [
+            2464231652016875657, 11602794600843324846, 733764001042591551,
+            5324733124812429005, 14635935237425590731, 4722186862080112093,
+            16160338188616420244, 5488700251982350304, 9830647176479810634,
+            6282991482565129712,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn starstar_long_jump() {
+        let mut rng = Xoroshiro128StarStar::from_seed(
+            [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]);
+        rng.long_jump();
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoroshiro128starstar.c
+        let expected = [
+            1154914562721061336, 6059381922964790418, 15458620134926953352,
+            6449629845481199462, 16689757678599312554, 3412265672712362534,
+            15452021286289288989, 14008746486977289672, 12513606066216797820,
+            17066077850955568493,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn starstar_advance() {
+        let mut rng1 = Xoroshiro128StarStar::from_seed_u64(0);
+        let mut rng2 = rng1.clone();
+        for _ in 0..100 {
+            rng1.next_u64();
+        }
+        rng2.advance(100);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+
+        rng1.jump();
+        rng2.advance(1 << 64);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn starstar_jump_back() {
+        let mut rng1 = Xoroshiro128StarStar::from_seed_u64(0);
+        let mut rng2 = rng1.clone();
+        rng2.jump();
+        rng2.jump_back();
+        rng2.long_jump_back();
+        rng2.long_jump();
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn starstar_prev() {
+        let mut rng = Xoroshiro128StarStar::from_seed_u64(0);
+        let values: Vec<_> = (0..10).map(|_| rng.next_u64()).collect();
+        for &v in values.iter().rev() {
+            assert_eq!(rng.prev_u64(), v);
+        }
+        for &v in &values {
+            assert_eq!(rng.next_u64(), v);
+        }
+        let x = rng.next_u32();
+        assert_eq!(rng.prev_u32(), x);
+    }
+
+    #[test]
+    fn starstar_nth_stream() {
+        let mut rng1 = Xoroshiro128StarStar::from_seed_u64(0);
+        for _ in 0..3 {
+            rng1.jump();
+        }
+        let mut rng2 = Xoroshiro128StarStar::nth_stream(0, 3);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn starstar_jump_with_polynomial() {
+        let mut rng1 = Xoroshiro128StarStar::from_seed_u64(0);
+        let mut rng2 = rng1.clone();
+        rng1.jump();
+        rng2.jump_with_polynomial(&Xoroshiro128StarStar::JUMP);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+        // x^3 corresponds to three steps.
+        rng1.jump_with_polynomial(&[0b1000]);
+        for _ in 0..3 {
+            rng2.next_u64();
+        }
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn starstar_const_construction() {
+        use SplitMix64;
+
+        const RNG: Xoroshiro128StarStar = Xoroshiro128StarStar::from_seed_u64(1);
+        let mut rng1 = RNG;
+        let mut rng2 = Xoroshiro128StarStar::from_rng(SplitMix64::from_seed_u64(1)).unwrap();
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+
+        let mut seed = [0; 16];
+        for (i, chunk) in seed.chunks_mut(8).enumerate() {
+            chunk[0] = i as u8 + 1;
+        }
+        let mut rng1 = Xoroshiro128StarStar::new_unchecked([1, 2]);
+        let mut rng2 = Xoroshiro128StarStar::from_seed(seed);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+}
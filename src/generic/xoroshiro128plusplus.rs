@@ -0,0 +1,328 @@
+use rand_core::{RngCore, SeedableRng};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use poly;
+use common::{read_u64s, write_u64s};
+use splitmix64::{mix, splitmix_states};
+use super::{Core, Scrambler, Xoshiro, PlusPlus};
+
+/// The xoroshiro128 linear engine with the parameters of xoroshiro128++.
+///
+/// This differs from `Xoroshiro128Core` in its rotations and shift
+/// (49, 21 and 28 instead of 24, 16 and 37), so it has its own jump
+/// polynomials.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "copy", derive(Copy))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Xoroshiro128PlusPlusCore {
+    pub(crate) s: [u64; 2],
+}
+
+impl Core for Xoroshiro128PlusPlusCore {
+    const NAME: &'static str = "xoroshiro128";
+    const POLY: &'static [u64] = &poly::XOROSHIRO128PLUSPLUS;
+    const JUMP_LOG2: u32 = 64;
+
+    type Word = u64;
+    type State = [u64; 2];
+
+    #[inline]
+    fn state(&self) -> &[u64; 2] {
+        &self.s
+    }
+
+    #[inline]
+    fn state_mut(&mut self) -> &mut [u64; 2] {
+        &mut self.s
+    }
+
+    #[inline]
+    fn step(&mut self) {
+        impl_xoroshiro_u64!(self, 49, 21, 28);
+    }
+
+    #[inline]
+    fn step_back(&mut self) {
+        impl_xoroshiro_u64_inv!(self, 49, 21, 28);
+    }
+}
+
+impl Scrambler<Xoroshiro128PlusPlusCore> for PlusPlus {
+    const NAME: &'static str = "++";
+
+    #[inline]
+    fn scramble(s: &[u64; 2]) -> u64 {
+        (s[0].wrapping_add(s[1])).rotate_left(17).wrapping_add(s[0])
+    }
+}
+
+/// A xoroshiro128++ random number generator.
+///
+/// The xoroshiro128++ algorithm is not suitable for cryptographic purposes,
+/// but is very fast and has excellent statistical properties. It is the
+/// generator `Xoroshiro128PlusPlus` of Java 17, see `from_java_seed()`.
+///
+/// The algorithm used here is translated from [the `xoroshiro128plusplus.c`
+/// reference source code](http://xoshiro.di.unimi.it/xoroshiro128plusplus.c)
+/// by David Blackman and Sebastiano Vigna.
+pub type Xoroshiro128PlusPlus = Xoshiro<Xoroshiro128PlusPlusCore, PlusPlus>;
+
+impl<S> Xoshiro<Xoroshiro128PlusPlusCore, S> {
+    /// Polynomial used by `jump()`.
+    pub const JUMP: [u64; 2] = [0x2bd7a6a6e99c2ddc, 0x0992ccaf6a6fca05];
+
+    /// Polynomial used by `long_jump()`.
+    pub const LONG_JUMP: [u64; 2] = [0x360fd5f2cf8d5d99, 0x9c6e6877736c46e3];
+
+    /// Polynomial used by `jump_back()`.
+    pub const JUMP_BACK: [u64; 2] = [0xeafe139297c2be69, 0x5045d4cedd0c0efa];
+
+    /// Polynomial used by `long_jump_back()`.
+    pub const LONG_JUMP_BACK: [u64; 2] = [0x81d9297a5c33bf8a, 0x611676e978fdf31f];
+
+    /// Create a generator from its state words.
+    ///
+    /// The state must not be entirely zero, which is not checked.
+    pub const fn new_unchecked(s: [u64; 2]) -> Self {
+        Xoshiro::from_core(Xoroshiro128PlusPlusCore { s })
+    }
+
+    /// Seed the generator from a `u64` using `SplitMix64`.
+    pub const fn from_seed_u64(seed: u64) -> Self {
+        Self::new_unchecked(splitmix_states(seed))
+    }
+
+    /// Jump forward, equivalently to 2^64 calls to `next_u64()`.
+    ///
+    /// This can be used to generate 2^64 non-overlapping subsequences for
+    /// parallel computations.
+    pub fn jump(&mut self) {
+        self.jump_with_polynomial(&Self::JUMP);
+    }
+
+    /// Jump forward, equivalently to 2^96 calls to `next_u64()`.
+    ///
+    /// This can be used to generate 2^32 starting points, from each of which
+    /// `jump()` will generate 2^32 non-overlapping subsequences for parallel
+    /// distributed computations.
+    pub fn long_jump(&mut self) {
+        self.jump_with_polynomial(&Self::LONG_JUMP);
+    }
+
+    /// Jump backward, equivalently to 2^64 calls to `next_u64()` in reverse.
+    ///
+    /// This undoes `jump()`. Because the period of the generator is 2^128 - 1,
+    /// this is the same as jumping forward by 2^128 - 1 - 2^64 steps.
+    pub fn jump_back(&mut self) {
+        self.jump_with_polynomial(&Self::JUMP_BACK);
+    }
+
+    /// Jump backward, equivalently to 2^96 calls to `next_u64()` in reverse.
+    ///
+    /// This undoes `long_jump()`. Because the period of the generator is
+    /// 2^128 - 1, this is the same as jumping forward by
+    /// 2^128 - 1 - 2^96 steps.
+    pub fn long_jump_back(&mut self) {
+        self.jump_with_polynomial(&Self::LONG_JUMP_BACK);
+    }
+}
+
+impl<S> SeedableRng for Xoshiro<Xoroshiro128PlusPlusCore, S> {
+    type Seed = [u8; 16];
+
+    /// Create a new generator.
+    ///
+    /// An all-zero seed, which is not a valid state, is replaced by the state
+    /// of `from_seed_u64(0)`.
+    fn from_seed(seed: [u8; 16]) -> Self {
+        if seed == [0; 16] {
+            return Self::from_seed_u64(0);
+        }
+        Self::new_unchecked(read_u64s(&seed))
+    }
+
+    /// Seed the generator like `from_seed_u64()`, instead of the PCG-based
+    /// expansion of the default implementation.
+    fn seed_from_u64(seed: u64) -> Self {
+        Self::from_seed_u64(seed)
+    }
+}
+
+impl<S> From<[u64; 2]> for Xoshiro<Xoroshiro128PlusPlusCore, S> {
+    /// Create a new generator from state words.
+    ///
+    /// This is equivalent to `from_seed()` with the words in little-endian
+    /// order, so an all-zero state is replaced as well.
+    fn from(words: [u64; 2]) -> Self {
+        let mut seed = [0; 16];
+        write_u64s(&words, &mut seed);
+        Self::from_seed(seed)
+    }
+}
+
+impl Xoshiro<Xoroshiro128PlusPlusCore, PlusPlus> {
+    /// Seed the generator like the constructor `Xoroshiro128PlusPlus(long)`
+    /// of the `jdk.random` module of Java 17.
+    ///
+    /// Java xors the seed with `0x6a09e667f3bcc909` and expands it like
+    /// `SplitMix64`. The generator then reproduces the Java stream:
+    /// `nextLong()` is `next_u64() as i64`, `nextDouble()` is `next_f64()`,
+    /// `nextInt()` is the upper half `(next_u64() >> 32) as i32`, which
+    /// differs from `next_u32()`, and `jump()` and `long_jump()` match `jump()`
+    /// and `leap()`.
+    ///
+    /// ```
+    /// # extern crate rand_core;
+    /// # extern crate xoshiro;
+    /// # fn main() {
+    /// use rand_core::RngCore;
+    /// use xoshiro::Xoroshiro128PlusPlus;
+    ///
+    /// // RandomGeneratorFactory.of("Xoroshiro128PlusPlus").create(42)
+    /// let mut rng = Xoroshiro128PlusPlus::from_java_seed(42);
+    /// assert_eq!(rng.next_u64(), 0xbed4a3d469c5d91f);
+    /// # }
+    /// ```
+    pub const fn from_java_seed(seed: i64) -> Self {
+        const SILVER_RATIO: u64 = 0x6a09e667f3bcc909;
+        const GOLDEN_RATIO: u64 = 0x9e3779b97f4a7c15;
+        let s = seed as u64 ^ SILVER_RATIO;
+        Self::new_unchecked([mix(s), mix(s.wrapping_add(GOLDEN_RATIO))])
+    }
+}
+
+impl_rng_core_u64!(Xoroshiro128PlusPlusCore);
+impl_bytes!(Xoroshiro128PlusPlusCore, read_u64s, write_u64s, 16);
+impl_zeroize!(Xoroshiro128PlusPlusCore);
+impl_pod!(Xoroshiro128PlusPlusCore);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference() {
+        let mut rng = Xoroshiro128PlusPlus::from_seed(
+            [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]);
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoroshiro128plusplus.c
+        let expected = [
+            393217, 669327710093319, 1732421326133921491, 11394790081659126983,
+            9555452776773192676, 3586421180005889563, 1691397964866707553,
+            10735626796753111697, 15216282715349408991, 14247243556711267923,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn jump() {
+        let mut rng = Xoroshiro128PlusPlus::from_seed(
+            [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]);
+        rng.jump();
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoroshiro128plusplus.c
+        let expected = [
+            6995778298204176446, 17606341508358386873, 18268233585225622342,
+            1634122034616564957, 1545814713924780798, 2325680523787453464,
+            6135803246428234680, 7282039823769053662, 14713893908791078219,
+            14773603201369657697,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn long_jump() {
+        let mut rng = Xoroshiro128PlusPlus::from_seed(
+            [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]);
+        rng.long_jump();
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoroshiro128plusplus.c
+        let expected = [
+            13476878559037916028, 4599739792799904096, 9592342027630475676,
+            16396948912373680941, 3683122285862085747, 17612707734963284936,
+            10775282888249997651, 11228019977563137881, 11445248118393287881,
+            8937479946026735686,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn java() {
+        // Generated with OpenJDK 17:
+        // RandomGeneratorFactory.of("Xoroshiro128PlusPlus").create(seed)
+        // followed by 3 x nextLong(), nextInt() and nextDouble().
+        let expected: [(i64, [u64; 3], i32, f64); 3] = [
+            (0, [0x2a2ca488f66f517e, 0xccbc22d72e97c372, 0x404e64b826f4b9f4],
+             503047592, 0.0997124786680137),
+            (42, [0xbed4a3d469c5d91f, 0x65e301cb50e8f4ab, 0x9752d3d4db9a2abd],
+             1138283283, 0.45643019714958855),
+            (-1, [0x8796dbdfa97705d3, 0xf3f229bc77c85f24, 0xa8215b2e369f765a],
+             -481608198, 0.695010951770786),
+        ];
+        for &(seed, longs, int, double) in &expected {
+            let mut rng = Xoroshiro128PlusPlus::from_java_seed(seed);
+            for &x in &longs {
+                assert_eq!(rng.next_u64(), x);
+            }
+            assert_eq!((rng.next_u64() >> 32) as i32, int);
+            assert_eq!(rng.next_f64(), double);
+        }
+
+        // `jump()` and `leap()` in Java.
+        let mut rng = Xoroshiro128PlusPlus::from_java_seed(42);
+        rng.jump();
+        assert_eq!(rng.next_u64(), 0x7d21df1fa9d5e89d);
+        rng.long_jump();
+        assert_eq!(rng.next_u64(), 0x92fe4c5b14811b4a);
+    }
+
+    #[test]
+    fn advance() {
+        let mut rng1 = Xoroshiro128PlusPlus::from_seed_u64(0);
+        let mut rng2 = rng1.clone();
+        for _ in 0..100 {
+            rng1.next_u64();
+        }
+        rng2.advance(100);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+
+        rng1.jump();
+        rng2.advance(1 << 64);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn jump_back() {
+        let mut rng1 = Xoroshiro128PlusPlus::from_seed_u64(0);
+        let mut rng2 = rng1.clone();
+        rng2.jump();
+        rng2.jump_back();
+        rng2.long_jump_back();
+        rng2.long_jump();
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+
+        let mut rng3 = rng1.clone();
+        rng1.next_u64();
+        rng1.prev_u64();
+        assert_eq!(rng1, rng3);
+        rng3.jump_n(3);
+        for _ in 0..3 {
+            rng1.jump();
+        }
+        assert_eq!(rng1, rng3);
+    }
+}
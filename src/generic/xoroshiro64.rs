@@ -0,0 +1,439 @@
+use rand_core::{RngCore, SeedableRng};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use poly;
+use common::{read_u32s, write_u32s};
+use splitmix64::splitmix_states;
+use super::{Core, Scrambler, Xoshiro, Star, StarStar};
+
+/// The xoroshiro64 linear engine.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "copy", derive(Copy))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Xoroshiro64Core {
+    pub(crate) s: [u32; 2],
+}
+
+impl Core for Xoroshiro64Core {
+    const NAME: &'static str = "xoroshiro64";
+    const POLY: &'static [u64] = &poly::XOROSHIRO64;
+    const JUMP_LOG2: u32 = 32;
+
+    type Word = u32;
+    type State = [u32; 2];
+
+    #[inline]
+    fn state(&self) -> &[u32; 2] {
+        &self.s
+    }
+
+    #[inline]
+    fn state_mut(&mut self) -> &mut [u32; 2] {
+        &mut self.s
+    }
+
+    #[inline]
+    fn step(&mut self) {
+        impl_xoroshiro_u32!(self);
+    }
+
+    #[inline]
+    fn step_back(&mut self) {
+        impl_xoroshiro_u32_inv!(self);
+    }
+}
+
+impl Scrambler<Xoroshiro64Core> for Star {
+    const NAME: &'static str = "*";
+
+    #[inline]
+    fn scramble(s: &[u32; 2]) -> u32 {
+        s[0].wrapping_mul(0x9E3779BB)
+    }
+}
+
+impl Scrambler<Xoroshiro64Core> for StarStar {
+    const NAME: &'static str = "**";
+
+    #[inline]
+    fn scramble(s: &[u32; 2]) -> u32 {
+        starstar_u32!(s[0])
+    }
+}
+
+/// A xoroshiro64* random number generator.
+///
+/// The xoroshiro64* algorithm is not suitable for cryptographic purposes, but
+/// is very fast and has excellent statistical properties.
+///
+/// The algorithm used here is translated from [the `xoroshiro64star.c`
+/// reference source code](http://xoshiro.di.unimi.it/xoroshiro64star.c) by
+/// David Blackman and Sebastiano Vigna.
+pub type Xoroshiro64Star = Xoshiro<Xoroshiro64Core, Star>;
+
+/// A Xoroshiro64** random number generator.
+///
+/// The Xoroshiro64** algorithm is not suitable for cryptographic purposes, but
+/// is very fast and has better statistical properties than `XorShiftRng`.  If
+/// you do not know for sure that it fits your requirements, use a more secure
+/// one such as `IsaacRng` or `OsRng`.
+///
+/// The algorithm used here is translated from [the `xoroshiro64starstar.c`
+/// reference source code](http://xoshiro.di.unimi.it/xoroshiro64starstar.c) by
+/// David Blackman and Sebastiano Vigna.
+pub type Xoroshiro64StarStar = Xoshiro<Xoroshiro64Core, StarStar>;
+
+impl<S> Xoshiro<Xoroshiro64Core, S> {
+    /// Polynomial used by `jump()`.
+    pub const JUMP: [u32; 2] = [0x77fcd1a0, 0x4cbf99bd];
+
+    /// Polynomial used by `jump_back()`.
+    pub const JUMP_BACK: [u32; 2] = [0x55ac25cf, 0xacff1279];
+
+    /// Create a generator from its state words.
+    ///
+    /// The state must not be entirely zero, which is not checked.
+    pub const fn new_unchecked(s: [u32; 2]) -> Self {
+        Xoshiro::from_core(Xoroshiro64Core { s })
+    }
+
+    /// Seed the generator from a `u64`, which is used as the state.
+    ///
+    /// A zero seed, which is not a valid state, is replaced by the first
+    /// output of `SplitMix64::from_seed_u64(0)`, like in `from_seed()`.
+    pub const fn from_seed_u64(seed: u64) -> Self {
+        let seed = if seed == 0 {
+            let [x] = splitmix_states::<1>(0);
+            x
+        } else {
+            seed
+        };
+        Self::new_unchecked([seed as u32, (seed >> 32) as u32])
+    }
+
+    /// Jump forward, equivalently to 2^32 calls to `next_u32()`.
+    ///
+    /// This can be used to generate 2^32 non-overlapping subsequences for
+    /// small-scale parallel computations.
+    ///
+    /// The reference implementation does not provide a jump function for this
+    /// generator. The polynomial used here is x^(2^32) modulo the
+    /// characteristic polynomial of the xoroshiro64 linear engine.
+    ///
+    /// ```
+    /// # extern crate rand;
+    /// # extern crate xoshiro;
+    /// # fn main() {
+    /// use rand::SeedableRng;
+    /// use xoshiro::Xoroshiro64StarStar;
+    ///
+    /// let rng1 = Xoroshiro64StarStar::from_seed_u64(1);
+    /// let mut rng2 = rng1.clone();
+    /// rng2.jump();
+    /// let mut rng3 = rng2.clone();
+    /// rng3.jump();
+    /// # }
+    /// ```
+    pub fn jump(&mut self) {
+        self.jump_with_polynomial(&Self::JUMP);
+    }
+
+    /// Jump backward, equivalently to 2^32 calls to `next_u32()` in reverse.
+    ///
+    /// This undoes `jump()`. Because the period of the generator is 2^64 - 1,
+    /// this is the same as jumping forward by 2^64 - 1 - 2^32 steps.
+    pub fn jump_back(&mut self) {
+        self.jump_with_polynomial(&Self::JUMP_BACK);
+    }
+}
+
+impl<S> SeedableRng for Xoshiro<Xoroshiro64Core, S> {
+    type Seed = [u8; 8];
+
+    /// Create a new generator.
+    ///
+    /// An all-zero seed, which is not a valid state, is replaced by the first
+    /// output of `SplitMix64::from_seed_u64(0)`.
+    fn from_seed(seed: [u8; 8]) -> Self {
+        if seed == [0; 8] {
+            return Self::from_seed_u64(0);
+        }
+        Self::new_unchecked(read_u32s(&seed))
+    }
+
+    /// Seed the generator like `from_seed_u64()`, instead of the PCG-based
+    /// expansion of the default implementation.
+    fn seed_from_u64(seed: u64) -> Self {
+        Self::from_seed_u64(seed)
+    }
+}
+
+impl<S> From<[u32; 2]> for Xoshiro<Xoroshiro64Core, S> {
+    /// Create a new generator from state words.
+    ///
+    /// This is equivalent to `from_seed()` with the words in little-endian
+    /// order, so an all-zero state is replaced as well.
+    fn from(words: [u32; 2]) -> Self {
+        let mut seed = [0; 8];
+        write_u32s(&words, &mut seed);
+        Self::from_seed(seed)
+    }
+}
+
+impl_rng_core_u32!(Xoroshiro64Core);
+impl_bytes!(Xoroshiro64Core, read_u32s, write_u32s, 8);
+impl_zeroize!(Xoroshiro64Core);
+impl_pod!(Xoroshiro64Core);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn star_reference() {
+        let mut rng = Xoroshiro64Star::from_seed([1, 0, 0, 0, 2, 0, 0, 0]);
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoshiro64star.c
+        let expected = [
+            2654435771, 327208753, 4063491769, 4259754937, 261922412, 168123673,
+            552743735, 1672597395, 1031040050, 2755315674,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u32(), e);
+        }
+    }
+
+    #[test]
+    fn star_jump() {
+        let mut rng = Xoroshiro64Star::from_seed([1, 0, 0, 0, 2, 0, 0, 0]);
+        rng.jump();
+        // These values were produced by calling `next()` 2^32 times with the
+        // reference implementation:
+        // http://xoshiro.di.unimi.it/xoroshiro64star.c
+        let expected = [
+            821372760, 1075077864, 4038676641, 2690859811, 3657590416,
+            1027264865, 1658893107, 1680153506, 3218444389, 2381839082,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u32(), e);
+        }
+    }
+
+    #[test]
+    fn star_advance() {
+        let mut rng1 = Xoroshiro64Star::from_seed_u64(1);
+        let mut rng2 = rng1.clone();
+        for _ in 0..100 {
+            rng1.next_u32();
+        }
+        rng2.advance(100);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u32(), rng2.next_u32());
+        }
+
+        rng1.jump();
+        rng2.advance(1 << 32);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u32(), rng2.next_u32());
+        }
+    }
+
+    #[test]
+    fn star_jump_back() {
+        let mut rng1 = Xoroshiro64Star::from_seed_u64(1);
+        let mut rng2 = rng1.clone();
+        rng2.jump();
+        rng2.jump_back();
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u32(), rng2.next_u32());
+        }
+    }
+
+    #[test]
+    fn star_prev() {
+        let mut rng = Xoroshiro64Star::from_seed_u64(1);
+        let values: Vec<_> = (0..10).map(|_| rng.next_u32()).collect();
+        for &v in values.iter().rev() {
+            assert_eq!(rng.prev_u32(), v);
+        }
+        for &v in &values {
+            assert_eq!(rng.next_u32(), v);
+        }
+        let x = rng.next_u64();
+        assert_eq!(rng.prev_u64(), x);
+    }
+
+    #[test]
+    fn star_nth_stream() {
+        let mut rng1 = Xoroshiro64Star::from_seed_u64(1);
+        for _ in 0..3 {
+            rng1.jump();
+        }
+        let mut rng2 = Xoroshiro64Star::nth_stream(1, 3);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u32(), rng2.next_u32());
+        }
+    }
+
+    #[test]
+    fn star_zero_seed() {
+        const RNG: Xoroshiro64Star = Xoroshiro64Star::from_seed_u64(0);
+        assert_eq!(RNG, Xoroshiro64Star::from_seed([0; 8]));
+        assert_eq!(RNG, Xoroshiro64Star::from_seed_u64(0xe220a8397b1dcdaf));
+        let mut rng = Xoroshiro64Star::from_seed_u64(0);
+        rng.jump_n(2);
+        assert_eq!(Xoroshiro64Star::nth_stream(0, 2), rng);
+    }
+
+    #[test]
+    fn star_jump_with_polynomial() {
+        let mut rng1 = Xoroshiro64Star::from_seed_u64(1);
+        let mut rng2 = rng1.clone();
+        rng1.jump();
+        rng2.jump_with_polynomial(&Xoroshiro64Star::JUMP);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u32(), rng2.next_u32());
+        }
+        // x^3 corresponds to three steps.
+        rng1.jump_with_polynomial(&[0b1000]);
+        for _ in 0..3 {
+            rng2.next_u32();
+        }
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u32(), rng2.next_u32());
+        }
+    }
+
+    #[test]
+    fn star_const_construction() {
+        const RNG: Xoroshiro64Star = Xoroshiro64Star::from_seed_u64(1);
+        let mut rng1 = RNG;
+        let mut rng2 = Xoroshiro64Star::from_seed(1u64.to_le_bytes());
+        let mut rng3 = Xoroshiro64Star::new_unchecked([1, 0]);
+        for _ in 0..10 {
+            let x = rng1.next_u64();
+            assert_eq!(x, rng2.next_u64());
+            assert_eq!(x, rng3.next_u64());
+        }
+    }
+
+    #[test]
+    fn starstar_reference() {
+        let mut rng = Xoroshiro64StarStar::from_seed([1, 0, 0, 0, 2, 0, 0, 0]);
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoshiro64starstar.c
+        let expected = [
+            3802928447, 813792938, 1618621494, 2955957307, 3252880261,
+            1129983909, 2539651700, 1327610908, 1757650787, 2763843748,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u32(), e);
+        }
+    }
+
+    #[test]
+    fn starstar_jump() {
+        let mut rng = Xoroshiro64StarStar::from_seed([1, 0, 0, 0, 2, 0, 0, 0]);
+        rng.jump();
+        // These values were produced by calling `next()` 2^32 times with the
+        // reference implementation:
+        // http://xoshiro.di.unimi.it/xoroshiro64starstar.c
+        let expected = [
+            2570622750, 213766440, 1943168310, 1040840260, 1098914439,
+            1153621187, 3429892124, 2536588668, 3849994131, 3137131157,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u32(), e);
+        }
+    }
+
+    #[test]
+    fn starstar_advance() {
+        let mut rng1 = Xoroshiro64StarStar::from_seed_u64(1);
+        let mut rng2 = rng1.clone();
+        for _ in 0..100 {
+            rng1.next_u32();
+        }
+        rng2.advance(100);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u32(), rng2.next_u32());
+        }
+
+        rng1.jump();
+        rng2.advance(1 << 32);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u32(), rng2.next_u32());
+        }
+    }
+
+    #[test]
+    fn starstar_jump_back() {
+        let mut rng1 = Xoroshiro64StarStar::from_seed_u64(1);
+        let mut rng2 = rng1.clone();
+        rng2.jump();
+        rng2.jump_back();
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u32(), rng2.next_u32());
+        }
+    }
+
+    #[test]
+    fn starstar_prev() {
+        let mut rng = Xoroshiro64StarStar::from_seed_u64(1);
+        let values: Vec<_> = (0..10).map(|_| rng.next_u32()).collect();
+        for &v in values.iter().rev() {
+            assert_eq!(rng.prev_u32(), v);
+        }
+        for &v in &values {
+            assert_eq!(rng.next_u32(), v);
+        }
+        let x = rng.next_u64();
+        assert_eq!(rng.prev_u64(), x);
+    }
+
+    #[test]
+    fn starstar_nth_stream() {
+        let mut rng1 = Xoroshiro64StarStar::from_seed_u64(1);
+        for _ in 0..3 {
+            rng1.jump();
+        }
+        let mut rng2 = Xoroshiro64StarStar::nth_stream(1, 3);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u32(), rng2.next_u32());
+        }
+    }
+
+    #[test]
+    fn starstar_jump_with_polynomial() {
+        let mut rng1 = Xoroshiro64StarStar::from_seed_u64(1);
+        let mut rng2 = rng1.clone();
+        rng1.jump();
+        rng2.jump_with_polynomial(&Xoroshiro64StarStar::JUMP);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u32(), rng2.next_u32());
+        }
+        // x^3 corresponds to three steps.
+        rng1.jump_with_polynomial(&[0b1000]);
+        for _ in 0..3 {
+            rng2.next_u32();
+        }
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u32(), rng2.next_u32());
+        }
+    }
+
+    #[test]
+    fn starstar_const_construction() {
+        const RNG: Xoroshiro64StarStar = Xoroshiro64StarStar::from_seed_u64(1);
+        let mut rng1 = RNG;
+        let mut rng2 = Xoroshiro64StarStar::from_seed(1u64.to_le_bytes());
+        let mut rng3 = Xoroshiro64StarStar::new_unchecked([1, 0]);
+        for _ in 0..10 {
+            let x = rng1.next_u64();
+            assert_eq!(x, rng2.next_u64());
+            assert_eq!(x, rng3.next_u64());
+        }
+    }
+}
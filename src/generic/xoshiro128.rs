@@ -0,0 +1,595 @@
+use rand_core::{RngCore, SeedableRng};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use poly;
+use common::{read_u32s, write_u32s};
+use splitmix64::splitmix_states;
+use super::{Core, Scrambler, Xoshiro, Plus, PlusPlus, StarStar};
+
+/// The xoshiro128 linear engine.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "copy", derive(Copy))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Xoshiro128Core {
+    pub(crate) s: [u32; 4],
+}
+
+impl Core for Xoshiro128Core {
+    const NAME: &'static str = "xoshiro128";
+    const POLY: &'static [u64] = &poly::XOSHIRO128;
+    const JUMP_LOG2: u32 = 64;
+
+    type Word = u32;
+    type State = [u32; 4];
+
+    #[inline]
+    fn state(&self) -> &[u32; 4] {
+        &self.s
+    }
+
+    #[inline]
+    fn state_mut(&mut self) -> &mut [u32; 4] {
+        &mut self.s
+    }
+
+    #[inline]
+    fn step(&mut self) {
+        impl_xoshiro_u32!(self);
+    }
+
+    #[inline]
+    fn step_back(&mut self) {
+        impl_xoshiro_u32_inv!(self);
+    }
+}
+
+impl Scrambler<Xoshiro128Core> for Plus {
+    const NAME: &'static str = "+";
+
+    #[inline]
+    fn scramble(s: &[u32; 4]) -> u32 {
+        s[0].wrapping_add(s[3])
+    }
+}
+
+impl Scrambler<Xoshiro128Core> for StarStar {
+    const NAME: &'static str = "**";
+
+    #[inline]
+    fn scramble(s: &[u32; 4]) -> u32 {
+        starstar_u64!(s[0])
+    }
+}
+
+impl Scrambler<Xoshiro128Core> for PlusPlus {
+    const NAME: &'static str = "++";
+
+    #[inline]
+    fn scramble(s: &[u32; 4]) -> u32 {
+        (s[0].wrapping_add(s[3])).rotate_left(7).wrapping_add(s[0])
+    }
+}
+
+/// A xoshiro128+ random number generator.
+///
+/// The xoshiro128+ algorithm is not suitable for cryptographic purposes, but
+/// is very fast and has good statistical properties, besides a low linear
+/// complexity in the lowest bits.
+///
+/// The algorithm used here is translated from [the `xoshiro128plus.c`
+/// reference source code](http://xoshiro.di.unimi.it/xoshiro128plus.c) by
+/// David Blackman and Sebastiano Vigna.
+pub type Xoshiro128Plus = Xoshiro<Xoshiro128Core, Plus>;
+
+/// A xoshiro128** random number generator.
+///
+/// The xoshiro128** algorithm is not suitable for cryptographic purposes, but
+/// is very fast and has excellent statistical properties.
+///
+/// The algorithm used here is translated from [the `xoshiro128starstar.c`
+/// reference source code](http://xoshiro.di.unimi.it/xoshiro128starstar.c) by
+/// David Blackman and Sebastiano Vigna. This is version 1.0 of the algorithm,
+/// which scrambles the first state word. Version 1.1 of the reference source
+/// code scrambles the second one and produces different outputs.
+pub type Xoshiro128StarStar = Xoshiro<Xoshiro128Core, StarStar>;
+
+/// A xoshiro128++ random number generator.
+///
+/// The xoshiro128++ algorithm is not suitable for cryptographic purposes, but
+/// is very fast and has excellent statistical properties.
+///
+/// The algorithm used here is translated from [the `xoshiro128plusplus.c`
+/// reference source code](http://xoshiro.di.unimi.it/xoshiro128plusplus.c) by
+/// David Blackman and Sebastiano Vigna.
+pub type Xoshiro128PlusPlus = Xoshiro<Xoshiro128Core, PlusPlus>;
+
+/// A stand-in for `XorShiftRng` of `rand`, to ease migrating from it.
+///
+/// Replacing `rand::XorShiftRng` by this alias keeps code compiling, with a
+/// deprecation warning. It is not a xorshift generator but
+/// `Xoshiro128PlusPlus`, which has the same state size and output type, but
+/// its output differs from `rand::XorShiftRng` for every seed. Use
+/// `Xoshiro128PlusPlus` directly, and convert checkpointed states with
+/// `from_xorshift_state()`.
+#[deprecated(note = "this is `Xoshiro128PlusPlus`, not xorshift: its output \
+                     differs from `rand::XorShiftRng` for the same seed. Use \
+                     `Xoshiro128PlusPlus` directly and convert xorshift \
+                     states with `from_xorshift_state()`")]
+pub type XorShiftRng = Xoshiro<Xoshiro128Core, PlusPlus>;
+
+impl<S> Xoshiro<Xoshiro128Core, S> {
+    /// Polynomial used by `jump()`.
+    pub const JUMP: [u32; 4] = [0x8764000b, 0xf542d2d3, 0x6fa035c3, 0x77f2db5b];
+
+    /// Polynomial used by `long_jump()`.
+    pub const LONG_JUMP: [u32; 4] = [0xb523952e, 0x0b6f099f, 0xccf5a0ef, 0x1c580662];
+
+    /// Polynomial used by `jump_back()`.
+    pub const JUMP_BACK: [u32; 4] = [0x1563698e, 0xca2fcd71, 0x7e3baedd, 0x3567bd8e];
+
+    /// Polynomial used by `long_jump_back()`.
+    pub const LONG_JUMP_BACK: [u32; 4] = [0x7472ccdb, 0xc9ed50bd, 0x5e99cf9e, 0xfb50950a];
+
+    /// Create a generator from its state words.
+    ///
+    /// The state must not be entirely zero, which is not checked.
+    pub const fn new_unchecked(s: [u32; 4]) -> Self {
+        Xoshiro::from_core(Xoshiro128Core { s })
+    }
+
+    /// Seed the generator from a `u64` using `SplitMix64`.
+    pub const fn from_seed_u64(seed: u64) -> Self {
+        let s = splitmix_states::<2>(seed);
+        Self::new_unchecked([
+            s[0] as u32, (s[0] >> 32) as u32,
+            s[1] as u32, (s[1] >> 32) as u32,
+        ])
+    }
+
+    /// Jump forward, equivalently to 2^64 calls to `next_u32()`.
+    ///
+    /// This can be used to generate 2^64 non-overlapping subsequences for
+    /// parallel computations.
+    ///
+    /// ```
+    /// # extern crate rand;
+    /// # extern crate xoshiro;
+    /// # fn main() {
+    /// use rand::SeedableRng;
+    /// use xoshiro::Xoshiro128StarStar;
+    ///
+    /// let rng1 = Xoshiro128StarStar::from_seed_u64(0);
+    /// let mut rng2 = rng1.clone();
+    /// rng2.jump();
+    /// let mut rng3 = rng2.clone();
+    /// rng3.jump();
+    /// # }
+    /// ```
+    pub fn jump(&mut self) {
+        self.jump_with_polynomial(&Self::JUMP);
+    }
+
+    /// Jump forward, equivalently to 2^96 calls to `next_u32()`.
+    ///
+    /// This can be used to generate 2^32 starting points, from each of which
+    /// `jump()` will generate 2^32 non-overlapping subsequences for parallel
+    /// distributed computations.
+    pub fn long_jump(&mut self) {
+        self.jump_with_polynomial(&Self::LONG_JUMP);
+    }
+
+    /// Jump backward, equivalently to 2^64 calls to `next_u32()` in reverse.
+    ///
+    /// This undoes `jump()`. Because the period of the generator is 2^128 - 1,
+    /// this is the same as jumping forward by 2^128 - 1 - 2^64 steps.
+    pub fn jump_back(&mut self) {
+        self.jump_with_polynomial(&Self::JUMP_BACK);
+    }
+
+    /// Jump backward, equivalently to 2^96 calls to `next_u32()` in reverse.
+    ///
+    /// This undoes `long_jump()`. Because the period of the generator is
+    /// 2^128 - 1, this is the same as jumping forward by
+    /// 2^128 - 1 - 2^96 steps.
+    pub fn long_jump_back(&mut self) {
+        self.jump_with_polynomial(&Self::LONG_JUMP_BACK);
+    }
+}
+
+impl<S> SeedableRng for Xoshiro<Xoshiro128Core, S> {
+    type Seed = [u8; 16];
+
+    /// Create a new generator.
+    ///
+    /// An all-zero seed, which is not a valid state, is replaced by the state
+    /// of `from_seed_u64(0)`.
+    #[inline]
+    fn from_seed(seed: [u8; 16]) -> Self {
+        if seed == [0; 16] {
+            return Self::from_seed_u64(0);
+        }
+        Self::new_unchecked(read_u32s(&seed))
+    }
+
+    /// Seed the generator like `from_seed_u64()`, instead of the PCG-based
+    /// expansion of the default implementation.
+    fn seed_from_u64(seed: u64) -> Self {
+        Self::from_seed_u64(seed)
+    }
+}
+
+impl<S> From<[u32; 4]> for Xoshiro<Xoshiro128Core, S> {
+    /// Create a new generator from state words.
+    ///
+    /// This is equivalent to `from_seed()` with the words in little-endian
+    /// order, so an all-zero state is replaced as well.
+    fn from(words: [u32; 4]) -> Self {
+        let mut seed = [0; 16];
+        write_u32s(&words, &mut seed);
+        Self::from_seed(seed)
+    }
+}
+
+impl_rng_core_u32!(Xoshiro128Core);
+impl_bytes!(Xoshiro128Core, read_u32s, write_u32s, 16);
+impl_zeroize!(Xoshiro128Core);
+impl_pod!(Xoshiro128Core);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plus_reference() {
+        let mut rng = Xoshiro128Plus::from_seed(
+            [1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 4, 0, 0, 0]);
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoshiro128plus.c
+        let expected = [
+            5, 12295, 25178119, 27286542, 39879690, 1140358681, 3276312097,
+            4110231701, 399823256, 2144435200,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u32(), e);
+        }
+    }
+
+    #[test]
+    fn plus_jump() {
+        let mut rng = Xoshiro128Plus::from_seed(
+            [1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 4, 0, 0, 0]);
+        rng.jump();
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoshiro128plus.c
+        let expected = [
+            2887920503, 1583871485, 1223031203, 350630958, 1275758957,
+            3520893674, 2089251829, 539808940, 1683025647, 1353992420,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u32(), e);
+        }
+    }
+
+    #[test]
+    fn plus_long_jump() {
+        let mut rng = Xoshiro128Plus::from_seed(
+            [1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 4, 0, 0, 0]);
+        rng.long_jump();
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoshiro128plus.c
+        let expected = [
+            510881524, 4189888193, 4065621604, 3656018618, 2940187679,
+            3975336349, 491853863, 1796559228, 3497626486, 3939701726,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u32(), e);
+        }
+    }
+
+    #[test]
+    fn plus_advance() {
+        let mut rng1 = Xoshiro128Plus::from_seed_u64(0);
+        let mut rng2 = rng1.clone();
+        for _ in 0..100 {
+            rng1.next_u32();
+        }
+        rng2.advance(100);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u32(), rng2.next_u32());
+        }
+
+        rng1.jump();
+        rng2.advance(1 << 64);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u32(), rng2.next_u32());
+        }
+    }
+
+    #[test]
+    fn plus_jump_back() {
+        let mut rng1 = Xoshiro128Plus::from_seed_u64(0);
+        let mut rng2 = rng1.clone();
+        rng2.jump();
+        rng2.jump_back();
+        rng2.long_jump_back();
+        rng2.long_jump();
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u32(), rng2.next_u32());
+        }
+    }
+
+    #[test]
+    fn plus_prev() {
+        let mut rng = Xoshiro128Plus::from_seed_u64(0);
+        let values: Vec<_> = (0..10).map(|_| rng.next_u32()).collect();
+        for &v in values.iter().rev() {
+            assert_eq!(rng.prev_u32(), v);
+        }
+        for &v in &values {
+            assert_eq!(rng.next_u32(), v);
+        }
+        let x = rng.next_u64();
+        assert_eq!(rng.prev_u64(), x);
+    }
+
+    #[test]
+    fn plus_nth_stream() {
+        let mut rng1 = Xoshiro128Plus::from_seed_u64(0);
+        for _ in 0..3 {
+            rng1.jump();
+        }
+        let mut rng2 = Xoshiro128Plus::nth_stream(0, 3);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u32(), rng2.next_u32());
+        }
+    }
+
+    #[test]
+    fn plus_jump_with_polynomial() {
+        let mut rng1 = Xoshiro128Plus::from_seed_u64(0);
+        let mut rng2 = rng1.clone();
+        rng1.jump();
+        rng2.jump_with_polynomial(&Xoshiro128Plus::JUMP);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u32(), rng2.next_u32());
+        }
+        // x^3 corresponds to three steps.
+        rng1.jump_with_polynomial(&[0b1000]);
+        for _ in 0..3 {
+            rng2.next_u32();
+        }
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u32(), rng2.next_u32());
+        }
+    }
+
+    #[test]
+    fn plus_const_construction() {
+        use SplitMix64;
+
+        const RNG: Xoshiro128Plus = Xoshiro128Plus::from_seed_u64(1);
+        let mut rng1 = RNG;
+        let mut rng2 = Xoshiro128Plus::from_rng(SplitMix64::from_seed_u64(1)).unwrap();
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+
+        let mut seed = [0; 16];
+        for (i, chunk) in seed.chunks_mut(4).enumerate() {
+            chunk[0] = i as u8 + 1;
+        }
+        let mut rng1 = Xoshiro128Plus::new_unchecked([1, 2, 3, 4]);
+        let mut rng2 = Xoshiro128Plus::from_seed(seed);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn starstar_reference() {
+        let mut rng = Xoshiro128StarStar::from_seed(
+            [1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 4, 0, 0, 0]);
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoshiro128starstar.c
+        let expected = [
+            5760, 40320, 70819200, 3297914139, 2480851620, 1792823698,
+            4118739149, 1251203317, 1581886583, 1721184582,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u32(), e);
+        }
+    }
+
+    #[test]
+    fn starstar_jump() {
+        let mut rng = Xoshiro128StarStar::from_seed(
+            [1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 4, 0, 0, 0]);
+        rng.jump();
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoshiro128starstar.c
+        let expected = [
+            3862267999, 2972957182, 3753665397, 2404499961, 3095905765,
+            3750650679, 1749187135, 428690784, 2126145496, 1502595346,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u32(), e);
+        }
+    }
+
+    #[test]
+    fn starstar_long_jump() {
+        let mut rng = Xoshiro128StarStar::from_seed(
+            [1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 4, 0, 0, 0]);
+        rng.long_jump();
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoshiro128starstar.c
+        let expected = [
+            3513047792, 2042386850, 2422613406, 2681956245, 3365431743,
+            1077385457, 2974646342, 3050854456, 2128142869, 3337680347,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u32(), e);
+        }
+    }
+
+    #[test]
+    fn starstar_advance() {
+        let mut rng1 = Xoshiro128StarStar::from_seed_u64(0);
+        let mut rng2 = rng1.clone();
+        for _ in 0..100 {
+            rng1.next_u32();
+        }
+        rng2.advance(100);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u32(), rng2.next_u32());
+        }
+
+        rng1.jump();
+        rng2.advance(1 << 64);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u32(), rng2.next_u32());
+        }
+    }
+
+    #[test]
+    fn starstar_jump_back() {
+        let mut rng1 = Xoshiro128StarStar::from_seed_u64(0);
+        let mut rng2 = rng1.clone();
+        rng2.jump();
+        rng2.jump_back();
+        rng2.long_jump_back();
+        rng2.long_jump();
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u32(), rng2.next_u32());
+        }
+    }
+
+    #[test]
+    fn starstar_prev() {
+        let mut rng = Xoshiro128StarStar::from_seed_u64(0);
+        let values: Vec<_> = (0..10).map(|_| rng.next_u32()).collect();
+        for &v in values.iter().rev() {
+            assert_eq!(rng.prev_u32(), v);
+        }
+        for &v in &values {
+            assert_eq!(rng.next_u32(), v);
+        }
+        let x = rng.next_u64();
+        assert_eq!(rng.prev_u64(), x);
+    }
+
+    #[test]
+    fn starstar_nth_stream() {
+        let mut rng1 = Xoshiro128StarStar::from_seed_u64(0);
+        for _ in 0..3 {
+            rng1.jump();
+        }
+        let mut rng2 = Xoshiro128StarStar::nth_stream(0, 3);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u32(), rng2.next_u32());
+        }
+    }
+
+    #[test]
+    fn starstar_jump_with_polynomial() {
+        let mut rng1 = Xoshiro128StarStar::from_seed_u64(0);
+        let mut rng2 = rng1.clone();
+        rng1.jump();
+        rng2.jump_with_polynomial(&Xoshiro128StarStar::JUMP);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u32(), rng2.next_u32());
+        }
+        // x^3 corresponds to three steps.
+        rng1.jump_with_polynomial(&[0b1000]);
+        for _ in 0..3 {
+            rng2.next_u32();
+        }
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u32(), rng2.next_u32());
+        }
+    }
+
+    #[test]
+    fn starstar_const_construction() {
+        use SplitMix64;
+
+        const RNG: Xoshiro128StarStar = Xoshiro128StarStar::from_seed_u64(1);
+        let mut rng1 = RNG;
+        let mut rng2 = Xoshiro128StarStar::from_rng(SplitMix64::from_seed_u64(1)).unwrap();
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+
+        let mut seed = [0; 16];
+        for (i, chunk) in seed.chunks_mut(4).enumerate() {
+            chunk[0] = i as u8 + 1;
+        }
+        let mut rng1 = Xoshiro128StarStar::new_unchecked([1, 2, 3, 4]);
+        let mut rng2 = Xoshiro128StarStar::from_seed(seed);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn plusplus_reference() {
+        let mut rng = Xoshiro128PlusPlus::from_seed(
+            [1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 4, 0, 0, 0]);
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoshiro128plusplus.c
+        let expected = [641, 1573767, 3222811527, 3517856514, 836907274, 4247214768, 3867114732, 1355841295, 495546011, 621204420];
+        for &e in &expected {
+            assert_eq!(rng.next_u32(), e);
+        }
+    }
+
+    #[test]
+    fn plusplus_jump() {
+        let mut rng = Xoshiro128PlusPlus::from_seed(
+            [1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 4, 0, 0, 0]);
+        rng.jump();
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoshiro128plusplus.c
+        let expected = [
+            3129740764, 111290574, 1158071106, 1835317750, 1792746261,
+            130735218, 326039554, 1024861044, 3423294072, 4108966210,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u32(), e);
+        }
+    }
+
+    #[test]
+    fn plusplus_long_jump() {
+        let mut rng = Xoshiro128PlusPlus::from_seed(
+            [1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 4, 0, 0, 0]);
+        rng.long_jump();
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoshiro128plusplus.c
+        let expected = [
+            2580293941, 2135890358, 163124449, 1843864296, 1015915734,
+            4033214785, 401910505, 1420128893, 3902659533, 508088920,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u32(), e);
+        }
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn from_xorshift_state() {
+        let state = [0x193a6754, 0xa8a7d469, 0x97830e05, 0x113ba7bb];
+        let rng = XorShiftRng::from_xorshift_state(state);
+        let mut bytes = b"xorshift".to_vec();
+        for w in &state {
+            bytes.extend_from_slice(&w.to_le_bytes());
+        }
+        assert_eq!(rng, Xoshiro128PlusPlus::from_seed_bytes(&bytes));
+        assert!(rng != Xoshiro128PlusPlus::from_xorshift_state([1, 0, 0, 0]));
+        // The state is not reinterpreted as a `new_stream()` seed.
+        assert!(Xoshiro128PlusPlus::from_xorshift_state([1, 2, 3, 4])
+                != Xoshiro128PlusPlus::new_stream(1 | 2 << 32, 3 | 4 << 32));
+    }
+}
@@ -0,0 +1,667 @@
+use rand_core::{RngCore, SeedableRng};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use poly;
+use common::{read_u64s, write_u64s};
+use splitmix64::{mix, splitmix_states};
+use super::{Core, Scrambler, Xoshiro, Plus, PlusPlus, StarStar};
+
+/// The xoshiro256 linear engine.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "copy", derive(Copy))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Xoshiro256Core {
+    pub(crate) s: [u64; 4],
+}
+
+impl Core for Xoshiro256Core {
+    const NAME: &'static str = "xoshiro256";
+    const POLY: &'static [u64] = &poly::XOSHIRO256;
+    const JUMP_LOG2: u32 = 128;
+
+    type Word = u64;
+    type State = [u64; 4];
+
+    #[inline]
+    fn state(&self) -> &[u64; 4] {
+        &self.s
+    }
+
+    #[inline]
+    fn state_mut(&mut self) -> &mut [u64; 4] {
+        &mut self.s
+    }
+
+    #[inline]
+    fn step(&mut self) {
+        impl_xoshiro_u64!(self);
+    }
+
+    #[inline]
+    fn step_back(&mut self) {
+        impl_xoshiro_u64_inv!(self);
+    }
+}
+
+impl Scrambler<Xoshiro256Core> for Plus {
+    const NAME: &'static str = "+";
+
+    const WEAK_LOW_BITS: bool = true;
+
+    #[inline]
+    fn scramble(s: &[u64; 4]) -> u64 {
+        s[0].wrapping_add(s[3])
+    }
+}
+
+impl Scrambler<Xoshiro256Core> for StarStar {
+    const NAME: &'static str = "**";
+
+    #[inline]
+    fn scramble(s: &[u64; 4]) -> u64 {
+        starstar_u64!(s[1])
+    }
+}
+
+impl Scrambler<Xoshiro256Core> for PlusPlus {
+    const NAME: &'static str = "++";
+
+    #[inline]
+    fn scramble(s: &[u64; 4]) -> u64 {
+        (s[0].wrapping_add(s[3])).rotate_left(23).wrapping_add(s[0])
+    }
+}
+
+/// A xoshiro256+ random number generator.
+///
+/// The xoshiro256+ algorithm is not suitable for cryptographic purposes, but
+/// is very fast and has good statistical properties, besides a low linear
+/// complexity in the lowest bits.
+///
+/// The algorithm used here is translated from [the `xoshiro256plus.c`
+/// reference source code](http://xoshiro.di.unimi.it/xoshiro256plus.c) by
+/// David Blackman and Sebastiano Vigna.
+pub type Xoshiro256Plus = Xoshiro<Xoshiro256Core, Plus>;
+
+/// A xoshiro256** random number generator.
+///
+/// The xoshiro256** algorithm is not suitable for cryptographic purposes, but
+/// is very fast and has excellent statistical properties.
+///
+/// The algorithm used here is translated from [the `xoshiro256starstar.c`
+/// reference source code](http://xoshiro.di.unimi.it/xoshiro256starstar.c) by
+/// David Blackman and Sebastiano Vigna.
+pub type Xoshiro256StarStar = Xoshiro<Xoshiro256Core, StarStar>;
+
+/// A xoshiro256++ random number generator.
+///
+/// The xoshiro256++ algorithm is not suitable for cryptographic purposes, but
+/// is very fast and has excellent statistical properties.
+///
+/// The algorithm used here is translated from [the `xoshiro256plusplus.c`
+/// reference source code](http://xoshiro.di.unimi.it/xoshiro256plusplus.c) by
+/// David Blackman and Sebastiano Vigna.
+pub type Xoshiro256PlusPlus = Xoshiro<Xoshiro256Core, PlusPlus>;
+
+impl<S> Xoshiro<Xoshiro256Core, S> {
+    /// Polynomial used by `jump()`.
+    pub const JUMP: [u64; 4] = [
+        0x180ec6d33cfd0aba, 0xd5a61266f0c9392c,
+        0xa9582618e03fc9aa, 0x39abdc4529b1661c,
+    ];
+
+    /// Polynomial used by `long_jump()`.
+    pub const LONG_JUMP: [u64; 4] = [
+        0x76e15d3efefdcbbf, 0xc5004e441c522fb3,
+        0x77710069854ee241, 0x39109bb02acbe635,
+    ];
+
+    /// Polynomial used by `jump_back()`.
+    pub const JUMP_BACK: [u64; 4] = [
+        0xe4f165c97b5587ed, 0xd272429846ce3eef,
+        0x227f6f8892c33391, 0xda8cc53a9a74b263,
+    ];
+
+    /// Polynomial used by `long_jump_back()`.
+    pub const LONG_JUMP_BACK: [u64; 4] = [
+        0xdd89caee7494f0dd, 0xa3e1251b87ed55eb,
+        0xac085ee412c9dd63, 0xb199717cbf73cc84,
+    ];
+
+    /// Create a generator from its state words.
+    ///
+    /// The state must not be entirely zero, which is not checked.
+    pub const fn new_unchecked(s: [u64; 4]) -> Self {
+        Xoshiro::from_core(Xoshiro256Core { s })
+    }
+
+    /// Seed the generator from a `u64` using `SplitMix64`.
+    pub const fn from_seed_u64(seed: u64) -> Self {
+        Self::new_unchecked(splitmix_states(seed))
+    }
+
+    /// Jump forward, equivalently to 2^128 calls to `next_u64()`.
+    ///
+    /// This can be used to generate 2^128 non-overlapping subsequences for
+    /// parallel computations.
+    ///
+    /// ```
+    /// # extern crate rand;
+    /// # extern crate xoshiro;
+    /// # fn main() {
+    /// use rand::SeedableRng;
+    /// use xoshiro::Xoshiro256StarStar;
+    ///
+    /// let rng1 = Xoshiro256StarStar::from_seed_u64(0);
+    /// let mut rng2 = rng1.clone();
+    /// rng2.jump();
+    /// let mut rng3 = rng2.clone();
+    /// rng3.jump();
+    /// # }
+    /// ```
+    pub fn jump(&mut self) {
+        self.jump_with_polynomial(&Self::JUMP);
+    }
+
+    /// Jump forward, equivalently to 2^192 calls to `next_u64()`.
+    ///
+    /// This can be used to generate 2^64 starting points, from each of which
+    /// `jump()` will generate 2^64 non-overlapping subsequences for parallel
+    /// distributed computations.
+    pub fn long_jump(&mut self) {
+        self.jump_with_polynomial(&Self::LONG_JUMP);
+    }
+
+    /// Jump backward, equivalently to 2^128 calls to `next_u64()` in reverse.
+    ///
+    /// This undoes `jump()`. Because the period of the generator is 2^256 - 1,
+    /// this is the same as jumping forward by 2^256 - 1 - 2^128 steps.
+    pub fn jump_back(&mut self) {
+        self.jump_with_polynomial(&Self::JUMP_BACK);
+    }
+
+    /// Jump backward, equivalently to 2^192 calls to `next_u64()` in reverse.
+    ///
+    /// This undoes `long_jump()`. Because the period of the generator is
+    /// 2^256 - 1, this is the same as jumping forward by
+    /// 2^256 - 1 - 2^192 steps.
+    pub fn long_jump_back(&mut self) {
+        self.jump_with_polynomial(&Self::LONG_JUMP_BACK);
+    }
+}
+
+impl<S> SeedableRng for Xoshiro<Xoshiro256Core, S> {
+    type Seed = [u8; 32];
+
+    /// Create a new generator.
+    ///
+    /// An all-zero seed, which is not a valid state, is replaced by the state
+    /// of `from_seed_u64(0)`.
+    #[inline]
+    fn from_seed(seed: [u8; 32]) -> Self {
+        if seed == [0; 32] {
+            return Self::from_seed_u64(0);
+        }
+        Self::new_unchecked(read_u64s(&seed))
+    }
+
+    /// Seed the generator like `from_seed_u64()`, instead of the PCG-based
+    /// expansion of the default implementation.
+    fn seed_from_u64(seed: u64) -> Self {
+        Self::from_seed_u64(seed)
+    }
+}
+
+impl<S> From<[u64; 4]> for Xoshiro<Xoshiro256Core, S> {
+    /// Create a new generator from state words.
+    ///
+    /// This is equivalent to `from_seed()` with the words in little-endian
+    /// order, so an all-zero state is replaced as well.
+    fn from(words: [u64; 4]) -> Self {
+        let mut seed = [0; 32];
+        write_u64s(&words, &mut seed);
+        Self::from_seed(seed)
+    }
+}
+
+impl Xoshiro<Xoshiro256Core, PlusPlus> {
+    /// Seed the generator like the constructor `Xoshiro256PlusPlus(long)` of
+    /// the `jdk.random` module of Java 17.
+    ///
+    /// Java xors the seed with `0x6a09e667f3bcc909` and expands it like
+    /// `SplitMix64`. The generator then reproduces the Java stream:
+    /// `nextLong()` is `next_u64() as i64`, `nextDouble()` is `next_f64()`,
+    /// `nextInt()` is the upper half `(next_u64() >> 32) as i32`, which
+    /// differs from `next_u32()`, and `jump()` and `long_jump()` match `jump()`
+    /// and `leap()`.
+    ///
+    /// For Java's `Xoroshiro128PlusPlus`, see
+    /// `Xoroshiro128PlusPlus::from_java_seed()`.
+    ///
+    /// ```
+    /// # extern crate rand_core;
+    /// # extern crate xoshiro;
+    /// # fn main() {
+    /// use rand_core::RngCore;
+    /// use xoshiro::Xoshiro256PlusPlus;
+    ///
+    /// // RandomGeneratorFactory.of("Xoshiro256PlusPlus").create(42)
+    /// let mut rng = Xoshiro256PlusPlus::from_java_seed(42);
+    /// assert_eq!(rng.next_u64(), 0xb3f4e5814323016c);
+    /// # }
+    /// ```
+    pub const fn from_java_seed(seed: i64) -> Self {
+        const SILVER_RATIO: u64 = 0x6a09e667f3bcc909;
+        const GOLDEN_RATIO: u64 = 0x9e3779b97f4a7c15;
+        let s = seed as u64 ^ SILVER_RATIO;
+        Self::new_unchecked([
+            mix(s),
+            mix(s.wrapping_add(GOLDEN_RATIO)),
+            mix(s.wrapping_add(GOLDEN_RATIO.wrapping_mul(2))),
+            mix(s.wrapping_add(GOLDEN_RATIO.wrapping_mul(3))),
+        ])
+    }
+}
+
+impl_rng_core_u64!(Xoshiro256Core);
+impl_bytes!(Xoshiro256Core, read_u64s, write_u64s, 32);
+impl_zeroize!(Xoshiro256Core);
+impl_pod!(Xoshiro256Core);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn java() {
+        // Generated with OpenJDK 17:
+        // RandomGeneratorFactory.of("Xoshiro256PlusPlus").create(seed)
+        // followed by 3 x nextLong(), nextInt() and nextDouble().
+        let expected: [(i64, [u64; 3], i32, f64); 3] = [
+            (0, [0x8cc8089a273ce493, 0x50b50939d50a527a, 0x90648ef3574c04f7],
+             -571931639, 0.10598546550119925),
+            (42, [0xb3f4e5814323016c, 0x55e937cf86c553c6, 0x1d0acbd9d261ee0a],
+             -776442268, 0.09305684931713143),
+            (-1, [0xeb8e3c2ea4a5e49c, 0x99e8caed7f52ed43, 0x2227f03a120e016e],
+             -61836312, 0.8447545219998007),
+        ];
+        for &(seed, longs, int, double) in &expected {
+            let mut rng = Xoshiro256PlusPlus::from_java_seed(seed);
+            for &x in &longs {
+                assert_eq!(rng.next_u64(), x);
+            }
+            assert_eq!((rng.next_u64() >> 32) as i32, int);
+            assert_eq!(rng.next_f64(), double);
+        }
+
+        // `jump()` and `leap()` in Java.
+        let mut rng = Xoshiro256PlusPlus::from_java_seed(42);
+        rng.jump();
+        assert_eq!(rng.next_u64(), 0x1c0933a3d71db8b6);
+        rng.long_jump();
+        assert_eq!(rng.next_u64(), 0x7c90a31e462fd303);
+    }
+
+    #[test]
+    fn plus_reference() {
+        let mut rng = Xoshiro256Plus::from_seed(
+            [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
+             3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0]);
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoshiro256plus.c
+        let expected = [
+            5, 211106232532999, 211106635186183, 9223759065350669058,
+            9250833439874351877, 13862484359527728515, 2346507365006083650,
+            1168864526675804870, 34095955243042024, 3466914240207415127,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn plus_jump() {
+        let mut rng = Xoshiro256Plus::from_seed(
+            [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
+             3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0]);
+        rng.jump();
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoshiro256plus.c
+        let expected = [
+            1153146630064993313, 12314415065245919719, 6215237862445749542,
+            16777907402320790505, 14817394491937344750, 2279703207880370032,
+            10926219597413161763, 10629096669736861998, 17316359193759225714,
+            11426814573291377579,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn plus_long_jump() {
+        let mut rng = Xoshiro256Plus::from_seed(
+            [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
+             3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0]);
+        rng.long_jump();
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoshiro256plus.c
+        let expected = [
+            4237864540600467441, 12093458965634073548, 15742032294781686688,
+            1104482975493234836, 2714622702718700844, 4173336073863572623,
+            14666834478568102155, 13451834099019458918, 13400679100509613938,
+            5791427418357307583,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn plus_advance() {
+        let mut rng1 = Xoshiro256Plus::from_seed_u64(0);
+        let mut rng2 = rng1.clone();
+        for _ in 0..100 {
+            rng1.next_u64();
+        }
+        rng2.advance(100);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+
+        rng1.jump();
+        rng2.advance(1 << 127);
+        rng2.advance(1 << 127);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn plus_jump_back() {
+        let mut rng1 = Xoshiro256Plus::from_seed_u64(0);
+        let mut rng2 = rng1.clone();
+        rng2.jump();
+        rng2.jump_back();
+        rng2.long_jump_back();
+        rng2.long_jump();
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn plus_prev() {
+        let mut rng = Xoshiro256Plus::from_seed_u64(0);
+        let values: Vec<_> = (0..10).map(|_| rng.next_u64()).collect();
+        for &v in values.iter().rev() {
+            assert_eq!(rng.prev_u64(), v);
+        }
+        for &v in &values {
+            assert_eq!(rng.next_u64(), v);
+        }
+        let x = rng.next_u32();
+        assert_eq!(rng.prev_u32(), x);
+    }
+
+    #[test]
+    fn plus_nth_stream() {
+        let mut rng1 = Xoshiro256Plus::from_seed_u64(0);
+        for _ in 0..3 {
+            rng1.jump();
+        }
+        let mut rng2 = Xoshiro256Plus::nth_stream(0, 3);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn plus_jump_with_polynomial() {
+        let mut rng1 = Xoshiro256Plus::from_seed_u64(0);
+        let mut rng2 = rng1.clone();
+        rng1.jump();
+        rng2.jump_with_polynomial(&Xoshiro256Plus::JUMP);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+        // x^3 corresponds to three steps.
+        rng1.jump_with_polynomial(&[0b1000]);
+        for _ in 0..3 {
+            rng2.next_u64();
+        }
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn plus_const_construction() {
+        use SplitMix64;
+
+        const RNG: Xoshiro256Plus = Xoshiro256Plus::from_seed_u64(1);
+        let mut rng1 = RNG;
+        let mut rng2 = Xoshiro256Plus::from_rng(SplitMix64::from_seed_u64(1)).unwrap();
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+
+        let mut seed = [0; 32];
+        for (i, chunk) in seed.chunks_mut(8).enumerate() {
+            chunk[0] = i as u8 + 1;
+        }
+        let mut rng1 = Xoshiro256Plus::new_unchecked([1, 2, 3, 4]);
+        let mut rng2 = Xoshiro256Plus::from_seed(seed);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn starstar_reference() {
+        let mut rng = Xoshiro256StarStar::from_seed(
+            [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
+             3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0]);
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoshiro128starstar.c
+        let expected = [
+            11520, 0, 1509978240, 1215971899390074240, 1216172134540287360,
+            607988272756665600, 16172922978634559625, 8476171486693032832,
+            10595114339597558777, 2904607092377533576,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn starstar_jump() {
+        let mut rng = Xoshiro256StarStar::from_seed(
+            [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
+             3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0]);
+        rng.jump();
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoshiro256starstar.c
+        let expected = [
+            13534147089533256664, 7126240192422241655, 3805973808039778091,
+            11547880530658420384, 10982751773866918481, 191296283419114087,
+            5426983283421486391, 11862457687714050616, 11560836193490265043,
+            10390896668600061890,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn starstar_long_jump() {
+        let mut rng = Xoshiro256StarStar::from_seed(
+            [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
+             3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0]);
+        rng.long_jump();
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoshiro256starstar.c
+        let expected = [
+            5942309088398569549, 15625447729937358436, 6925613901769781251,
+            16198770605655666946, 16839222832146757471, 13220645139340494064,
+            17790973104022037039, 15818520673186923474, 5214195809072129922,
+            2264280516447788581,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn starstar_advance() {
+        let mut rng1 = Xoshiro256StarStar::from_seed_u64(0);
+        let mut rng2 = rng1.clone();
+        for _ in 0..100 {
+            rng1.next_u64();
+        }
+        rng2.advance(100);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+
+        rng1.jump();
+        rng2.advance(1 << 127);
+        rng2.advance(1 << 127);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn starstar_jump_back() {
+        let mut rng1 = Xoshiro256StarStar::from_seed_u64(0);
+        let mut rng2 = rng1.clone();
+        rng2.jump();
+        rng2.jump_back();
+        rng2.long_jump_back();
+        rng2.long_jump();
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn starstar_prev() {
+        let mut rng = Xoshiro256StarStar::from_seed_u64(0);
+        let values: Vec<_> = (0..10).map(|_| rng.next_u64()).collect();
+        for &v in values.iter().rev() {
+            assert_eq!(rng.prev_u64(), v);
+        }
+        for &v in &values {
+            assert_eq!(rng.next_u64(), v);
+        }
+        let x = rng.next_u32();
+        assert_eq!(rng.prev_u32(), x);
+    }
+
+    #[test]
+    fn starstar_nth_stream() {
+        let mut rng1 = Xoshiro256StarStar::from_seed_u64(0);
+        for _ in 0..3 {
+            rng1.jump();
+        }
+        let mut rng2 = Xoshiro256StarStar::nth_stream(0, 3);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn starstar_jump_with_polynomial() {
+        let mut rng1 = Xoshiro256StarStar::from_seed_u64(0);
+        let mut rng2 = rng1.clone();
+        rng1.jump();
+        rng2.jump_with_polynomial(&Xoshiro256StarStar::JUMP);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+        // x^3 corresponds to three steps.
+        rng1.jump_with_polynomial(&[0b1000]);
+        for _ in 0..3 {
+            rng2.next_u64();
+        }
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn starstar_const_construction() {
+        use SplitMix64;
+
+        const RNG: Xoshiro256StarStar = Xoshiro256StarStar::from_seed_u64(1);
+        let mut rng1 = RNG;
+        let mut rng2 = Xoshiro256StarStar::from_rng(SplitMix64::from_seed_u64(1)).unwrap();
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+
+        let mut seed = [0; 32];
+        for (i, chunk) in seed.chunks_mut(8).enumerate() {
+            chunk[0] = i as u8 + 1;
+        }
+        let mut rng1 = Xoshiro256StarStar::new_unchecked([1, 2, 3, 4]);
+        let mut rng2 = Xoshiro256StarStar::from_seed(seed);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn plusplus_reference() {
+        let mut rng = Xoshiro256PlusPlus::from_seed(
+            [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
+             3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0]);
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoshiro256plusplus.c
+        let expected = [41943041, 58720359, 3588806011781223, 3591011842654386, 9228616714210784205, 9973669472204895162, 14011001112246962877, 12406186145184390807, 15849039046786891736, 10450023813501588000];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn plusplus_jump() {
+        let mut rng = Xoshiro256PlusPlus::from_seed(
+            [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
+             3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0]);
+        rng.jump();
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoshiro256plusplus.c
+        let expected = [
+            17043750140134683703, 2364973248208838314, 13951431646535487319,
+            8066193832155293345, 10838999831620499216, 8680420094678800874,
+            9570055643283944810, 7079802948504130534, 9337897757504934856,
+            9754970014877867138,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn plusplus_long_jump() {
+        let mut rng = Xoshiro256PlusPlus::from_seed(
+            [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
+             3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0]);
+        rng.long_jump();
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoshiro256plusplus.c
+        let expected = [
+            13097851138432240629, 5869259491745178931, 2145365994275058833,
+            16694938170147227233, 755180411581300843, 4025406863595626629,
+            16170634547833206701, 15038087167920305072, 15516354975165331290,
+            16359070474319612403,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+}
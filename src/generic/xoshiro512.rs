@@ -0,0 +1,622 @@
+use rand_core::{RngCore, SeedableRng};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use poly;
+use Seed512;
+use common::read_u64s;
+use splitmix64::splitmix_states;
+use super::{Core, Scrambler, Xoshiro, Plus, PlusPlus, StarStar};
+
+/// The xoshiro512 linear engine.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "copy", derive(Copy))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Xoshiro512Core {
+    pub(crate) s: [u64; 8],
+}
+
+impl Core for Xoshiro512Core {
+    const NAME: &'static str = "xoshiro512";
+    const POLY: &'static [u64] = &poly::XOSHIRO512;
+    const JUMP_LOG2: u32 = 256;
+
+    type Word = u64;
+    type State = [u64; 8];
+
+    #[inline]
+    fn state(&self) -> &[u64; 8] {
+        &self.s
+    }
+
+    #[inline]
+    fn state_mut(&mut self) -> &mut [u64; 8] {
+        &mut self.s
+    }
+
+    #[inline]
+    fn step(&mut self) {
+        impl_xoshiro_large!(self);
+    }
+
+    #[inline]
+    fn step_back(&mut self) {
+        impl_xoshiro_large_inv!(self);
+    }
+}
+
+impl Scrambler<Xoshiro512Core> for Plus {
+    const NAME: &'static str = "+";
+
+    const WEAK_LOW_BITS: bool = true;
+
+    #[inline]
+    fn scramble(s: &[u64; 8]) -> u64 {
+        s[0].wrapping_add(s[2])
+    }
+}
+
+impl Scrambler<Xoshiro512Core> for StarStar {
+    const NAME: &'static str = "**";
+
+    #[inline]
+    fn scramble(s: &[u64; 8]) -> u64 {
+        starstar_u64!(s[1])
+    }
+}
+
+impl Scrambler<Xoshiro512Core> for PlusPlus {
+    const NAME: &'static str = "++";
+
+    #[inline]
+    fn scramble(s: &[u64; 8]) -> u64 {
+        (s[0].wrapping_add(s[2])).rotate_left(17).wrapping_add(s[2])
+    }
+}
+
+/// A xoshiro512+ random number generator.
+///
+/// The xoshiro512+ algorithm is not suitable for cryptographic purposes, but
+/// is very fast and has good statistical properties, besides a low linear
+/// complexity in the lowest bits.
+///
+/// The algorithm used here is translated from [the `xoshiro512plus.c`
+/// reference source code](http://xoshiro.di.unimi.it/xoshiro512plus.c) by
+/// David Blackman and Sebastiano Vigna.
+pub type Xoshiro512Plus = Xoshiro<Xoshiro512Core, Plus>;
+
+/// A xoshiro512** random number generator.
+///
+/// The xoshiro512** algorithm is not suitable for cryptographic purposes, but
+/// is very fast and has excellent statistical properties.
+///
+/// The algorithm used here is translated from [the `xoshiro512starstar.c`
+/// reference source code](http://xoshiro.di.unimi.it/xoshiro512starstar.c) by
+/// David Blackman and Sebastiano Vigna.
+pub type Xoshiro512StarStar = Xoshiro<Xoshiro512Core, StarStar>;
+
+/// A xoshiro512++ random number generator.
+///
+/// The xoshiro512++ algorithm is not suitable for cryptographic purposes, but
+/// is very fast and has excellent statistical properties.
+///
+/// The algorithm used here is translated from [the `xoshiro512plusplus.c`
+/// reference source code](http://xoshiro.di.unimi.it/xoshiro512plusplus.c) by
+/// David Blackman and Sebastiano Vigna.
+pub type Xoshiro512PlusPlus = Xoshiro<Xoshiro512Core, PlusPlus>;
+
+impl<S> Xoshiro<Xoshiro512Core, S> {
+    /// Polynomial used by `jump()`.
+    pub const JUMP: [u64; 8] = [
+        0x33ed89b6e7a353f9, 0x760083d7955323be, 0x2837f2fbb5f22fae,
+        0x4b8c5674d309511c, 0xb11ac47a7ba28c25, 0xf1be7667092bcc1c,
+        0x53851efdb6df0aaf, 0x1ebbc8b23eaf25db,
+    ];
+
+    /// Polynomial used by `long_jump()`.
+    pub const LONG_JUMP: [u64; 8] = [
+        0x11467fef8f921d28, 0xa2a819f2e79c8ea8, 0xa8299fc284b3959a,
+        0xb4d347340ca63ee1, 0x1cb0940bedbff6ce, 0xd956c5c4fa1f8e17,
+        0x915e38fd4eda93bc, 0x5b3ccdfa5d7daca5,
+    ];
+
+    /// Polynomial used by `jump_back()`.
+    pub const JUMP_BACK: [u64; 8] = [
+        0x0c19be58f09bfbca, 0x0760c5c0e20f1a79, 0xdee503569a215077,
+        0x86f4dce60d7452e4, 0x6a090797e32856fc, 0xf44c9686ce9f4cc1,
+        0xc4eb49b09bd3d476, 0x59942744f59087e7,
+    ];
+
+    /// Polynomial used by `long_jump_back()`.
+    pub const LONG_JUMP_BACK: [u64; 8] = [
+        0x3c71948e7926b8c2, 0xda18d8879cec08f0, 0x33c7e442fdfc6a68,
+        0xece6d6b01dd9ae4d, 0xaabdc787fd214872, 0x4d69809aa792507c,
+        0x6a1948c12d2018f3, 0x83049b2c7b8d32e9,
+    ];
+
+    /// Create a generator from its state words.
+    ///
+    /// The state must not be entirely zero, which is not checked.
+    pub const fn new_unchecked(s: [u64; 8]) -> Self {
+        Xoshiro::from_core(Xoshiro512Core { s })
+    }
+
+    /// Seed the generator from a `u64` using `SplitMix64`.
+    pub const fn from_seed_u64(seed: u64) -> Self {
+        Self::new_unchecked(splitmix_states(seed))
+    }
+
+    /// Jump forward, equivalently to 2^256 calls to `next_u64()`.
+    ///
+    /// This can be used to generate 2^256 non-overlapping subsequences for
+    /// parallel computations.
+    ///
+    /// ```
+    /// # extern crate rand;
+    /// # extern crate xoshiro;
+    /// # fn main() {
+    /// use rand::SeedableRng;
+    /// use xoshiro::Xoshiro512StarStar;
+    ///
+    /// let rng1 = Xoshiro512StarStar::from_seed_u64(0);
+    /// let mut rng2 = rng1.clone();
+    /// rng2.jump();
+    /// let mut rng3 = rng2.clone();
+    /// rng3.jump();
+    /// # }
+    /// ```
+    pub fn jump(&mut self) {
+        self.jump_with_polynomial(&Self::JUMP);
+    }
+
+    /// Jump forward, equivalently to 2^384 calls to `next_u64()`.
+    ///
+    /// This can be used to generate 2^128 starting points, from each of which
+    /// `jump()` will generate 2^128 non-overlapping subsequences for parallel
+    /// distributed computations.
+    pub fn long_jump(&mut self) {
+        self.jump_with_polynomial(&Self::LONG_JUMP);
+    }
+
+    /// Jump backward, equivalently to 2^256 calls to `next_u64()` in reverse.
+    ///
+    /// This undoes `jump()`. Because the period of the generator is 2^512 - 1,
+    /// this is the same as jumping forward by 2^512 - 1 - 2^256 steps.
+    pub fn jump_back(&mut self) {
+        self.jump_with_polynomial(&Self::JUMP_BACK);
+    }
+
+    /// Jump backward, equivalently to 2^384 calls to `next_u64()` in reverse.
+    ///
+    /// This undoes `long_jump()`. Because the period of the generator is
+    /// 2^512 - 1, this is the same as jumping forward by
+    /// 2^512 - 1 - 2^384 steps.
+    pub fn long_jump_back(&mut self) {
+        self.jump_with_polynomial(&Self::LONG_JUMP_BACK);
+    }
+}
+
+impl<S> SeedableRng for Xoshiro<Xoshiro512Core, S> {
+    type Seed = Seed512;
+
+    /// Create a new generator.
+    ///
+    /// An all-zero seed, which is not a valid state, is replaced by the state
+    /// of `from_seed_u64(0)`.
+    #[inline]
+    fn from_seed(seed: Seed512) -> Self {
+        if seed.0 == [0; 64] {
+            return Self::from_seed_u64(0);
+        }
+        Self::new_unchecked(read_u64s(&seed.0))
+    }
+
+    /// Seed the generator like `from_seed_u64()`, instead of the PCG-based
+    /// expansion of the default implementation.
+    fn seed_from_u64(seed: u64) -> Self {
+        Self::from_seed_u64(seed)
+    }
+}
+
+impl<S> From<[u64; 8]> for Xoshiro<Xoshiro512Core, S> {
+    /// Create a new generator from state words.
+    ///
+    /// This is equivalent to `from_seed()` with the words in little-endian
+    /// order, so an all-zero state is replaced as well.
+    fn from(words: [u64; 8]) -> Self {
+        Self::from_seed(Seed512::from(words))
+    }
+}
+
+impl_rng_core_u64!(Xoshiro512Core);
+impl_bytes!(Xoshiro512Core, read_u64s, write_u64s, 64);
+impl_zeroize!(Xoshiro512Core);
+impl_pod!(Xoshiro512Core);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plus_reference() {
+        let mut rng = Xoshiro512Plus::from_seed(Seed512(
+            [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
+             3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
+             5, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0,
+             7, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0]));
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoshiro512plus.c
+        let expected = [
+            4, 8, 4113, 25169936, 52776585412635, 57174648719367,
+            9223482039571869716, 9331471677901559830, 9340533895746033672,
+            14078399799840753678,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn plus_jump() {
+        let mut rng = Xoshiro512Plus::from_seed(Seed512(
+            [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
+             3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
+             5, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0,
+             7, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0]));
+        rng.jump();
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoshiro512plus.c
+        let expected = [
+            16325273756755146526, 12142417007566404861, 9706237776385078821,
+            14342488686476699128, 262208809358621996, 8559719613921572503,
+            12181111195822724926, 144520330904651564, 11002470174725792545,
+            12089844284003475673,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn plus_long_jump() {
+        let mut rng = Xoshiro512Plus::from_seed(Seed512(
+            [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
+             3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
+             5, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0,
+             7, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0]));
+        rng.long_jump();
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoshiro512plus.c
+        let expected = [
+            17470937901245608029, 404838506862911480, 17631258090784883482,
+            16407672656058813853, 16599130834360961546, 17891882599365972287,
+            10802099139395412834, 2929212662578122082, 12807034942997601510,
+            604766919894399455,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn plus_advance() {
+        let mut rng1 = Xoshiro512Plus::from_seed_u64(0);
+        let mut rng2 = rng1.clone();
+        for _ in 0..100 {
+            rng1.next_u64();
+        }
+        rng2.advance(100);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+
+        rng1.advance(1 << 100);
+        rng1.advance(1 << 100);
+        rng2.advance(1 << 101);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn plus_jump_back() {
+        let mut rng1 = Xoshiro512Plus::from_seed_u64(0);
+        let mut rng2 = rng1.clone();
+        rng2.jump();
+        rng2.jump_back();
+        rng2.long_jump_back();
+        rng2.long_jump();
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn plus_prev() {
+        let mut rng = Xoshiro512Plus::from_seed_u64(0);
+        let values: Vec<_> = (0..10).map(|_| rng.next_u64()).collect();
+        for &v in values.iter().rev() {
+            assert_eq!(rng.prev_u64(), v);
+        }
+        for &v in &values {
+            assert_eq!(rng.next_u64(), v);
+        }
+        let x = rng.next_u32();
+        assert_eq!(rng.prev_u32(), x);
+    }
+
+    #[test]
+    fn plus_nth_stream() {
+        let mut rng1 = Xoshiro512Plus::from_seed_u64(0);
+        for _ in 0..3 {
+            rng1.jump();
+        }
+        let mut rng2 = Xoshiro512Plus::nth_stream(0, 3);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn plus_jump_with_polynomial() {
+        let mut rng1 = Xoshiro512Plus::from_seed_u64(0);
+        let mut rng2 = rng1.clone();
+        rng1.jump();
+        rng2.jump_with_polynomial(&Xoshiro512Plus::JUMP);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+        // x^3 corresponds to three steps.
+        rng1.jump_with_polynomial(&[0b1000]);
+        for _ in 0..3 {
+            rng2.next_u64();
+        }
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn plus_const_construction() {
+        use SplitMix64;
+
+        const RNG: Xoshiro512Plus = Xoshiro512Plus::from_seed_u64(1);
+        let mut rng1 = RNG;
+        let mut rng2 = Xoshiro512Plus::from_rng(SplitMix64::from_seed_u64(1)).unwrap();
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+
+        let mut seed = [0; 64];
+        for (i, chunk) in seed.chunks_mut(8).enumerate() {
+            chunk[0] = i as u8 + 1;
+        }
+        let mut rng1 = Xoshiro512Plus::new_unchecked([1, 2, 3, 4, 5, 6, 7, 8]);
+        let mut rng2 = Xoshiro512Plus::from_seed(Seed512(seed));
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn starstar_reference() {
+        let mut rng = Xoshiro512StarStar::from_seed(Seed512(
+            [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
+             3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
+             5, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0,
+             7, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0]));
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoshiro512starstar.c
+        let expected = [
+            11520, 0, 23040, 23667840, 144955163520, 303992986974289920,
+            25332796375735680, 296904390158016, 13911081092387501979,
+            15304787717237593024,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn starstar_jump() {
+        let mut rng = Xoshiro512StarStar::from_seed(Seed512(
+            [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
+             3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
+             5, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0,
+             7, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0]));
+        rng.jump();
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoshiro512starstar.c
+        let expected = [
+            9855632635473413185, 8685991250662704880, 3382494248885713442,
+            665445566715075068, 6173576884829712160, 9962888955848987048,
+            11054557239170654431, 3210672000260786453, 13719456784880161181,
+            16352301000023250060,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn starstar_long_jump() {
+        let mut rng = Xoshiro512StarStar::from_seed(Seed512(
+            [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
+             3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
+             5, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0,
+             7, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0]));
+        rng.long_jump();
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoshiro512starstar.c
+        let expected = [
+            13598512769107285152, 1041926885682271417, 11528305269957160422,
+            11923534093919679431, 17469789036836061166, 11084089104731586729,
+            17847976626452057620, 16578820862771662814, 1224987982532247162,
+            2619773174910317560,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn starstar_advance() {
+        let mut rng1 = Xoshiro512StarStar::from_seed_u64(0);
+        let mut rng2 = rng1.clone();
+        for _ in 0..100 {
+            rng1.next_u64();
+        }
+        rng2.advance(100);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+
+        rng1.advance(1 << 100);
+        rng1.advance(1 << 100);
+        rng2.advance(1 << 101);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn starstar_jump_back() {
+        let mut rng1 = Xoshiro512StarStar::from_seed_u64(0);
+        let mut rng2 = rng1.clone();
+        rng2.jump();
+        rng2.jump_back();
+        rng2.long_jump_back();
+        rng2.long_jump();
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn starstar_prev() {
+        let mut rng = Xoshiro512StarStar::from_seed_u64(0);
+        let values: Vec<_> = (0..10).map(|_| rng.next_u64()).collect();
+        for &v in values.iter().rev() {
+            assert_eq!(rng.prev_u64(), v);
+        }
+        for &v in &values {
+            assert_eq!(rng.next_u64(), v);
+        }
+        let x = rng.next_u32();
+        assert_eq!(rng.prev_u32(), x);
+    }
+
+    #[test]
+    fn starstar_nth_stream() {
+        let mut rng1 = Xoshiro512StarStar::from_seed_u64(0);
+        for _ in 0..3 {
+            rng1.jump();
+        }
+        let mut rng2 = Xoshiro512StarStar::nth_stream(0, 3);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn starstar_jump_with_polynomial() {
+        let mut rng1 = Xoshiro512StarStar::from_seed_u64(0);
+        let mut rng2 = rng1.clone();
+        rng1.jump();
+        rng2.jump_with_polynomial(&Xoshiro512StarStar::JUMP);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+        // x^3 corresponds to three steps.
+        rng1.jump_with_polynomial(&[0b1000]);
+        for _ in 0..3 {
+            rng2.next_u64();
+        }
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn starstar_const_construction() {
+        use SplitMix64;
+
+        const RNG: Xoshiro512StarStar = Xoshiro512StarStar::from_seed_u64(1);
+        let mut rng1 = RNG;
+        let mut rng2 = Xoshiro512StarStar::from_rng(SplitMix64::from_seed_u64(1)).unwrap();
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+
+        let mut seed = [0; 64];
+        for (i, chunk) in seed.chunks_mut(8).enumerate() {
+            chunk[0] = i as u8 + 1;
+        }
+        let mut rng1 = Xoshiro512StarStar::new_unchecked([1, 2, 3, 4, 5, 6, 7, 8]);
+        let mut rng2 = Xoshiro512StarStar::from_seed(Seed512(seed));
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn plusplus_reference() {
+        let mut seed = [0; 64];
+        for (i, chunk) in seed.chunks_mut(8).enumerate() {
+            chunk[0] = i as u8 + 1;
+        }
+        let mut rng = Xoshiro512PlusPlus::from_seed(
+            Seed512(seed));
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoshiro512plusplus.c
+        let expected = [524291, 1048578, 539099140, 3299073855497, 6917532603230064654, 7494048333530275843, 14418333309547923463, 10960079161595355914, 18279570946505382726, 10209173166699159237];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn plusplus_jump() {
+        let mut seed = [0; 64];
+        for (i, chunk) in seed.chunks_mut(8).enumerate() {
+            chunk[0] = i as u8 + 1;
+        }
+        let mut rng = Xoshiro512PlusPlus::from_seed(
+            Seed512(seed));
+        rng.jump();
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoshiro512plusplus.c
+        let expected = [
+            13286526788043013824, 12262680891927860707, 10098866830339918375,
+            16806137253657580474, 2420108657260014909, 8275886529652613204,
+            9283612541143483183, 9923493081297181490, 3650079738310322733,
+            15704407357252264488,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn plusplus_long_jump() {
+        let mut seed = [0; 64];
+        for (i, chunk) in seed.chunks_mut(8).enumerate() {
+            chunk[0] = i as u8 + 1;
+        }
+        let mut rng = Xoshiro512PlusPlus::from_seed(
+            Seed512(seed));
+        rng.long_jump();
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoshiro512plusplus.c
+        let expected = [
+            14265167035050131074, 8745144204428450457, 10736756745833318691,
+            10430175912588919801, 13746181955254172240, 4176831277726524033,
+            9081961477446343312, 13285606821062830953, 5431173317685398737,
+            3337635371269811103,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+}
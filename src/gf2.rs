@@ -0,0 +1,375 @@
+//! Models of the linear engines as matrices over GF(2).
+//!
+//! The state transition of every generator in this crate is a linear map on
+//! the state bits. This module builds these maps as explicit bit matrices,
+//! which makes it possible to verify jump polynomials, invertibility and
+//! `advance(n)` independently of the polynomial arithmetic used by the
+//! generators. It is meant for testing and analysis, not for generating
+//! random numbers, and is only available with the `gf2` feature.
+//!
+//! States are represented as little-endian `u64` words. For the generators
+//! with 32-bit state words, consecutive pairs of words are packed into one
+//! `u64`, the first word in the low half.
+
+use poly;
+use {Core, Xoroshiro64Core, Xoroshiro128Core, Xoroshiro128PlusPlusCore,
+     Xoshiro128Core, Xoshiro256Core, Xoshiro512Core};
+
+/// A square matrix over GF(2), stored by columns.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitMatrix {
+    n: usize,
+    cols: Vec<Vec<u64>>,
+}
+
+impl BitMatrix {
+    /// The `n`×`n` identity matrix, where `n` is a multiple of 64.
+    pub fn identity(n: usize) -> BitMatrix {
+        assert!(n.is_multiple_of(64), "BitMatrix::identity called with n = {}", n);
+        let cols = (0..n).map(|j| unit(n, j)).collect();
+        BitMatrix { n, cols }
+    }
+
+    /// Build the matrix of the linear map `f` on `n`-bit vectors.
+    pub fn from_linear_map<F>(n: usize, mut f: F) -> BitMatrix
+        where F: FnMut(&mut [u64])
+    {
+        assert!(n.is_multiple_of(64),
+                "BitMatrix::from_linear_map called with n = {}", n);
+        let cols = (0..n).map(|j| {
+            let mut v = unit(n, j);
+            f(&mut v);
+            v
+        }).collect();
+        BitMatrix { n, cols }
+    }
+
+    /// The dimension of the matrix.
+    pub fn dim(&self) -> usize {
+        self.n
+    }
+
+    /// Multiply the matrix by the vector `v`.
+    pub fn mul_vec(&self, v: &[u64]) -> Vec<u64> {
+        let mut r = vec![0; self.n / 64];
+        for (j, col) in self.cols.iter().enumerate() {
+            if (v[j / 64] >> (j % 64)) & 1 != 0 {
+                xor_into(&mut r, col);
+            }
+        }
+        r
+    }
+
+    /// Multiply the matrix by `other`.
+    pub fn mul(&self, other: &BitMatrix) -> BitMatrix {
+        assert_eq!(self.n, other.n);
+        let cols = other.cols.iter().map(|c| self.mul_vec(c)).collect();
+        BitMatrix { n: self.n, cols }
+    }
+
+    /// Raise the matrix to the power `e`, given as little-endian words.
+    pub fn pow(&self, e: &[u64]) -> BitMatrix {
+        let mut r = BitMatrix::identity(self.n);
+        let mut started = false;
+        for &w in e.iter().rev() {
+            for bit in (0..64).rev() {
+                if started {
+                    r = r.mul(&r);
+                }
+                if (w >> bit) & 1 != 0 {
+                    r = if started { r.mul(self) } else { self.clone() };
+                    started = true;
+                }
+            }
+        }
+        r
+    }
+
+    /// Compute the inverse by Gaussian elimination, if the matrix is
+    /// invertible.
+    pub fn inverse(&self) -> Option<BitMatrix> {
+        // Row reduce the transpose, whose rows are the stored columns,
+        // together with the identity. The rows of the result are the
+        // columns of the inverse.
+        let mut a = self.cols.clone();
+        let mut b = BitMatrix::identity(self.n).cols;
+        for j in 0..self.n {
+            let (w, bit) = (j / 64, j % 64);
+            let pivot = (j..self.n).find(|&i| (a[i][w] >> bit) & 1 != 0)?;
+            a.swap(j, pivot);
+            b.swap(j, pivot);
+            for i in 0..self.n {
+                if i != j && (a[i][w] >> bit) & 1 != 0 {
+                    let (ra, rb) = (a[j].clone(), b[j].clone());
+                    xor_into(&mut a[i], &ra);
+                    xor_into(&mut b[i], &rb);
+                }
+            }
+        }
+        Some(BitMatrix { n: self.n, cols: b })
+    }
+
+    /// The transpose of the matrix.
+    pub fn transpose(&self) -> BitMatrix {
+        let mut cols = vec![vec![0; self.n / 64]; self.n];
+        for (j, col) in self.cols.iter().enumerate() {
+            for (i, c) in cols.iter_mut().enumerate() {
+                if (col[i / 64] >> (i % 64)) & 1 != 0 {
+                    c[j / 64] |= 1 << (j % 64);
+                }
+            }
+        }
+        BitMatrix { n: self.n, cols }
+    }
+}
+
+/// The linear engines underlying the generators of this crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Engine {
+    /// The engine of `Xoroshiro64Star` and `Xoroshiro64StarStar`.
+    Xoroshiro64,
+    /// The engine of `Xoroshiro128Plus` and `Xoroshiro128StarStar`.
+    Xoroshiro128,
+    /// The engine of `Xoroshiro128PlusPlus`.
+    Xoroshiro128PlusPlus,
+    /// The engine of `Xoshiro128Plus`, `Xoshiro128PlusPlus` and
+    /// `Xoshiro128StarStar`.
+    Xoshiro128,
+    /// The engine of `Xoshiro256Plus`, `Xoshiro256PlusPlus` and
+    /// `Xoshiro256StarStar`.
+    Xoshiro256,
+    /// The engine of `Xoshiro512Plus`, `Xoshiro512PlusPlus` and
+    /// `Xoshiro512StarStar`.
+    Xoshiro512,
+}
+
+/// All engines.
+pub const ENGINES: [Engine; 6] = [
+    Engine::Xoroshiro64, Engine::Xoroshiro128, Engine::Xoroshiro128PlusPlus,
+    Engine::Xoshiro128, Engine::Xoshiro256, Engine::Xoshiro512,
+];
+
+impl Engine {
+    /// The size of the state in bits.
+    pub fn bits(self) -> usize {
+        match self {
+            Engine::Xoroshiro64 => 64,
+            Engine::Xoroshiro128 | Engine::Xoroshiro128PlusPlus
+            | Engine::Xoshiro128 => 128,
+            Engine::Xoshiro256 => 256,
+            Engine::Xoshiro512 => 512,
+        }
+    }
+
+    /// The characteristic polynomial without its leading term x^`bits()`.
+    pub fn characteristic_polynomial(self) -> &'static [u64] {
+        match self {
+            Engine::Xoroshiro64 => &poly::XOROSHIRO64,
+            Engine::Xoroshiro128 => &poly::XOROSHIRO128,
+            Engine::Xoroshiro128PlusPlus => &poly::XOROSHIRO128PLUSPLUS,
+            Engine::Xoshiro128 => &poly::XOSHIRO128,
+            Engine::Xoshiro256 => &poly::XOSHIRO256,
+            Engine::Xoshiro512 => &poly::XOSHIRO512,
+        }
+    }
+
+    /// Apply one state transition to `v`.
+    pub fn step(self, v: &mut [u64]) {
+        match self {
+            Engine::Xoroshiro64 => {
+                let mut x = Xoroshiro64Core {
+                    s: [v[0] as u32, (v[0] >> 32) as u32],
+                };
+                x.step();
+                v[0] = u64::from(x.s[0]) | u64::from(x.s[1]) << 32;
+            }
+            Engine::Xoroshiro128 => {
+                let mut x = Xoroshiro128Core { s: [v[0], v[1]] };
+                x.step();
+                v.copy_from_slice(&x.s);
+            }
+            Engine::Xoroshiro128PlusPlus => {
+                let mut x = Xoroshiro128PlusPlusCore { s: [v[0], v[1]] };
+                x.step();
+                v.copy_from_slice(&x.s);
+            }
+            Engine::Xoshiro128 => {
+                let mut x = Xoshiro128Core { s: unpack_u32(v) };
+                x.step();
+                pack_u32(&x.s, v);
+            }
+            Engine::Xoshiro256 => {
+                let mut x = Xoshiro256Core { s: [v[0], v[1], v[2], v[3]] };
+                x.step();
+                v.copy_from_slice(&x.s);
+            }
+            Engine::Xoshiro512 => {
+                let mut x = Xoshiro512Core { s: [0; 8] };
+                x.s.copy_from_slice(v);
+                x.step();
+                v.copy_from_slice(&x.s);
+            }
+        }
+    }
+
+    /// Apply the inverse of one state transition to `v`.
+    pub fn step_back(self, v: &mut [u64]) {
+        match self {
+            Engine::Xoroshiro64 => {
+                let mut x = Xoroshiro64Core {
+                    s: [v[0] as u32, (v[0] >> 32) as u32],
+                };
+                x.step_back();
+                v[0] = u64::from(x.s[0]) | u64::from(x.s[1]) << 32;
+            }
+            Engine::Xoroshiro128 => {
+                let mut x = Xoroshiro128Core { s: [v[0], v[1]] };
+                x.step_back();
+                v.copy_from_slice(&x.s);
+            }
+            Engine::Xoroshiro128PlusPlus => {
+                let mut x = Xoroshiro128PlusPlusCore { s: [v[0], v[1]] };
+                x.step_back();
+                v.copy_from_slice(&x.s);
+            }
+            Engine::Xoshiro128 => {
+                let mut x = Xoshiro128Core { s: unpack_u32(v) };
+                x.step_back();
+                pack_u32(&x.s, v);
+            }
+            Engine::Xoshiro256 => {
+                let mut x = Xoshiro256Core { s: [v[0], v[1], v[2], v[3]] };
+                x.step_back();
+                v.copy_from_slice(&x.s);
+            }
+            Engine::Xoshiro512 => {
+                let mut x = Xoshiro512Core { s: [0; 8] };
+                x.s.copy_from_slice(v);
+                x.step_back();
+                v.copy_from_slice(&x.s);
+            }
+        }
+    }
+
+    /// The matrix of the state transition.
+    pub fn transition_matrix(self) -> BitMatrix {
+        BitMatrix::from_linear_map(self.bits(), |v| self.step(v))
+    }
+
+    /// The matrix of the inverse state transition.
+    pub fn inverse_transition_matrix(self) -> BitMatrix {
+        BitMatrix::from_linear_map(self.bits(), |v| self.step_back(v))
+    }
+
+    /// Evaluate the polynomial `p` at the transition and apply it to `v`,
+    /// like the jump functions of the generators do.
+    pub fn apply_polynomial(self, p: &[u64], v: &[u64]) -> Vec<u64> {
+        let mut r = vec![0; v.len()];
+        let mut x = v.to_vec();
+        for &w in p {
+            for bit in 0..64 {
+                if (w >> bit) & 1 != 0 {
+                    xor_into(&mut r, &x);
+                }
+                self.step(&mut x);
+            }
+        }
+        r
+    }
+}
+
+fn unit(n: usize, j: usize) -> Vec<u64> {
+    let mut v = vec![0; n / 64];
+    v[j / 64] = 1 << (j % 64);
+    v
+}
+
+fn xor_into(a: &mut [u64], b: &[u64]) {
+    for (a, b) in a.iter_mut().zip(b) {
+        *a ^= *b;
+    }
+}
+
+fn unpack_u32(v: &[u64]) -> [u32; 4] {
+    [v[0] as u32, (v[0] >> 32) as u32, v[1] as u32, (v[1] >> 32) as u32]
+}
+
+fn pack_u32(s: &[u32; 4], v: &mut [u64]) {
+    v[0] = u64::from(s[0]) | u64::from(s[1]) << 32;
+    v[1] = u64::from(s[2]) | u64::from(s[3]) << 32;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand_core::RngCore;
+    use SplitMix64;
+
+    fn random_vector(rng: &mut SplitMix64, n: usize) -> Vec<u64> {
+        (0..n / 64).map(|_| rng.next_u64()).collect()
+    }
+
+    #[test]
+    fn invertible() {
+        for &engine in &ENGINES {
+            let m = engine.transition_matrix();
+            let inv = m.inverse().unwrap();
+            assert_eq!(m.mul(&inv), BitMatrix::identity(engine.bits()));
+            assert_eq!(inv, engine.inverse_transition_matrix());
+        }
+    }
+
+    #[test]
+    fn characteristic_polynomial() {
+        // p(T) = 0, checked on random vectors.
+        let mut rng = SplitMix64::from_seed_u64(1);
+        for &engine in &ENGINES {
+            for _ in 0..4 {
+                let v = random_vector(&mut rng, engine.bits());
+                let mut r = engine.apply_polynomial(
+                    engine.characteristic_polynomial(), &v);
+                let mut x = v.clone();
+                for _ in 0..engine.bits() {
+                    engine.step(&mut x);
+                }
+                xor_into(&mut r, &x);
+                assert!(r.iter().all(|&w| w == 0), "{:?}", engine);
+            }
+        }
+    }
+
+    #[test]
+    fn jump() {
+        // The jump polynomial must agree with the matrix power. The 512-bit
+        // engine is left out, because squaring its matrix 256 times is slow.
+        let mut rng = SplitMix64::from_seed_u64(2);
+        for &(engine, e) in &[
+            (Engine::Xoroshiro64, &[1 << 32][..]),
+            (Engine::Xoroshiro128, &[0, 1][..]),
+            (Engine::Xoroshiro128PlusPlus, &[0, 1][..]),
+            (Engine::Xoshiro128, &[0, 1][..]),
+            (Engine::Xoshiro256, &[0, 0, 1][..]),
+        ] {
+            let p = engine.characteristic_polynomial();
+            let jump = poly::x_pow_mod(e, p);
+            let m = engine.transition_matrix().pow(e);
+            let v = random_vector(&mut rng, engine.bits());
+            assert_eq!(m.mul_vec(&v), engine.apply_polynomial(&jump[..p.len()], &v));
+        }
+    }
+
+    #[test]
+    fn advance() {
+        let mut rng = SplitMix64::from_seed_u64(3);
+        for &engine in &ENGINES {
+            let p = engine.characteristic_polynomial();
+            let m = engine.transition_matrix();
+            for &n in &[0, 1, 2, 1000, rng.next_u64() >> 44] {
+                let jump = poly::x_pow_mod(&[n], p);
+                let v = random_vector(&mut rng, engine.bits());
+                assert_eq!(m.pow(&[n]).mul_vec(&v),
+                           engine.apply_polynomial(&jump[..p.len()], &v));
+            }
+        }
+    }
+}
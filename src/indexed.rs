@@ -0,0 +1,73 @@
+use {Jumpable, XoshiroRng};
+
+/// The generators of numbered work items.
+///
+/// `get(i)` returns the generator of work item `i`, which is the base
+/// generator jumped `i` times. It only depends on `i`, not on the thread
+/// processing the item or the order of processing, so parallel loops produce
+/// the same results as sequential ones.
+///
+/// Computing a generator takes O(log i) polynomial multiplications with the
+/// generators of this crate, which is cheap compared to most work items, but
+/// not to single random numbers.
+///
+/// ```
+/// # extern crate rand_core;
+/// # extern crate xoshiro;
+/// # fn main() {
+/// use rand_core::RngCore;
+/// use xoshiro::{IndexedStreams, Xoshiro256PlusPlus};
+///
+/// let streams = IndexedStreams::<Xoshiro256PlusPlus>::from_seed_u64(7);
+/// let work = |i| {
+///     let mut rng = streams.get(i);
+///     (0..10).map(|_| rng.next_u64() >> 32).sum::<u64>()
+/// };
+/// let forward: Vec<u64> = (0..100).map(work).collect();
+/// let mut backward: Vec<u64> = (0..100).rev().map(work).collect();
+/// backward.reverse();
+/// assert_eq!(forward, backward);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexedStreams<R> {
+    base: R,
+}
+
+impl<R: Jumpable + Clone> IndexedStreams<R> {
+    /// Create the streams whose item 0 uses `base`.
+    pub fn new(base: R) -> IndexedStreams<R> {
+        IndexedStreams { base }
+    }
+
+    /// Return the generator of work item `index`.
+    pub fn get(&self, index: u64) -> R {
+        let mut rng = self.base.clone();
+        rng.jump_n(index);
+        rng
+    }
+}
+
+impl<R: XoshiroRng> IndexedStreams<R> {
+    /// Create the streams from a seed, seeding item 0 with
+    /// `from_seed_u64()`.
+    pub fn from_seed_u64(seed: u64) -> IndexedStreams<R> {
+        IndexedStreams::new(R::from_seed_u64(seed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Xoshiro128PlusPlus;
+
+    #[test]
+    fn get() {
+        let streams = IndexedStreams::<Xoshiro128PlusPlus>::from_seed_u64(1);
+        let expected: Vec<_> = Xoshiro128PlusPlus::from_seed_u64(1).streams()
+            .take(5).collect();
+        for i in (0..5).rev() {
+            assert_eq!(streams.get(i), expected[i as usize]);
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use rand_core::impls::fill_bytes_via_next;
+use rand_core::{RngCore, Error};
+
+use Jumpable;
+
+/// A generator alternating between `N` independent generators.
+///
+/// The output of a single xoshiro generator depends on the state produced by
+/// the previous step, so consecutive calls cannot overlap. This wrapper
+/// advances all `N` generators at once and buffers their outputs, which lets
+/// wide out-of-order CPUs execute the independent steps in parallel. The
+/// outputs are returned in turn: the first output of each generator, then
+/// the second one, and so on.
+///
+/// `next_u32()` returns the upper half of `next_u64()`.
+///
+/// ```
+/// # extern crate rand_core;
+/// # extern crate xoshiro;
+/// # fn main() {
+/// use rand_core::RngCore;
+/// use xoshiro::{Interleaved, Xoshiro256Plus};
+///
+/// let mut rng: Interleaved<_> = Interleaved::new(Xoshiro256Plus::from_seed_u64(0));
+/// let x = rng.next_u64();
+/// # let _ = x;
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Interleaved<R, const N: usize = 2> {
+    rngs: [R; N],
+    buf: [u64; N],
+    index: usize,
+}
+
+impl<R: RngCore, const N: usize> Interleaved<R, N> {
+    /// Interleave `rng` with copies of itself jumped `1..N` times.
+    ///
+    /// The generators are on non-overlapping subsequences.
+    pub fn new(mut rng: R) -> Interleaved<R, N> where R: Jumpable + Clone {
+        Interleaved::from_rngs(::std::array::from_fn(|_| {
+            let r = rng.clone();
+            rng.jump();
+            r
+        }))
+    }
+
+    /// Interleave the given generators.
+    ///
+    /// This panics if `N` is zero.
+    pub fn from_rngs(rngs: [R; N]) -> Interleaved<R, N> {
+        assert!(N > 0, "Interleaved::from_rngs called with N = 0");
+        Interleaved { rngs, buf: [0; N], index: N }
+    }
+
+    /// Fill `dest` with random `u64`, equivalently to calling `next_u64()`
+    /// for each element.
+    ///
+    /// Whole rounds are written directly to `dest`, without going through
+    /// the buffer.
+    pub fn fill_u64(&mut self, dest: &mut [u64]) {
+        let start = ::std::cmp::min(N - self.index, dest.len());
+        let (head, rest) = dest.split_at_mut(start);
+        for x in head {
+            *x = self.next_u64();
+        }
+        let mut chunks = rest.chunks_exact_mut(N);
+        for chunk in &mut chunks {
+            for (x, rng) in chunk.iter_mut().zip(self.rngs.iter_mut()) {
+                *x = rng.next_u64();
+            }
+        }
+        for x in chunks.into_remainder() {
+            *x = self.next_u64();
+        }
+    }
+
+    /// Return the inner generators, discarding buffered outputs.
+    pub fn into_inner(self) -> [R; N] {
+        self.rngs
+    }
+}
+
+impl<R: RngCore, const N: usize> RngCore for Interleaved<R, N> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        if self.index == N {
+            for (x, rng) in self.buf.iter_mut().zip(self.rngs.iter_mut()) {
+                *x = rng.next_u64();
+            }
+            self.index = 0;
+        }
+        let r = self.buf[self.index];
+        self.index += 1;
+        r
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        fill_bytes_via_next(self, dest);
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Xoshiro256StarStar;
+
+    #[test]
+    fn alternate() {
+        let rng = Xoshiro256StarStar::from_seed_u64(0);
+        let mut a = rng.clone();
+        let mut b = rng.clone();
+        b.jump();
+        let mut c = b.clone();
+        c.jump();
+        let mut interleaved = Interleaved::<_, 3>::new(rng);
+        for _ in 0..10 {
+            assert_eq!(interleaved.next_u64(), a.next_u64());
+            assert_eq!(interleaved.next_u64(), b.next_u64());
+            assert_eq!(interleaved.next_u64(), c.next_u64());
+        }
+    }
+
+    #[test]
+    fn fill_u64() {
+        let mut rng1: Interleaved<_, 4> =
+            Interleaved::new(Xoshiro256StarStar::from_seed_u64(0));
+        let mut rng2 = rng1.clone();
+        rng1.next_u64();
+        rng2.next_u64();
+        let mut v = [0; 17];
+        rng1.fill_u64(&mut v);
+        for &x in &v {
+            assert_eq!(x, rng2.next_u64());
+        }
+        assert_eq!(rng1.next_u64(), rng2.next_u64());
+    }
+}
@@ -0,0 +1,318 @@
+//! Traits for generators supporting jumps.
+
+use {Core, Xoshiro};
+#[cfg(feature = "xoroshiro64")]
+use Xoroshiro64Core;
+#[cfg(feature = "xoroshiro128")]
+use {Xoroshiro128Core, Xoroshiro128PlusPlusCore};
+#[cfg(feature = "xoshiro128")]
+use Xoshiro128Core;
+#[cfg(feature = "xoshiro256")]
+use Xoshiro256Core;
+#[cfg(feature = "xoshiro512")]
+use Xoshiro512Core;
+#[cfg(test)]
+use {Xoroshiro64StarStar, Xoroshiro128Plus, Xoroshiro128PlusPlus,
+     Xoshiro128Plus, Xoshiro128StarStar, Xoshiro256StarStar, Xoshiro512Plus,
+     Xoshiro512StarStar};
+
+/// A generator that can jump ahead by a fixed, large number of steps.
+///
+/// The subsequences between successive jumps do not overlap, so this can be
+/// used to write parallel code that is generic over the generator.
+///
+/// ```
+/// # extern crate xoshiro;
+/// # fn main() {
+/// use xoshiro::{Jumpable, Xoshiro256StarStar};
+///
+/// fn streams<R: Jumpable + Clone>(mut rng: R, n: usize) -> Vec<R> {
+///     let mut v = Vec::with_capacity(n);
+///     for _ in 0..n {
+///         v.push(rng.clone());
+///         rng.jump();
+///     }
+///     v
+/// }
+///
+/// let rngs = streams(Xoshiro256StarStar::from_seed_u64(0), 4);
+/// assert_eq!(rngs.len(), 4);
+/// # }
+/// ```
+pub trait Jumpable {
+    /// The base-2 logarithm of the number of non-overlapping subsequences
+    /// that `jump()` divides the period into.
+    ///
+    /// The generator jumped `k` times only avoids the others for
+    /// `k < 2^STREAM_BITS`; beyond that, the subsequences wrap around the
+    /// period and repeat, shifted by a few outputs. This is 32 for the
+    /// xoroshiro64 generators and at least 64, so any `u64` index is
+    /// safe, for the others.
+    const STREAM_BITS: u32 = 64;
+
+    /// Jump forward by the distance of the generator's `jump()`.
+    fn jump(&mut self);
+
+    /// Jump forward `n` times.
+    ///
+    /// The generators of this crate override this to take O(log n) time, so
+    /// it can be used to compute the `n`-th subsequence directly.
+    fn jump_n(&mut self, n: u64) {
+        for _ in 0..n {
+            self.jump();
+        }
+    }
+
+    /// Return a child generator on a subsequence not overlapping the parent.
+    ///
+    /// The child continues from the current position of `self`, and `self`
+    /// is jumped once. Forking repeatedly hands out the same generators as
+    /// `streams()`.
+    ///
+    /// ```
+    /// # extern crate xoshiro;
+    /// # fn main() {
+    /// use xoshiro::{Jumpable, Xoshiro256PlusPlus};
+    ///
+    /// let mut rng = Xoshiro256PlusPlus::from_seed_u64(0);
+    /// let physics = rng.fork();
+    /// let audio = rng.fork();
+    /// # assert!(physics != audio);
+    /// # }
+    /// ```
+    fn fork(&mut self) -> Self where Self: Sized + Clone {
+        let child = self.clone();
+        self.jump();
+        child
+    }
+
+    /// Return an iterator over non-overlapping subsequences.
+    ///
+    /// The first item is the generator itself, every following item is the
+    /// previous one jumped once. The iterator is infinite.
+    ///
+    /// ```
+    /// # extern crate xoshiro;
+    /// # fn main() {
+    /// use xoshiro::{Jumpable, Xoroshiro128StarStar};
+    ///
+    /// let rng = Xoroshiro128StarStar::from_seed_u64(0);
+    /// let workers: Vec<_> = rng.streams().take(8).collect();
+    /// # assert_eq!(workers.len(), 8);
+    /// # }
+    /// ```
+    fn streams(self) -> StreamIter<Self> where Self: Sized + Clone {
+        StreamIter { rng: self }
+    }
+
+    /// Split the generator into `n` generators on non-overlapping
+    /// subsequences.
+    ///
+    /// The first generator is `self`, the others are successively jumped
+    /// once. For hierarchical splitting (e.g. across machines, then across
+    /// threads), combine this with `LongJumpable::long_jump`.
+    ///
+    /// This never long-jumps, because plain jumps are enough for any `n`
+    /// that fits in memory: the generators of this crate can be split into
+    /// at least 2^32 non-overlapping subsequences by `jump()`, which would
+    /// already take 32 GiB for the smallest generator, and the others into
+    /// at least 2^64. Each generator is computed from the previous one, so
+    /// splitting takes time linear in `n`, like allocating the result.
+    ///
+    /// ```
+    /// # extern crate xoshiro;
+    /// # fn main() {
+    /// use xoshiro::{Jumpable, Xoshiro256Plus};
+    ///
+    /// let rngs = Xoshiro256Plus::from_seed_u64(0).split_n(16);
+    /// assert_eq!(rngs.len(), 16);
+    /// # }
+    /// ```
+    fn split_n(self, n: usize) -> Vec<Self> where Self: Sized + Clone {
+        let mut v = Vec::with_capacity(n);
+        v.extend(self.streams().take(n));
+        v
+    }
+}
+
+/// An infinite iterator over successively jumped generators.
+///
+/// This is created by `Jumpable::streams`.
+#[derive(Debug, Clone)]
+pub struct StreamIter<R> {
+    rng: R,
+}
+
+impl<R: Jumpable + Clone> Iterator for StreamIter<R> {
+    type Item = R;
+
+    fn next(&mut self) -> Option<R> {
+        let rng = self.rng.clone();
+        self.rng.jump();
+        Some(rng)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+/// A generator that can additionally jump ahead by a much larger distance.
+///
+/// The distance of `long_jump()` is a multiple of the distance of `jump()`,
+/// so each long jump provides a starting point for many jumps.
+pub trait LongJumpable: Jumpable {
+    /// Jump forward by the distance of the generator's `long_jump()`.
+    fn long_jump(&mut self);
+}
+
+/// Implement `Jumpable` for all generators using the core `$core` by
+/// forwarding to the inherent methods.
+///
+/// The state has `64 * POLY.len()` bits, so it holds
+/// `2^(64 * POLY.len() - JUMP_LOG2)` subsequences of the length of a jump.
+macro_rules! impl_jumpable {
+    ($core:ident) => {
+        impl<S> ::Jumpable for Xoshiro<$core, S> {
+            const STREAM_BITS: u32 = 64 * <$core as Core>::POLY.len() as u32
+                - <$core as Core>::JUMP_LOG2;
+
+            #[inline]
+            fn jump(&mut self) {
+                Xoshiro::<$core, S>::jump(self)
+            }
+
+            #[inline]
+            fn jump_n(&mut self, n: u64) {
+                Xoshiro::<$core, S>::jump_n(self, n)
+            }
+        }
+    }
+}
+
+/// Implement `Jumpable` and `LongJumpable` for all generators using the core
+/// `$core` by forwarding to the inherent methods.
+macro_rules! impl_long_jumpable {
+    ($core:ident) => {
+        impl_jumpable!($core);
+
+        impl<S> ::LongJumpable for Xoshiro<$core, S> {
+            #[inline]
+            fn long_jump(&mut self) {
+                Xoshiro::<$core, S>::long_jump(self)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "xoroshiro64")]
+impl_jumpable!(Xoroshiro64Core);
+#[cfg(feature = "xoroshiro128")]
+impl_long_jumpable!(Xoroshiro128Core);
+#[cfg(feature = "xoroshiro128")]
+impl_long_jumpable!(Xoroshiro128PlusPlusCore);
+#[cfg(feature = "xoshiro128")]
+impl_long_jumpable!(Xoshiro128Core);
+#[cfg(feature = "xoshiro256")]
+impl_long_jumpable!(Xoshiro256Core);
+#[cfg(feature = "xoshiro512")]
+impl_long_jumpable!(Xoshiro512Core);
+
+#[cfg(test)]
+mod tests {
+    use rand_core::RngCore;
+    use super::*;
+
+    fn check<R: LongJumpable + RngCore + Clone>(rng: R) {
+        let mut rng1 = rng.clone();
+        let mut rng2 = rng;
+        Jumpable::jump(&mut rng1);
+        LongJumpable::long_jump(&mut rng2);
+        assert!(rng1.next_u64() != rng2.next_u64());
+    }
+
+    #[test]
+    fn generic() {
+        check(Xoroshiro128Plus::from_seed_u64(0));
+        check(Xoshiro128StarStar::from_seed_u64(0));
+        check(Xoshiro256StarStar::from_seed_u64(0));
+        check(Xoshiro512Plus::from_seed_u64(0));
+    }
+
+    #[test]
+    fn streams() {
+        let rng = Xoshiro128Plus::from_seed_u64(0);
+        let mut expected = rng.clone();
+        for mut r in rng.streams().take(4) {
+            assert_eq!(r.next_u32(), expected.clone().next_u32());
+            expected.jump();
+        }
+    }
+
+    fn check_jump_n<R: Jumpable + Clone + PartialEq + ::std::fmt::Debug>(
+        rng: R)
+    {
+        let mut expected = rng.clone();
+        for n in 0..4 {
+            let mut jumped = rng.clone();
+            jumped.jump_n(n);
+            assert_eq!(jumped, expected);
+            expected.jump();
+        }
+    }
+
+    #[test]
+    fn jump_n() {
+        check_jump_n(Xoroshiro64StarStar::from_seed_u64(1));
+        check_jump_n(Xoroshiro128Plus::from_seed_u64(1));
+        check_jump_n(Xoroshiro128PlusPlus::from_seed_u64(1));
+        check_jump_n(Xoshiro128StarStar::from_seed_u64(1));
+        check_jump_n(Xoshiro256StarStar::from_seed_u64(1));
+        check_jump_n(Xoshiro512Plus::from_seed_u64(1));
+    }
+
+    #[test]
+    fn fork() {
+        let mut rng = Xoshiro256StarStar::from_seed_u64(0);
+        let mut streams = rng.clone().streams();
+        for _ in 0..3 {
+            assert_eq!(rng.fork(), streams.next().unwrap());
+        }
+        assert_eq!(rng, streams.next().unwrap());
+    }
+
+    #[test]
+    fn split_n() {
+        let rng = Xoshiro512StarStar::from_seed_u64(0);
+        let mut expected = rng.clone();
+        let rngs = rng.split_n(3);
+        assert_eq!(rngs.len(), 3);
+        for mut r in rngs {
+            assert_eq!(r.next_u64(), expected.clone().next_u64());
+            expected.jump();
+        }
+        assert!(Xoshiro512StarStar::from_seed_u64(0).split_n(0).is_empty());
+    }
+
+    fn check_split_n<R>(rng: R)
+        where R: Jumpable + Clone + PartialEq + ::std::fmt::Debug
+    {
+        let rngs = rng.clone().split_n(1000);
+        assert_eq!(rngs.len(), 1000);
+        for &i in &[1, 2, 500, 999] {
+            let mut expected = rng.clone();
+            expected.jump_n(i as u64);
+            assert_eq!(rngs[i], expected);
+            assert!(rngs[i] != rngs[i - 1]);
+        }
+    }
+
+    #[test]
+    fn split_n_large() {
+        check_split_n(Xoroshiro64StarStar::from_seed_u64(1));
+        check_split_n(Xoroshiro128Plus::from_seed_u64(1));
+        check_split_n(Xoshiro128StarStar::from_seed_u64(1));
+        check_split_n(Xoshiro256StarStar::from_seed_u64(1));
+        check_split_n(Xoshiro512Plus::from_seed_u64(1));
+    }
+}
@@ -0,0 +1,103 @@
+use rand_core::impls::fill_bytes_via_next;
+use rand_core::{RngCore, Error};
+
+/// A generator yielding every `k`-th output of an underlying stream.
+///
+/// Leapfrogging partitions a single logical stream between `k` workers:
+/// worker `i` gets outputs `i`, `i + k`, `i + 2k`, ... of the inner
+/// generator's `next_u64()` sequence. Together, the workers consume exactly
+/// the values a single generator would have produced. This is an alternative
+/// to jump-based blocking, where each worker gets a contiguous subsequence.
+///
+/// Each draw steps the inner generator `k` times, so this is only efficient
+/// for a small number of workers.
+///
+/// `next_u32()` returns the upper half of `next_u64()`, so the partitioning
+/// holds for both.
+///
+/// ```
+/// # extern crate rand_core;
+/// # extern crate xoshiro;
+/// # fn main() {
+/// use rand_core::RngCore;
+/// use xoshiro::{Leapfrog, Xoshiro256StarStar};
+///
+/// let rng = Xoshiro256StarStar::from_seed_u64(0);
+/// let mut workers: Vec<_> = (0..4)
+///     .map(|i| Leapfrog::new(rng.clone(), i, 4))
+///     .collect();
+/// let x = workers[2].next_u64();
+/// # let mut check = rng.clone();
+/// # check.next_u64(); check.next_u64();
+/// # assert_eq!(x, check.next_u64());
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Leapfrog<R> {
+    rng: R,
+    k: u64,
+}
+
+impl<R: RngCore> Leapfrog<R> {
+    /// Create the leapfrog generator of worker `i` out of `k`.
+    ///
+    /// This panics if `k` is zero or `i` is not less than `k`.
+    pub fn new(mut rng: R, i: u64, k: u64) -> Leapfrog<R> {
+        assert!(i < k, "Leapfrog::new called with i = {} and k = {}", i, k);
+        for _ in 0..i {
+            rng.next_u64();
+        }
+        Leapfrog { rng, k }
+    }
+
+    /// Return the inner generator.
+    pub fn into_inner(self) -> R {
+        self.rng
+    }
+}
+
+impl<R: RngCore> RngCore for Leapfrog<R> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let r = self.rng.next_u64();
+        for _ in 1..self.k {
+            self.rng.next_u64();
+        }
+        r
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        fill_bytes_via_next(self, dest);
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Xoshiro128StarStar;
+
+    #[test]
+    fn partition() {
+        let mut rng = Xoshiro128StarStar::from_seed_u64(0);
+        let mut workers: Vec<_> = (0..3)
+            .map(|i| Leapfrog::new(rng.clone(), i, 3))
+            .collect();
+        for _ in 0..10 {
+            for w in &mut workers {
+                assert_eq!(w.next_u64(), rng.next_u64());
+            }
+        }
+    }
+}
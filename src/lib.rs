@@ -9,6 +9,8 @@
 //! # 64-bit generators
 //! - [`Xoshiro256StarStar`]: Recommended for all purposes. Excellent speed and
 //!   a state space (256 bits) large enough for any parallel application.
+//! - [`Xoshiro256PlusPlus`]: An alternative to `Xoshiro256StarStar` with the
+//!   same speed and state, using the ++ scrambler.
 //! - [`Xoshiro256Plus`]: Recommended for generating 64-bit floating-point
 //!   numbers. About 15% faster than `Xoshiro256StarStar`, but has a [low linear
 //!   complexity] in the lowest bits (which are discarded when generating
@@ -17,6 +19,9 @@
 //! - [`Xoroshiro128StarStar`]: An alternative to `Xoshiro256StarStar`, having
 //!   the same speed but using half the state. Only suited for low-scale parallel
 //!   applications.
+//! - [`Xoroshiro128PlusPlus`]: An alternative to `Xoroshiro128StarStar` with
+//!   the ++ scrambler, and the same generator as `Xoroshiro128PlusPlus` of
+//!   Java 17.
 //! - [`Xoroshiro128Plus`]: An alternative to `Xoshiro256Plus`, having the same
 //!   speed but using half the state. Only suited for low-scale parallel
 //!   applications. Has a [low linear complexity] in the lowest bits (which are
@@ -24,15 +29,19 @@
 //!   unlikely to have any impact in practise.
 //! - [`Xoshiro512StarStar`]: An alternative to `Xoshiro256StarStar` with more
 //!   state and the same speed.
-//! - [`Xoshiro512Plus`]: An alternative to `Xoshiro512Plus` with more
+//! - [`Xoshiro512PlusPlus`]: An alternative to `Xoshiro256PlusPlus` with more
+//!   state and the same speed.
+//! - [`Xoshiro512Plus`]: An alternative to `Xoshiro256Plus` with more
 //!   state and the same speed. Has a [low linear complexity] in the lowest bits
 //!   (which are discarded when generating floats), making it fail linearity
 //!   tests. This is unlikely to have any impact in practise.
 //! - [`SplitMix64`]: Recommended for initializing generators of the xoshiro
-//!   familiy from a 64-bit seed. Used for implementing `seed_from_u64`.
+//!   familiy from a 64-bit seed. Used for implementing `from_seed_u64`.
 //!
 //! # 32-bit generators
 //! - [`Xoshiro128StarStar`]: Recommended for all purposes. Excellent speed.
+//! - [`Xoshiro128PlusPlus`]: An alternative to `Xoshiro128StarStar` with the
+//!   same speed and state, using the ++ scrambler.
 //! - [`Xoshiro128Plus`]: Recommended for generating 32-bit floating-point
 //!   numbers. Faster than `Xoshiro128StarStar`, but has a [low linear
 //!   complexity] in the lowest bits (which are discarded when generating
@@ -45,46 +54,225 @@
 //!   lowest bits (which are discarded when generating floats), making it fail
 //!   linearity tests. This is unlikely to have any impact in practise.
 //!
+//! All generators except `SplitMix64` are type aliases of [`Xoshiro`],
+//! combining one of the linear engines (e.g. [`Xoshiro256Core`]) with one of
+//! the scramblers [`Plus`], [`PlusPlus`], [`Star`] and [`StarStar`].
+//!
+//! # Seeding from a `u64`
+//! Every generator has a `from_seed_u64` constructor, which expands the seed
+//! with `SplitMix64` as recommended by the authors (`Xoroshiro64Core` uses
+//! a nonzero seed directly as its state). `SeedableRng::seed_from_u64` is
+//! overridden to agree with it, so there is no second, diverging expansion of
+//! the same `u64` seed.
+//!
+//! # Migrating from `XorShiftRng`
+//! The deprecated alias `XorShiftRng` stands in for `rand::XorShiftRng`, so
+//! that code switching to this crate keeps compiling. It is
+//! `Xoshiro128PlusPlus`, not a xorshift generator, so its output differs
+//! from `rand::XorShiftRng` for the same seed, and the deprecation warning
+//! says so. Checkpointed xorshift states can be carried over with
+//! `from_xorshift_state`, which gives a generator determined by the old
+//! state, but does not continue its stream.
+//!
+//! # Newer versions of `rand_core`
+//! With the `rand_core_06` and `rand_core_09` features, all generators also
+//! implement `RngCore` and `SeedableRng` of `rand_core` 0.6 and 0.9, so they
+//! can be used with current versions of `rand`. Their `seed_from_u64` agrees
+//! with `from_seed_u64`.
+//!
 //! [xoshiro]: http://xoshiro.di.unimi.it/
 //! [low linear complexity]: http://xoshiro.di.unimi.it/lowcomp.php
-//! [`Xoshiro256StarStar`]: ./struct.Xoshiro256StarStar.html
-//! [`Xoshiro256Plus`]: ./struct.Xoshiro256Plus.html
-//! [`Xoroshiro128StarStar`]: ./struct.Xoroshiro128StarStar.html
-//! [`Xoroshiro128Plus`]: ./struct.Xoroshiro128Plus.html
-//! [`Xoshiro512StarStar`]: ./struct.Xoshiro512StarStar.html
-//! [`Xoshiro512Plus`]: ./struct.Xoshiro512Plus.html
+//! [`Xoshiro256StarStar`]: ./type.Xoshiro256StarStar.html
+//! [`Xoshiro256PlusPlus`]: ./type.Xoshiro256PlusPlus.html
+//! [`Xoshiro256Plus`]: ./type.Xoshiro256Plus.html
+//! [`Xoroshiro128StarStar`]: ./type.Xoroshiro128StarStar.html
+//! [`Xoroshiro128PlusPlus`]: ./type.Xoroshiro128PlusPlus.html
+//! [`Xoroshiro128Plus`]: ./type.Xoroshiro128Plus.html
+//! [`Xoshiro512StarStar`]: ./type.Xoshiro512StarStar.html
+//! [`Xoshiro512PlusPlus`]: ./type.Xoshiro512PlusPlus.html
+//! [`Xoshiro512Plus`]: ./type.Xoshiro512Plus.html
 //! [`SplitMix64`]: ./struct.SplitMix64.html
-//! [`Xoshiro128StarStar`]: ./struct.Xoshiro128StarStar.html
-//! [`Xoshiro128Plus`]: ./struct.Xoshiro128Plus.html
-//! [`Xoroshiro64StarStar`]: ./struct.Xoroshiro64StarStar.html
-//! [`Xoroshiro64Star`]: ./struct.Xoroshiro64Star.html
+//! [`Xoshiro128StarStar`]: ./type.Xoshiro128StarStar.html
+//! [`Xoshiro128PlusPlus`]: ./type.Xoshiro128PlusPlus.html
+//! [`Xoshiro128Plus`]: ./type.Xoshiro128Plus.html
+//! [`Xoroshiro64StarStar`]: ./type.Xoroshiro64StarStar.html
+//! [`Xoroshiro64Star`]: ./type.Xoroshiro64Star.html
+//! [`Xoshiro`]: ./struct.Xoshiro.html
+//! [`Xoshiro256Core`]: ./struct.Xoshiro256Core.html
+//! [`Plus`]: ./struct.Plus.html
+//! [`PlusPlus`]: ./struct.PlusPlus.html
+//! [`Star`]: ./struct.Star.html
+//! [`StarStar`]: ./struct.StarStar.html
 
-extern crate byteorder;
+#![cfg_attr(feature = "portable-simd", feature(portable_simd))]
+// The generators are only `Copy` with the `copy` feature, so cloning them is
+// necessary in general.
+#![cfg_attr(feature = "copy", allow(clippy::clone_on_copy))]
+// Parts of the shared code are unused when some generators are disabled.
+#![cfg_attr(not(feature = "all-generators"),
+            allow(dead_code, unused_imports, unused_macros))]
+
+#[cfg(all(test, not(feature = "all-generators")))]
+compile_error!("the tests require the `all-generators` feature");
+
+#[cfg(feature = "arbitrary")]
+extern crate arbitrary;
+#[cfg(feature = "bytemuck")]
+extern crate bytemuck;
+#[cfg(feature = "getrandom")]
+extern crate getrandom;
+#[cfg(feature = "half")]
+extern crate half;
+// The code generated by the `pyo3` and `wasm-bindgen` macros refers to
+// `::core`, which is only in scope in the 2015 edition if declared here.
+#[cfg(any(feature = "pyo3", feature = "wasm"))]
+extern crate core;
+#[cfg(feature = "pyo3")]
+extern crate pyo3;
+#[cfg(feature = "quickcheck")]
+extern crate quickcheck;
 extern crate rand_core;
+#[cfg(feature = "rand_core_06")]
+extern crate rand_core_06;
+#[cfg(feature = "rand_core_09")]
+extern crate rand_core_09;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_json;
+#[cfg(feature = "wasm")]
+extern crate wasm_bindgen;
+#[cfg(feature = "zeroize")]
+extern crate zeroize;
 
 #[macro_use]
 mod common;
+#[cfg(any(feature = "rand_core_06", feature = "rand_core_09"))]
+mod compat;
+mod any;
+mod bernoulli;
+#[cfg(any(feature = "ffi", feature = "pyo3"))]
+mod bitgen;
+mod bitbuffer;
+mod bounded;
+mod builder;
+#[cfg(feature = "xoshiro256")]
+mod dotnet;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod block;
+mod generic;
+mod buffered32;
+#[cfg(any(test, feature = "gf2"))]
+pub mod gf2;
+mod indexed;
+mod interleaved;
+mod jump;
+mod leapfrog;
+#[cfg(feature = "xoshiro256")]
+mod lua;
+#[cfg(feature = "rayon")]
+mod par;
+mod poly;
+mod pool;
+#[cfg(feature = "pyo3")]
+pub mod python;
+mod range;
+mod reader;
+mod reseeding;
+mod rng;
+mod sharded;
+#[cfg(feature = "xoshiro256")]
+pub mod simd;
 mod splitmix64;
-mod xoshiro128starstar;
-mod xoshiro128plus;
-mod xoshiro256starstar;
-mod xoshiro256plus;
-mod xoshiro512starstar;
-mod xoshiro512plus;
-mod xoroshiro128plus;
-mod xoroshiro128starstar;
-mod xoroshiro64starstar;
-mod xoroshiro64star;
+#[cfg(feature = "stats")]
+pub mod stats;
+mod sync;
+#[cfg(feature = "testu01")]
+pub mod testu01;
+mod text;
+#[cfg(all(feature = "getrandom", feature = "xoshiro256"))]
+mod thread;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 pub use splitmix64::SplitMix64;
-pub use xoshiro128starstar::Xoshiro128StarStar;
-pub use xoshiro128plus::Xoshiro128Plus;
-pub use xoshiro256starstar::Xoshiro256StarStar;
-pub use xoshiro256plus::Xoshiro256Plus;
-pub use common::Seed512;
-pub use xoshiro512starstar::Xoshiro512StarStar;
-pub use xoshiro512plus::Xoshiro512Plus;
-pub use xoroshiro128plus::Xoroshiro128Plus;
-pub use xoroshiro128starstar::Xoroshiro128StarStar;
-pub use xoroshiro64starstar::Xoroshiro64StarStar;
-pub use xoroshiro64star::Xoroshiro64Star;
+pub use any::AnyXoshiro;
+pub use bernoulli::Bernoulli;
+pub use bitbuffer::BitBuffer;
+pub use bounded::Bounded;
+pub use builder::{BuildError, XoshiroBuilder};
+pub use buffered32::Buffered32;
+pub use common::{Seed512, ZeroSeedError};
+#[cfg(feature = "xoshiro256")]
+pub use dotnet::DotnetRandom;
+pub use generic::{Core, Plus, PlusPlus, Scrambler, Star, StarStar, Xoshiro};
+#[cfg(feature = "xoroshiro64")]
+pub use generic::{Xoroshiro64Core, Xoroshiro64Star, Xoroshiro64StarStar};
+#[cfg(feature = "xoroshiro128")]
+pub use generic::{Xoroshiro128Core, Xoroshiro128Plus, Xoroshiro128StarStar};
+#[cfg(feature = "xoroshiro128")]
+pub use generic::{Xoroshiro128PlusPlusCore, Xoroshiro128PlusPlus};
+#[cfg(feature = "xoshiro128")]
+pub use generic::{Xoshiro128Core, Xoshiro128Plus, Xoshiro128PlusPlus,
+                  Xoshiro128StarStar};
+#[cfg(feature = "xoshiro128")]
+#[allow(deprecated)]
+pub use generic::XorShiftRng;
+#[cfg(feature = "xoshiro256")]
+pub use generic::{Xoshiro256Core, Xoshiro256Plus, Xoshiro256PlusPlus,
+                  Xoshiro256StarStar};
+#[cfg(feature = "xoshiro512")]
+pub use generic::{Xoshiro512Core, Xoshiro512Plus, Xoshiro512PlusPlus,
+                  Xoshiro512StarStar};
+pub use indexed::IndexedStreams;
+pub use interleaved::Interleaved;
+pub use jump::{Jumpable, LongJumpable, StreamIter};
+pub use leapfrog::Leapfrog;
+#[cfg(feature = "xoshiro256")]
+pub use lua::LuaRandom;
+pub use pool::StreamPool;
+pub use range::SampleRange;
+pub use reader::{write_random_bytes, RngReader};
+pub use reseeding::ReseedingXoshiro;
+pub use rng::XoshiroRng;
+pub use sharded::ShardedRng;
+pub use sync::{SyncHandle, SyncXoshiro};
+#[cfg(feature = "rayon")]
+pub use par::{ParFill, ParIterU64};
+#[cfg(feature = "xoshiro256")]
+pub use simd::FastRng;
+pub use text::ParseStateError;
+#[cfg(all(feature = "getrandom", feature = "xoshiro256"))]
+pub use thread::{thread_rng, ThreadRng};
+
+/// The recommended generator for the target's pointer width.
+///
+/// This is `Xoshiro256StarStar` on 64-bit targets and `Xoshiro128StarStar`,
+/// whose 32-bit operations are native there, on other targets. The output
+/// therefore differs between targets for the same seed.
+///
+/// ```
+/// # extern crate rand_core;
+/// # extern crate xoshiro;
+/// # fn main() {
+/// use rand_core::RngCore;
+/// use xoshiro::SmallRng;
+///
+/// let mut rng = SmallRng::from_seed_u64(0);
+/// let x = rng.next_u32();
+/// # let _ = x;
+/// # }
+/// ```
+#[cfg(all(target_pointer_width = "64", feature = "xoshiro256"))]
+pub type SmallRng = Xoshiro256StarStar;
+
+/// The recommended generator for the target's pointer width.
+///
+/// This is `Xoshiro256StarStar` on 64-bit targets and `Xoshiro128StarStar`,
+/// whose 32-bit operations are native there, on other targets. The output
+/// therefore differs between targets for the same seed.
+#[cfg(all(not(target_pointer_width = "64"), feature = "xoshiro128"))]
+pub type SmallRng = Xoshiro128StarStar;
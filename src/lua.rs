@@ -0,0 +1,125 @@
+//! Compatibility with `math.random` of Lua 5.4.
+
+use rand_core::RngCore;
+
+use Xoshiro256StarStar;
+
+/// A reproduction of `math.random` and `math.randomseed` of Lua 5.4.
+///
+/// Lua 5.4 implements `math.random` with xoshiro256**. Seeded with the same
+/// integers, the methods of this type return exactly the same values as the
+/// corresponding calls of `math.random` in a Lua 5.4 build with 64-bit
+/// integers, so a Rust host and its scripts can share deterministic streams.
+///
+/// ```
+/// # extern crate xoshiro;
+/// # fn main() {
+/// use xoshiro::LuaRandom;
+///
+/// // math.randomseed(42); math.random(0)
+/// let mut random = LuaRandom::new(42);
+/// assert_eq!(random.random_int() as u64, 0xee49b4f7660276e5);
+/// // math.random(1, 6)
+/// let die = random.random_range(1, 6);
+/// # assert!(die >= 1 && die <= 6);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LuaRandom {
+    rng: Xoshiro256StarStar,
+}
+
+impl LuaRandom {
+    /// Seed the generator like `math.randomseed(n)`.
+    pub fn new(n: i64) -> LuaRandom {
+        LuaRandom::with_seeds(n, 0)
+    }
+
+    /// Seed the generator like `math.randomseed(n1, n2)`.
+    ///
+    /// The state is `[n1, 0xff, n2, 0]`, followed by 16 discarded outputs.
+    pub fn with_seeds(n1: i64, n2: i64) -> LuaRandom {
+        let mut rng = Xoshiro256StarStar::from_seed_u64(0);
+        rng.set_state([n1 as u64, 0xff, n2 as u64, 0]);
+        for _ in 0..16 {
+            rng.next_u64();
+        }
+        LuaRandom { rng }
+    }
+
+    /// Create a generator continuing `rng`.
+    pub fn from_rng(rng: Xoshiro256StarStar) -> LuaRandom {
+        LuaRandom { rng }
+    }
+
+    /// Return the underlying generator.
+    pub fn into_inner(self) -> Xoshiro256StarStar {
+        self.rng
+    }
+
+    /// Like `math.random()`, return a float in [0, 1) generated from the
+    /// upper 53 bits.
+    pub fn random(&mut self) -> f64 {
+        self.rng.next_f64()
+    }
+
+    /// Like `math.random(0)`, return an integer with all bits random.
+    pub fn random_int(&mut self) -> i64 {
+        self.rng.next_u64() as i64
+    }
+
+    /// Like `math.random(m)`, return an integer in [1, `m`].
+    ///
+    /// # Panics
+    ///
+    /// If `m` is less than 1. Unlike in Lua, `m = 0` is not special, use
+    /// `random_int()` instead.
+    pub fn random_up_to(&mut self, m: i64) -> i64 {
+        self.random_range(1, m)
+    }
+
+    /// Like `math.random(m, n)`, return an integer in [`m`, `n`].
+    ///
+    /// The lower bits of an output are masked to the smallest power of two
+    /// covering the interval, rejecting values outside of it.
+    ///
+    /// # Panics
+    ///
+    /// If `m` is greater than `n`.
+    pub fn random_range(&mut self, m: i64, n: i64) -> i64 {
+        assert!(m <= n, "interval is empty");
+        let mut x = self.rng.next_u64();
+        let size = (n as u64).wrapping_sub(m as u64);
+        // All ones from the highest bit of `size` downwards.
+        let mask = (!0u64).checked_shr(size.leading_zeros()).unwrap_or(0);
+        x &= mask;
+        while x > size {
+            x = self.rng.next_u64() & mask;
+        }
+        x.wrapping_add(m as u64) as i64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lua() {
+        // Generated with Lua 5.4.7.
+        let mut random = LuaRandom::new(42);
+        assert_eq!(random.random_int() as u64, 0xee49b4f7660276e5);
+        assert_eq!(random.random_int() as u64, 0x73a81c109b785431);
+        assert_eq!(random.random_range(1, 6), 4);
+        assert_eq!(random.random_up_to(100), 86);
+        assert_eq!(random.random(), 0.6173176359584727);
+        assert_eq!(random.random_range(-5, 1000000), 789050);
+
+        let mut random = LuaRandom::with_seeds(7, 9);
+        assert_eq!(random.random_int() as u64, 0x405b1452200dfe98);
+        assert_eq!(random.random_range(i64::MIN, i64::MAX),
+                   3385887694369438327);
+        assert_eq!(random.random_range(3, 3), 3);
+        assert_eq!(random.random_int() as u64, 0xd5f4626f2d80f1bb);
+    }
+}
@@ -0,0 +1,365 @@
+//! Parallel filling of large buffers and parallel iterators using rayon.
+
+use std::collections::VecDeque;
+
+use rand_core::RngCore;
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback,
+                            UnindexedConsumer};
+use rayon::iter::Map;
+use rayon::prelude::*;
+
+use common::u64_to_f64;
+use {Jumpable, XoshiroRng};
+
+/// Number of bytes filled by each sub-generator.
+const CHUNK_BYTES: usize = 1 << 20;
+
+/// Number of `u64` generated by each sub-generator.
+const CHUNK_WORDS: usize = CHUNK_BYTES / 8;
+
+/// Filling buffers in parallel using jumped sub-generators.
+///
+/// The buffer is split into chunks of 1 MiB, and chunk `i` is filled by the
+/// generator jumped `i` times. The result therefore only depends on the
+/// generator and the length of the buffer, not on the number of threads.
+/// Afterwards, the generator is jumped once per chunk, so it continues on a
+/// subsequence not used for the buffer.
+///
+/// This is implemented for all generators supporting `Jumpable`. The parallel
+/// iterators additionally require `XoshiroRng`, to position the generators
+/// with `advance()`.
+///
+/// ```
+/// # extern crate xoshiro;
+/// # fn main() {
+/// use xoshiro::{ParFill, Xoshiro256StarStar};
+///
+/// let mut rng = Xoshiro256StarStar::from_seed_u64(0);
+/// let mut buf = vec![0u8; 3 << 20];
+/// rng.par_fill_bytes(&mut buf);
+/// # }
+/// ```
+pub trait ParFill: RngCore + Jumpable + Clone + Send {
+    /// Fill `dest` with random bytes in parallel.
+    fn par_fill_bytes(&mut self, dest: &mut [u8]) {
+        let chunks = dest.par_chunks_mut(CHUNK_BYTES);
+        let rngs = split_chunks(self, chunks.len());
+        chunks.zip(rngs).for_each(|(chunk, mut rng)| rng.fill_bytes(chunk));
+    }
+
+    /// Fill `dest` with random `u64` in parallel.
+    fn par_fill_u64(&mut self, dest: &mut [u64]) {
+        let chunks = dest.par_chunks_mut(CHUNK_WORDS);
+        let rngs = split_chunks(self, chunks.len());
+        chunks.zip(rngs).for_each(|(chunk, mut rng)| {
+            for x in chunk {
+                *x = rng.next_u64();
+            }
+        });
+    }
+
+    /// Return an indexed parallel iterator over `n` random `u64`.
+    ///
+    /// The values are the same as those written by `par_fill_u64()` into a
+    /// buffer of length `n`, and the generator is jumped the same way. The
+    /// result of e.g. a sum is therefore independent of the number of
+    /// threads.
+    ///
+    /// ```
+    /// # extern crate rayon;
+    /// # extern crate xoshiro;
+    /// # fn main() {
+    /// use rayon::prelude::*;
+    /// use xoshiro::{ParFill, Xoshiro256PlusPlus};
+    ///
+    /// let mut rng = Xoshiro256PlusPlus::from_seed_u64(0);
+    /// let ones = rng.par_iter_u64(1 << 20).filter(|x| x & 1 == 1).count();
+    /// # assert!(ones > 500_000 && ones < 550_000);
+    /// # }
+    /// ```
+    fn par_iter_u64(&mut self, n: usize) -> ParIterU64<Self>
+        where Self: XoshiroRng + Sync
+    {
+        let chunks = n.div_ceil(CHUNK_WORDS);
+        ParIterU64 { rngs: split_chunks(self, chunks), len: n }
+    }
+
+    /// Return an indexed parallel iterator over `n` random `f64` in [0, 1).
+    ///
+    /// The values are generated from `par_iter_u64()` like in `next_f64()`.
+    ///
+    /// ```
+    /// # extern crate rayon;
+    /// # extern crate xoshiro;
+    /// # fn main() {
+    /// use rayon::prelude::*;
+    /// use xoshiro::{ParFill, Xoshiro256PlusPlus};
+    ///
+    /// let mut rng = Xoshiro256PlusPlus::from_seed_u64(0);
+    /// let n = 1 << 20;
+    /// let mean = rng.par_iter_f64(n).sum::<f64>() / n as f64;
+    /// # assert!((mean - 0.5).abs() < 0.01);
+    /// # }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn par_iter_f64(&mut self, n: usize)
+        -> Map<ParIterU64<Self>, fn(u64) -> f64>
+        where Self: XoshiroRng + Sync
+    {
+        self.par_iter_u64(n).map(u64_to_f64 as fn(u64) -> f64)
+    }
+}
+
+impl<R: RngCore + Jumpable + Clone + Send> ParFill for R {}
+
+/// Return `n` successively jumped copies of `rng`, and jump `rng` past them.
+fn split_chunks<R: Jumpable + Clone>(rng: &mut R, n: usize) -> Vec<R> {
+    let mut rngs = Vec::with_capacity(n);
+    for _ in 0..n {
+        rngs.push(rng.clone());
+        rng.jump();
+    }
+    rngs
+}
+
+/// An indexed parallel iterator over random `u64`.
+///
+/// This is created by `ParFill::par_iter_u64`. Value `i` is generated by the
+/// generator for its chunk of `2^17` values, advanced past the preceding
+/// values of that chunk in O(log i), so the iterator can be split anywhere and
+/// iterated from both ends efficiently.
+#[derive(Debug, Clone)]
+pub struct ParIterU64<R> {
+    rngs: Vec<R>,
+    len: usize,
+}
+
+impl<R: XoshiroRng + Send + Sync> ParallelIterator for ParIterU64<R> {
+    type Item = u64;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+        where C: UnindexedConsumer<u64>
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.len)
+    }
+}
+
+impl<R: XoshiroRng + Send + Sync> IndexedParallelIterator
+    for ParIterU64<R>
+{
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn drive<C: Consumer<u64>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<u64>>(self, callback: CB)
+        -> CB::Output
+    {
+        callback.callback(U64Producer {
+            rngs: &self.rngs,
+            start: 0,
+            end: self.len,
+        })
+    }
+}
+
+/// The values `start..end` of a `ParIterU64`.
+struct U64Producer<'a, R: 'a> {
+    rngs: &'a [R],
+    start: usize,
+    end: usize,
+}
+
+impl<'a, R: XoshiroRng + Send + Sync> Producer for U64Producer<'a, R> {
+    type Item = u64;
+    type IntoIter = U64Iter<'a, R>;
+
+    fn into_iter(self) -> U64Iter<'a, R> {
+        U64Iter {
+            rngs: self.rngs,
+            rng: None,
+            start: self.start,
+            end: self.end,
+            back: VecDeque::new(),
+        }
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let mid = self.start + index;
+        (U64Producer { end: mid, ..self }, U64Producer { start: mid, ..self })
+    }
+}
+
+// Derives would require `R: Copy`.
+impl<'a, R> Clone for U64Producer<'a, R> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, R> Copy for U64Producer<'a, R> {}
+
+/// Number of values generated at once by `U64Iter::next_back()`.
+const BACK_WORDS: usize = 256;
+
+/// A sequential iterator over the values `start..end` of a `ParIterU64`.
+struct U64Iter<'a, R: 'a> {
+    rngs: &'a [R],
+    /// The generator positioned at `start`, if it was already created.
+    rng: Option<R>,
+    start: usize,
+    end: usize,
+    /// The values generated by `next_back()` that were not returned yet,
+    /// which follow `end`.
+    back: VecDeque<u64>,
+}
+
+impl<'a, R: XoshiroRng> U64Iter<'a, R> {
+    /// Return the generator positioned at value `i`.
+    fn seek(&self, i: usize) -> R {
+        let mut rng = self.rngs[i / CHUNK_WORDS].clone();
+        // `next_u64()` takes two outputs of the 32-bit generators.
+        let words = (i % CHUNK_WORDS) as u128;
+        rng.advance(words * u128::from(64 / R::OUTPUT_BITS));
+        rng
+    }
+}
+
+impl<'a, R: XoshiroRng> Iterator for U64Iter<'a, R> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        if self.start == self.end {
+            return self.back.pop_front();
+        }
+        if self.start.is_multiple_of(CHUNK_WORDS) {
+            self.rng = None;
+        }
+        if self.rng.is_none() {
+            self.rng = Some(self.seek(self.start));
+        }
+        self.start += 1;
+        self.rng.as_mut().map(RngCore::next_u64)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start + self.back.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, R: XoshiroRng> ExactSizeIterator for U64Iter<'a, R> {}
+
+impl<'a, R: XoshiroRng> DoubleEndedIterator for U64Iter<'a, R> {
+    /// Return the last value. When no generated values are left, this
+    /// generates up to `BACK_WORDS` values preceding `end` in its chunk, from
+    /// a generator positioned with `seek()`.
+    fn next_back(&mut self) -> Option<u64> {
+        if self.back.is_empty() && self.start < self.end {
+            let chunk = (self.end - 1) / CHUNK_WORDS * CHUNK_WORDS;
+            let from = self.start.max(chunk)
+                .max(self.end.saturating_sub(BACK_WORDS));
+            let mut rng = self.seek(from);
+            self.back.extend((from..self.end).map(|_| rng.next_u64()));
+            self.end = from;
+        }
+        self.back.pop_back()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use {Xoroshiro64StarStar, Xoshiro256StarStar};
+
+    fn check<R: ParFill>(rng: R) {
+        let len = 2 * CHUNK_BYTES + 12345;
+        let mut expected = vec![0u8; len];
+        let mut seq = rng.clone();
+        for chunk in expected.chunks_mut(CHUNK_BYTES) {
+            let mut sub = seq.clone();
+            sub.fill_bytes(chunk);
+            seq.jump();
+        }
+
+        let mut par = rng.clone();
+        let mut bytes = vec![0u8; len];
+        par.par_fill_bytes(&mut bytes);
+        assert!(bytes == expected);
+        assert_eq!(par.next_u64(), seq.next_u64());
+
+        let mut par = rng.clone();
+        let mut words = vec![0u64; len / 8];
+        par.par_fill_u64(&mut words);
+        for (w, b) in words.iter().zip(expected.chunks(8)) {
+            let mut x = [0; 8];
+            x.copy_from_slice(b);
+            assert_eq!(*w, u64::from_le_bytes(x));
+        }
+    }
+
+    #[test]
+    fn par_iter() {
+        let rng = Xoshiro256StarStar::from_seed_u64(1);
+        let len = 2 * CHUNK_WORDS + 123;
+        let mut seq = rng.clone();
+        let mut expected = vec![0u64; len];
+        seq.par_fill_u64(&mut expected);
+
+        let mut par = rng.clone();
+        let v: Vec<u64> = par.par_iter_u64(len).collect();
+        assert!(v == expected);
+        assert_eq!(par, seq);
+
+        // Force splits in the middle of chunks.
+        let v: Vec<u64> = rng.clone().par_iter_u64(len)
+            .with_max_len(1000).collect();
+        assert!(v == expected);
+        let v: Vec<u64> = rng.clone().par_iter_u64(len).rev().collect();
+        assert!(v.iter().eq(expected.iter().rev()));
+
+        let v: Vec<f64> = rng.clone().par_iter_f64(len).collect();
+        for (&x, &y) in v.iter().zip(&expected) {
+            assert_eq!(x, u64_to_f64(y));
+        }
+        assert_eq!(rng.clone().par_iter_u64(0).count(), 0);
+    }
+
+    #[test]
+    fn rev_32_bit() {
+        let rng = Xoroshiro64StarStar::from_seed_u64(1);
+        let len = CHUNK_WORDS + 123;
+        let mut expected = vec![0u64; len];
+        rng.clone().par_fill_u64(&mut expected);
+        let v: Vec<u64> = rng.clone().par_iter_u64(len)
+            .with_max_len(1000).rev().collect();
+        assert!(v.iter().eq(expected.iter().rev()));
+
+        // Alternate between both ends.
+        let rngs = split_chunks(&mut rng.clone(), 2);
+        let mut iter = U64Producer { rngs: &rngs, start: 0, end: len }
+            .into_iter();
+        let (mut front, mut back) = (Vec::new(), Vec::new());
+        while let Some(x) = iter.next_back() {
+            back.push(x);
+            front.extend(iter.next());
+            assert_eq!(iter.len(), len - front.len() - back.len());
+        }
+        back.reverse();
+        front.extend(back);
+        assert!(front == expected);
+    }
+
+    #[test]
+    fn deterministic() {
+        check(Xoshiro256StarStar::from_seed_u64(1));
+        check(Xoroshiro64StarStar::from_seed_u64(1));
+    }
+}
@@ -0,0 +1,140 @@
+//! Arithmetic on polynomials over GF(2).
+//!
+//! The state transitions of the xoshiro family are linear maps over GF(2).
+//! Advancing a generator by `n` steps is therefore equivalent to evaluating
+//! the polynomial x^n modulo the characteristic polynomial of the linear
+//! engine at the transition matrix, which is what the jump functions do.
+//!
+//! Polynomials are stored as little-endian `u64` words, bit `i` being the
+//! coefficient of x^i. Characteristic polynomials are monic of degree `k`
+//! (the state size in bits), so their leading term is implicit.
+
+/// Maximal number of 64-bit words in a polynomial.
+const MAX_WORDS: usize = 8;
+
+/// A polynomial of degree less than 512.
+pub type Poly = [u64; MAX_WORDS];
+
+/// Characteristic polynomial of the xoroshiro64 linear engine.
+pub const XOROSHIRO64: [u64; 1] = [0x053be9da6e2286c1];
+
+/// Characteristic polynomial of the xoroshiro128 linear engine.
+pub const XOROSHIRO128: [u64; 2] = [0x095b8f76579aa001, 0x0008828e513b43d5];
+
+/// Characteristic polynomial of the xoroshiro128++ linear engine.
+pub const XOROSHIRO128PLUSPLUS: [u64; 2] = [
+    0x8dae70779760b081, 0x0031bcf2f855d6e5,
+];
+
+/// Characteristic polynomial of the xoshiro128 linear engine.
+pub const XOSHIRO128: [u64; 2] = [0x1b489db6de18fc01, 0x00fc65a2006254b1];
+
+/// Characteristic polynomial of the xoshiro256 linear engine.
+pub const XOSHIRO256: [u64; 4] = [
+    0x9d116f2bb0f0f001, 0x0280002bcefd1a5e,
+    0x04b4edcf26259f85, 0x0003c03c3f3ecb19,
+];
+
+/// Characteristic polynomial of the xoshiro512 linear engine.
+pub const XOSHIRO512: [u64; 8] = [
+    0xcf3cff0c00000001, 0x7fdc78d886f00c63, 0xf05e63fca6d7b781,
+    0x7a67058e7bbab6f0, 0xf11eef832e32518f, 0x51ba7c47edc758ad,
+    0x8f2d27268ce4b20b, 0x0000500055d8b77f,
+];
+
+/// Multiply `a` by x modulo `p`.
+#[inline]
+fn mul_x(a: &mut Poly, p: &[u64]) {
+    let n = p.len();
+    let carry = a[n - 1] >> 63;
+    for i in (1..n).rev() {
+        a[i] = (a[i] << 1) | (a[i - 1] >> 63);
+    }
+    a[0] <<= 1;
+    if carry != 0 {
+        for (a, p) in a.iter_mut().zip(p) {
+            *a ^= *p;
+        }
+    }
+}
+
+/// Multiply `a` by `b` modulo `p`.
+fn mul_mod(a: &Poly, b: &Poly, p: &[u64]) -> Poly {
+    let mut r = [0; MAX_WORDS];
+    for &w in b[..p.len()].iter().rev() {
+        for bit in (0..64).rev() {
+            mul_x(&mut r, p);
+            if (w >> bit) & 1 != 0 {
+                for (r, a) in r.iter_mut().zip(a) {
+                    *r ^= *a;
+                }
+            }
+        }
+    }
+    r
+}
+
+/// Compute x^e modulo `p`, where `e` is given as little-endian words.
+pub fn x_pow_mod(e: &[u64], p: &[u64]) -> Poly {
+    debug_assert!(p.len() <= MAX_WORDS);
+    let mut r = [0; MAX_WORDS];
+    r[0] = 1;
+    let mut started = false;
+    for &w in e.iter().rev() {
+        for bit in (0..64).rev() {
+            if started {
+                r = mul_mod(&r, &r, p);
+            }
+            if (w >> bit) & 1 != 0 {
+                mul_x(&mut r, p);
+                started = true;
+            }
+        }
+    }
+    r
+}
+
+/// Compute x^(n * 2^shift) modulo `p`, the polynomial of `n` jumps by 2^shift
+/// steps.
+pub fn x_pow_jumps_mod(n: u64, shift: u32, p: &[u64]) -> Poly {
+    debug_assert!(shift < 64 * (MAX_WORDS as u32 - 1));
+    let mut e = [0; MAX_WORDS];
+    let (word, bit) = ((shift / 64) as usize, shift % 64);
+    e[word] = n << bit;
+    if bit != 0 {
+        e[word + 1] = n >> (64 - bit);
+    }
+    x_pow_mod(&e, p)
+}
+
+/// Split a `u128` into little-endian words.
+#[inline]
+pub fn u128_words(n: u128) -> [u64; 2] {
+    [n as u64, (n >> 64) as u64]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn published_jumps() {
+        // x^(2^j) mod p must reproduce the constants of the reference
+        // implementations.
+        let jump = x_pow_mod(&[0, 1], &XOROSHIRO128);
+        assert_eq!(jump[..2], [0xdf900294d8f554a5, 0x170865df4b3201fc]);
+        let jump = x_pow_mod(&[0, 1], &XOSHIRO128);
+        assert_eq!(jump[..2], [0xf542d2d38764000b, 0x77f2db5b6fa035c3]);
+        let jump = x_pow_mod(&[0, 0, 1], &XOSHIRO256);
+        assert_eq!(jump[..4], [
+            0x180ec6d33cfd0aba, 0xd5a61266f0c9392c,
+            0xa9582618e03fc9aa, 0x39abdc4529b1661c,
+        ]);
+        let jump = x_pow_mod(&[0, 0, 0, 0, 1], &XOSHIRO512);
+        assert_eq!(jump[..], [
+            0x33ed89b6e7a353f9, 0x760083d7955323be, 0x2837f2fbb5f22fae,
+            0x4b8c5674d309511c, 0xb11ac47a7ba28c25, 0xf1be7667092bcc1c,
+            0x53851efdb6df0aaf, 0x1ebbc8b23eaf25db,
+        ]);
+    }
+}
@@ -0,0 +1,192 @@
+use std::collections::BTreeSet;
+
+use {Jumpable, XoshiroRng};
+
+/// A pool handing out numbered generators on non-overlapping subsequences.
+///
+/// Stream `k` is the master generator jumped `k` times. Each index is handed
+/// out at most once, and only indices below `2^Jumpable::STREAM_BITS` are
+/// used, so the generators of a pool never overlap. For the xoroshiro64
+/// generators, this limits a pool to 2^32 streams; for the others, every
+/// `u64` index is available. Any stream can be recreated later with
+/// `stream()`, e.g. to reproduce the behavior of a single worker.
+///
+/// ```
+/// # extern crate xoshiro;
+/// # fn main() {
+/// use xoshiro::{StreamPool, Xoshiro256PlusPlus};
+///
+/// let mut pool = StreamPool::<Xoshiro256PlusPlus>::from_seed_u64(42);
+/// let (i, worker) = pool.allocate();
+/// let (j, _) = pool.allocate();
+/// assert_eq!((i, j), (0, 1));
+///
+/// // Later, replay the first worker.
+/// assert_eq!(pool.stream(i), worker);
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct StreamPool<R> {
+    master: R,
+    /// The generator of stream `next`.
+    cursor: R,
+    /// The lowest index that might not be allocated.
+    next: u64,
+    allocated: BTreeSet<u64>,
+}
+
+impl<R: Jumpable + Clone> StreamPool<R> {
+    /// Create a pool whose stream 0 is `master`.
+    pub fn new(master: R) -> StreamPool<R> {
+        StreamPool {
+            cursor: master.clone(),
+            master,
+            next: 0,
+            allocated: BTreeSet::new(),
+        }
+    }
+
+    /// Allocate the lowest unallocated stream, returning its index and
+    /// generator.
+    ///
+    /// # Panics
+    ///
+    /// If all streams below `2^Jumpable::STREAM_BITS` are allocated.
+    pub fn allocate(&mut self) -> (u64, R) {
+        while self.allocated.contains(&self.next) {
+            self.advance();
+        }
+        assert!(in_capacity::<R>(self.next), "StreamPool is exhausted");
+        let index = self.next;
+        let rng = self.cursor.clone();
+        self.allocated.insert(index);
+        self.advance();
+        (index, rng)
+    }
+
+    /// Allocate stream `index`, or return `None` if it is already allocated
+    /// or not below `2^Jumpable::STREAM_BITS`, where it would overlap a lower
+    /// stream.
+    ///
+    /// The generator is computed like `stream(index)`.
+    pub fn allocate_index(&mut self, index: u64) -> Option<R> {
+        if !in_capacity::<R>(index) || !self.allocated.insert(index) {
+            return None;
+        }
+        Some(self.stream(index))
+    }
+
+    /// Return whether stream `index` has been allocated.
+    pub fn is_allocated(&self, index: u64) -> bool {
+        self.allocated.contains(&index)
+    }
+
+    /// Return the indices of the allocated streams in increasing order.
+    pub fn allocated(&self) -> impl Iterator<Item = u64> + '_ {
+        self.allocated.iter().cloned()
+    }
+
+    /// Recreate the generator of stream `index`, whether it is allocated or
+    /// not.
+    ///
+    /// This does not allocate the stream. It jumps with `jump_n()`, from the
+    /// cursor if `index` is not below the lowest unallocated stream and from
+    /// the master generator otherwise, which takes O(log index) polynomial
+    /// multiplications with the generators of this crate.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is not below `2^Jumpable::STREAM_BITS`.
+    pub fn stream(&self, index: u64) -> R {
+        assert!(in_capacity::<R>(index),
+                "stream {} overlaps a lower stream of the pool", index);
+        let (mut rng, start) = if index >= self.next {
+            (self.cursor.clone(), self.next)
+        } else {
+            (self.master.clone(), 0)
+        };
+        rng.jump_n(index - start);
+        rng
+    }
+
+    /// Move the cursor to the next stream.
+    fn advance(&mut self) {
+        self.cursor.jump();
+        self.next += 1;
+    }
+}
+
+/// Return whether stream `index` does not overlap the lower streams of `R`.
+fn in_capacity<R: Jumpable>(index: u64) -> bool {
+    R::STREAM_BITS >= 64 || index >> R::STREAM_BITS == 0
+}
+
+impl<R: XoshiroRng> StreamPool<R> {
+    /// Create a pool from a master seed, seeding stream 0 with
+    /// `from_seed_u64()`.
+    pub fn from_seed_u64(seed: u64) -> StreamPool<R> {
+        StreamPool::new(R::from_seed_u64(seed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use {Xoroshiro128StarStar, Xoroshiro64Star};
+
+    #[test]
+    fn allocate() {
+        let mut pool = StreamPool::<Xoroshiro128StarStar>::from_seed_u64(1);
+        let mut streams = Xoroshiro128StarStar::from_seed_u64(1).streams();
+        let expected: Vec<_> = (&mut streams).take(6).collect();
+
+        assert_eq!(pool.allocate_index(2), Some(expected[2].clone()));
+        assert_eq!(pool.allocate_index(2), None);
+        assert_eq!(pool.allocate(), (0, expected[0].clone()));
+        assert_eq!(pool.allocate(), (1, expected[1].clone()));
+        assert_eq!(pool.allocate(), (3, expected[3].clone()));
+        assert_eq!(pool.allocate_index(1), None);
+        assert!(pool.is_allocated(3));
+        assert!(!pool.is_allocated(4));
+        assert_eq!(pool.allocated().collect::<Vec<_>>(), [0, 1, 2, 3]);
+        for (i, rng) in expected.iter().enumerate() {
+            assert_eq!(&pool.stream(i as u64), rng);
+        }
+        assert!(!pool.is_allocated(5));
+
+        let far = u64::MAX - 1;
+        assert_eq!(pool.allocate_index(far),
+                   Some(Xoroshiro128StarStar::nth_stream(1, far)));
+        assert_eq!(pool.stream(far), Xoroshiro128StarStar::nth_stream(1, far));
+    }
+
+    #[test]
+    fn capacity() {
+        let mut pool = StreamPool::<Xoroshiro64Star>::from_seed_u64(1);
+        let last = (1 << 32) - 1;
+        assert_eq!(pool.allocate_index(last),
+                   Some(Xoroshiro64Star::nth_stream(1, last)));
+        assert_eq!(pool.allocate_index(1 << 32), None);
+        assert_eq!(pool.allocate_index(u64::MAX), None);
+        assert!(!pool.is_allocated(1 << 32));
+
+        // Stream 2^32 would be stream 0 advanced by one output.
+        let mut rng = Xoroshiro64Star::from_seed_u64(1);
+        rng.jump_n(1 << 32);
+        let mut shifted = Xoroshiro64Star::from_seed_u64(1);
+        shifted.advance(1);
+        assert_eq!(rng, shifted);
+    }
+
+    #[test]
+    #[should_panic]
+    fn stream_beyond_capacity() {
+        StreamPool::<Xoroshiro64Star>::from_seed_u64(1).stream(1 << 32);
+    }
+
+    #[test]
+    fn zero_seed() {
+        let mut pool = StreamPool::<Xoroshiro64Star>::from_seed_u64(0);
+        assert_eq!(pool.allocate(), (0, Xoroshiro64Star::from_seed_u64(0)));
+    }
+}
@@ -0,0 +1,333 @@
+//! Python bindings.
+//!
+//! The `pyo3` feature defines a Python extension module `xoshiro` with a
+//! class for each generator, named like its Rust counterpart. Seeded with the
+//! same `u64`, the classes produce exactly the same streams as the Rust
+//! generators:
+//!
+//! ```python
+//! import numpy as np
+//! import xoshiro
+//!
+//! rng = xoshiro.Xoshiro256StarStar(42)
+//! x = rng.next_u64()
+//! rng.jump()
+//! a = np.empty(1000, dtype=np.uint64)
+//! rng.fill_u64(a)
+//! ```
+//!
+//! The methods `fill_u64`, `fill_u32` and `fill_double` write into any
+//! writable, C-contiguous buffer of the matching type, such as a NumPy array,
+//! an `array.array` or a `memoryview`, without going through Python objects.
+//!
+//! The generators can also drive `numpy.random.Generator` directly, which
+//! only needs the `capsule` and `lock` attributes of a bit generator:
+//!
+//! ```python
+//! gen = np.random.Generator(xoshiro.Xoshiro256PlusPlus(42))
+//! samples = gen.normal(size=1000)
+//! ```
+//!
+//! NumPy then draws from the generator through its function table
+//! `bitgen_t`, holding `lock`. The methods of the generator do not take the
+//! lock, so they must not be called while another thread uses the NumPy
+//! generator.
+//!
+//! Build the module with [maturin](https://www.maturin.rs), using the
+//! `pyproject.toml` of the crate:
+//!
+//! ```text
+//! maturin develop --release
+//! ```
+
+use std::cell::Cell;
+
+use pyo3::buffer::{Element, PyBuffer};
+use pyo3::exceptions::{PyBufferError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::sync::PyOnceLock;
+use pyo3::types::{PyBytes, PyCapsule};
+use rand_core::RngCore;
+
+use bitgen::BitGen;
+
+/// The capsule name NumPy expects for the function table of a bit generator.
+const BITGEN_NAME: &::std::ffi::CStr = pyo3::ffi::c_str!("BitGenerator");
+
+/// The contents of the capsule of a generator.
+///
+/// The function table comes first, because NumPy takes the pointer to the
+/// contents as a pointer to the table. `owner` keeps the generator alive.
+#[repr(C)]
+struct NumpyCapsule {
+    bitgen: BitGen,
+    owner: Py<PyAny>,
+}
+
+// The generator `bitgen` points to is owned by `owner`, which is `Send`.
+unsafe impl Send for NumpyCapsule {}
+
+/// Return the elements of `buf` for writing.
+fn writable<'a, T: Element>(py: Python<'a>, buf: &'a PyBuffer<T>)
+    -> PyResult<&'a [Cell<T>]>
+{
+    buf.as_mut_slice(py).ok_or_else(|| {
+        PyBufferError::new_err("buffer must be writable and C-contiguous")
+    })
+}
+
+/// Define the Python class `$rng` wrapping the generator of the same name with
+/// the state `[$word; $n]`.
+macro_rules! py_generator {
+    ($rng:ident, [$word:ty; $n:expr] $(, $long_jump:ident)*) => {
+        #[doc = concat!("The Python class wrapping `", stringify!($rng),
+                        "`.")]
+        #[pyclass(module = "xoshiro", skip_from_py_object)]
+        pub struct $rng {
+            rng: ::$rng,
+            lock: PyOnceLock<Py<PyAny>>,
+        }
+
+        #[pymethods]
+        impl $rng {
+            /// Seed the generator from an integer like `from_seed_u64()`.
+            #[new]
+            fn new(seed: u64) -> $rng {
+                $rng {
+                    rng: ::$rng::from_seed_u64(seed),
+                    lock: PyOnceLock::new(),
+                }
+            }
+
+            /// Create a generator with the given state words.
+            #[staticmethod]
+            fn from_state(state: [$word; $n]) -> PyResult<$rng> {
+                if state.iter().all(|&w| w == 0) {
+                    return Err(PyValueError::new_err(
+                        "the state must not be entirely zero"));
+                }
+                Ok($rng {
+                    rng: ::$rng::new_unchecked(state),
+                    lock: PyOnceLock::new(),
+                })
+            }
+
+            /// Return the state words.
+            fn state(&self) -> [$word; $n] {
+                self.rng.state()
+            }
+
+            fn next_u32(&mut self) -> u32 {
+                self.rng.next_u32()
+            }
+
+            fn next_u64(&mut self) -> u64 {
+                self.rng.next_u64()
+            }
+
+            /// Return a float in [0, 1) like `next_f64()`.
+            fn next_double(&mut self) -> f64 {
+                self.rng.next_f64()
+            }
+
+            fn jump(&mut self) {
+                self.rng.jump();
+            }
+
+            $(
+                fn $long_jump(&mut self) {
+                    self.rng.long_jump();
+                }
+            )*
+
+            /// Return `n` random bytes like `fill_bytes()`.
+            fn random_bytes<'py>(&mut self, py: Python<'py>, n: usize)
+                -> PyResult<Bound<'py, PyBytes>>
+            {
+                PyBytes::new_with(py, n, |bytes| {
+                    self.rng.fill_bytes(bytes);
+                    Ok(())
+                })
+            }
+
+            /// Fill a buffer of `uint64` like `fill_u64()`.
+            fn fill_u64(&mut self, py: Python<'_>, buf: PyBuffer<u64>)
+                -> PyResult<()>
+            {
+                for x in writable(py, &buf)? {
+                    x.set(self.rng.next_u64());
+                }
+                Ok(())
+            }
+
+            /// Fill a buffer of `uint32` like `fill_u32()`.
+            fn fill_u32(&mut self, py: Python<'_>, buf: PyBuffer<u32>)
+                -> PyResult<()>
+            {
+                for x in writable(py, &buf)? {
+                    x.set(self.rng.next_u32());
+                }
+                Ok(())
+            }
+
+            /// Fill a buffer of `double` like `fill_f64()`.
+            fn fill_double(&mut self, py: Python<'_>, buf: PyBuffer<f64>)
+                -> PyResult<()>
+            {
+                for x in writable(py, &buf)? {
+                    x.set(self.rng.next_f64());
+                }
+                Ok(())
+            }
+
+            /// The NumPy function table of the generator, which makes it
+            /// usable as the bit generator of `numpy.random.Generator`.
+            #[getter]
+            fn capsule<'py>(slf: &Bound<'py, Self>)
+                -> PyResult<Bound<'py, PyCapsule>>
+            {
+                let rng: *mut ::$rng = &mut slf.borrow_mut().rng;
+                let contents = NumpyCapsule {
+                    bitgen: BitGen::new(rng),
+                    owner: slf.clone().into_any().unbind(),
+                };
+                PyCapsule::new_with_value(slf.py(), contents, BITGEN_NAME)
+            }
+
+            /// The lock NumPy holds while drawing from the generator.
+            #[getter]
+            fn lock(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+                self.lock.get_or_try_init(py, || {
+                    Ok(py.import("threading")?.getattr("Lock")?.call0()?
+                       .unbind())
+                }).map(|lock| lock.clone_ref(py))
+            }
+
+            fn __copy__(&self) -> $rng {
+                $rng { rng: self.rng.clone(), lock: PyOnceLock::new() }
+            }
+
+            fn __repr__(&self) -> String {
+                format!("{}.from_state({:?})", stringify!($rng),
+                        self.rng.state())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "xoroshiro64")]
+py_generator!(Xoroshiro64Star, [u32; 2]);
+#[cfg(feature = "xoroshiro64")]
+py_generator!(Xoroshiro64StarStar, [u32; 2]);
+#[cfg(feature = "xoroshiro128")]
+py_generator!(Xoroshiro128Plus, [u64; 2], long_jump);
+#[cfg(feature = "xoroshiro128")]
+py_generator!(Xoroshiro128PlusPlus, [u64; 2], long_jump);
+#[cfg(feature = "xoroshiro128")]
+py_generator!(Xoroshiro128StarStar, [u64; 2], long_jump);
+#[cfg(feature = "xoshiro128")]
+py_generator!(Xoshiro128Plus, [u32; 4], long_jump);
+#[cfg(feature = "xoshiro128")]
+py_generator!(Xoshiro128PlusPlus, [u32; 4], long_jump);
+#[cfg(feature = "xoshiro128")]
+py_generator!(Xoshiro128StarStar, [u32; 4], long_jump);
+#[cfg(feature = "xoshiro256")]
+py_generator!(Xoshiro256Plus, [u64; 4], long_jump);
+#[cfg(feature = "xoshiro256")]
+py_generator!(Xoshiro256PlusPlus, [u64; 4], long_jump);
+#[cfg(feature = "xoshiro256")]
+py_generator!(Xoshiro256StarStar, [u64; 4], long_jump);
+#[cfg(feature = "xoshiro512")]
+py_generator!(Xoshiro512Plus, [u64; 8], long_jump);
+#[cfg(feature = "xoshiro512")]
+py_generator!(Xoshiro512PlusPlus, [u64; 8], long_jump);
+#[cfg(feature = "xoshiro512")]
+py_generator!(Xoshiro512StarStar, [u64; 8], long_jump);
+
+/// The Python module `xoshiro`.
+#[cfg_attr(not(any(feature = "xoroshiro64", feature = "xoroshiro128",
+                   feature = "xoshiro128", feature = "xoshiro256",
+                   feature = "xoshiro512")),
+           allow(unused_variables))]
+#[pymodule]
+fn xoshiro(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    #[cfg(feature = "xoroshiro64")]
+    {
+        m.add_class::<Xoroshiro64Star>()?;
+        m.add_class::<Xoroshiro64StarStar>()?;
+    }
+    #[cfg(feature = "xoroshiro128")]
+    {
+        m.add_class::<Xoroshiro128Plus>()?;
+        m.add_class::<Xoroshiro128PlusPlus>()?;
+        m.add_class::<Xoroshiro128StarStar>()?;
+    }
+    #[cfg(feature = "xoshiro128")]
+    {
+        m.add_class::<Xoshiro128Plus>()?;
+        m.add_class::<Xoshiro128PlusPlus>()?;
+        m.add_class::<Xoshiro128StarStar>()?;
+    }
+    #[cfg(feature = "xoshiro256")]
+    {
+        m.add_class::<Xoshiro256Plus>()?;
+        m.add_class::<Xoshiro256PlusPlus>()?;
+        m.add_class::<Xoshiro256StarStar>()?;
+    }
+    #[cfg(feature = "xoshiro512")]
+    {
+        m.add_class::<Xoshiro512Plus>()?;
+        m.add_class::<Xoshiro512PlusPlus>()?;
+        m.add_class::<Xoshiro512StarStar>()?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn xoshiro256ss() {
+        Python::initialize();
+        Python::attach(|py| {
+            let mut py_rng = Xoshiro256StarStar::new(42);
+            let mut rng = ::Xoshiro256StarStar::from_seed_u64(42);
+            assert_eq!(py_rng.next_u64(), rng.next_u64());
+            assert_eq!(py_rng.next_double(), rng.next_f64());
+            py_rng.long_jump();
+            rng.long_jump();
+
+            let array = py.import("array").unwrap().getattr("array").unwrap();
+            let a = array.call1(("Q", vec![0u64; 5])).unwrap();
+            py_rng.fill_u64(py, PyBuffer::get(&a).unwrap()).unwrap();
+            let mut b = [0; 5];
+            rng.fill_u64(&mut b);
+            assert_eq!(a.extract::<Vec<u64>>().unwrap(), b);
+
+            let bytes = py_rng.random_bytes(py, 3).unwrap();
+            let mut b = [0; 3];
+            rng.fill_bytes(&mut b);
+            assert_eq!(bytes.as_bytes(), b);
+            assert_eq!(py_rng.state(), rng.state());
+
+            // Immutable buffers cannot be filled.
+            let view = py.import("builtins").unwrap()
+                .getattr("memoryview").unwrap()
+                .call1((PyBytes::new(py, &[0; 8]),)).unwrap()
+                .call_method1("cast", ("Q",)).unwrap();
+            assert!(py_rng.fill_u64(py, PyBuffer::get(&view).unwrap())
+                .is_err());
+
+            // The capsule holds the function table, drawing from `py_rng`.
+            let py_rng = Bound::new(py, py_rng).unwrap();
+            let capsule = Xoshiro256StarStar::capsule(&py_rng).unwrap();
+            let table = capsule.pointer_checked(Some(BITGEN_NAME)).unwrap();
+            let table = unsafe { &*(table.as_ptr() as *const BitGen) };
+            assert_eq!(unsafe { (table.next_uint64)(table.state) },
+                       rng.next_u64());
+            assert_eq!(py_rng.borrow().state(), rng.state());
+        });
+        assert!(Xoroshiro64Star::from_state([0, 0]).is_err());
+    }
+}
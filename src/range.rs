@@ -0,0 +1,102 @@
+//! Sampling integers uniformly from ranges.
+
+use std::ops::{Range, RangeInclusive};
+
+use rand_core::RngCore;
+
+use common::{u32_below, u64_below};
+
+/// A range of integers that can be sampled uniformly.
+///
+/// This is implemented for `a..b` and `a..=b` of all primitive integer types
+/// up to 64 bits, and used by the `gen_range()` methods of the generators.
+pub trait SampleRange<T> {
+    /// Return a uniformly distributed value in the range.
+    ///
+    /// # Panics
+    ///
+    /// If the range is empty.
+    fn sample<R: RngCore + ?Sized>(self, rng: &mut R) -> T;
+}
+
+macro_rules! impl_sample_range {
+    ($($t:ty, $unsigned:ty, $wide:ty, $below:ident, $next:ident;)*) => {$(
+        impl SampleRange<$t> for Range<$t> {
+            #[inline]
+            fn sample<R: RngCore + ?Sized>(self, rng: &mut R) -> $t {
+                assert!(self.start < self.end, "cannot sample empty range");
+                let span = (self.end as $unsigned)
+                    .wrapping_sub(self.start as $unsigned) as $wide;
+                (self.start as $unsigned)
+                    .wrapping_add($below(rng, span) as $unsigned) as $t
+            }
+        }
+
+        impl SampleRange<$t> for RangeInclusive<$t> {
+            #[inline]
+            fn sample<R: RngCore + ?Sized>(self, rng: &mut R) -> $t {
+                let (start, end) = self.into_inner();
+                assert!(start <= end, "cannot sample empty range");
+                let span = (end as $unsigned)
+                    .wrapping_sub(start as $unsigned) as $wide;
+                // The full range of the wide type has no representable size.
+                let x = match span.checked_add(1) {
+                    Some(size) => $below(rng, size),
+                    None => rng.$next(),
+                };
+                (start as $unsigned).wrapping_add(x as $unsigned) as $t
+            }
+        }
+    )*}
+}
+
+impl_sample_range! {
+    u8, u8, u32, u32_below, next_u32;
+    u16, u16, u32, u32_below, next_u32;
+    u32, u32, u32, u32_below, next_u32;
+    u64, u64, u64, u64_below, next_u64;
+    usize, usize, u64, u64_below, next_u64;
+    i8, u8, u32, u32_below, next_u32;
+    i16, u16, u32, u32_below, next_u32;
+    i32, u32, u32, u32_below, next_u32;
+    i64, u64, u64, u64_below, next_u64;
+    isize, usize, u64, u64_below, next_u64;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use {SplitMix64, Xoshiro128StarStar};
+
+    #[test]
+    fn bounds() {
+        let mut rng = Xoshiro128StarStar::from_seed_u64(1);
+        let mut seen = [false; 6];
+        for _ in 0..1000 {
+            let x = (1..7u8).sample(&mut rng);
+            seen[x as usize - 1] = true;
+            assert!((-3..=3i16).sample(&mut rng).abs() <= 3);
+            assert!((10..20u64).sample(&mut rng) >= 10);
+            assert!((-100..-90isize).sample(&mut rng) < -90);
+        }
+        assert!(seen.iter().all(|&s| s));
+        assert_eq!((5..6i32).sample(&mut rng), 5);
+        assert_eq!((5..=5u16).sample(&mut rng), 5);
+    }
+
+    #[test]
+    fn full_range() {
+        let mut rng1 = SplitMix64::from_seed_u64(1);
+        let mut rng2 = rng1.clone();
+        assert_eq!((0..=!0u64).sample(&mut rng1), rng2.next_u64());
+        assert_eq!((i32::MIN..=i32::MAX).sample(&mut rng1) as u32,
+                   rng2.next_u32().wrapping_add(1 << 31));
+        (i8::MIN..=i8::MAX).sample(&mut rng1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn empty() {
+        (3..3u32).sample(&mut SplitMix64::from_seed_u64(1));
+    }
+}
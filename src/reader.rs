@@ -0,0 +1,131 @@
+use std::cmp::min;
+use std::io::{self, Write};
+
+use rand_core::RngCore;
+
+/// A reader returning the byte stream of a generator.
+///
+/// The stream consists of the little-endian bytes of successive `next_u64()`
+/// outputs, which for the generators of this crate is the same as for
+/// `fill_bytes()`. Bytes of a partially read output are kept for the next
+/// read, so the stream does not depend on the sizes of the reads. The stream
+/// is infinite and reading never fails.
+///
+/// ```
+/// # extern crate xoshiro;
+/// # fn main() {
+/// use std::io::{self, Read};
+/// use xoshiro::{RngReader, Xoshiro256StarStar};
+///
+/// let reader = RngReader::new(Xoshiro256StarStar::from_seed_u64(0));
+/// let mut out = Vec::new();
+/// io::copy(&mut reader.take(1000), &mut out).unwrap();
+/// assert_eq!(out.len(), 1000);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RngReader<R> {
+    rng: R,
+    buf: [u8; 8],
+    pos: usize,
+}
+
+impl<R: RngCore> RngReader<R> {
+    /// Wrap a generator.
+    pub fn new(rng: R) -> RngReader<R> {
+        RngReader { rng, buf: [0; 8], pos: 8 }
+    }
+
+    /// Return the inner generator, discarding the bytes of a partially read
+    /// output.
+    pub fn into_inner(self) -> R {
+        self.rng
+    }
+}
+
+impl<R: RngCore> io::Read for RngReader<R> {
+    fn read(&mut self, dest: &mut [u8]) -> io::Result<usize> {
+        // Use up the buffered bytes.
+        let n = min(8 - self.pos, dest.len());
+        dest[..n].copy_from_slice(&self.buf[self.pos..self.pos + n]);
+        self.pos += n;
+        let rest = &mut dest[n..];
+
+        let whole = rest.len() - rest.len() % 8;
+        let (words, tail) = rest.split_at_mut(whole);
+        self.rng.fill_bytes(words);
+        if !tail.is_empty() {
+            self.buf = self.rng.next_u64().to_le_bytes();
+            tail.copy_from_slice(&self.buf[..tail.len()]);
+            self.pos = tail.len();
+        }
+        Ok(dest.len())
+    }
+}
+
+/// Write `n` random bytes from `rng` to `writer`.
+///
+/// The bytes are generated into a fixed stack buffer, so large amounts of
+/// data can be written without allocating. They are the same as for a single
+/// `fill_bytes()` call with `n` bytes.
+///
+/// ```
+/// # extern crate xoshiro;
+/// # fn main() {
+/// use std::io;
+/// use xoshiro::{write_random_bytes, Xoshiro256StarStar};
+///
+/// let mut rng = Xoshiro256StarStar::from_seed_u64(0);
+/// write_random_bytes(&mut rng, &mut io::sink(), 1 << 20).unwrap();
+/// # }
+/// ```
+pub fn write_random_bytes<R: RngCore, W: Write>(rng: &mut R, writer: &mut W,
+                                                 mut n: u64)
+    -> io::Result<()>
+{
+    let mut buf = [0; 4096];
+    while n > 0 {
+        let len = min(n, buf.len() as u64) as usize;
+        rng.fill_bytes(&mut buf[..len]);
+        writer.write_all(&buf[..len])?;
+        n -= len as u64;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use super::*;
+    use {Xoroshiro64Star, Xoshiro256StarStar};
+
+    fn check<R: RngCore + Clone>(rng: R) {
+        let mut expected = [0; 200];
+        rng.clone().fill_bytes(&mut expected);
+        let mut reader = RngReader::new(rng);
+        let mut v = [0; 200];
+        let mut start = 0;
+        for len in 0..20 {
+            reader.read_exact(&mut v[start..start + len]).unwrap();
+            start += len;
+        }
+        assert_eq!(v[..start], expected[..start]);
+    }
+
+    #[test]
+    fn write() {
+        let rng = Xoshiro256StarStar::from_seed_u64(0);
+        let mut expected = vec![0; 10005];
+        rng.clone().fill_bytes(&mut expected);
+        let mut v = Vec::new();
+        write_random_bytes(&mut rng.clone(), &mut v, 10005).unwrap();
+        assert_eq!(v, expected);
+    }
+
+    #[test]
+    fn stream() {
+        check(Xoshiro256StarStar::from_seed_u64(0));
+        check(Xoroshiro64Star::from_seed_u64(1));
+    }
+}
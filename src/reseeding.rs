@@ -0,0 +1,134 @@
+use rand_core::{RngCore, SeedableRng, Error};
+
+/// A wrapper reseeding its generator from an entropy source after a fixed
+/// number of generated bytes.
+///
+/// Before more than `threshold` bytes are generated from the current seed,
+/// the generator is replaced by `R::from_rng(&mut source)`. `next_u32()`
+/// counts as 4 bytes and `next_u64()` as 8 bytes, and `fill_bytes()` uses up
+/// the current seed before reseeding. If the source fails, the generator
+/// keeps its state and reseeding is retried after another `threshold` bytes.
+///
+/// This does not make the generators cryptographically secure. It only
+/// limits how long a single seed is used, e.g. in long-running services.
+///
+/// ```
+/// # extern crate rand_core;
+/// # extern crate xoshiro;
+/// # fn main() {
+/// use rand_core::RngCore;
+/// use xoshiro::{ReseedingXoshiro, SplitMix64, Xoshiro256StarStar};
+///
+/// // Use an entropy source such as `rand::rngs::OsRng` in practice.
+/// let source = SplitMix64::from_seed_u64(0);
+/// let mut rng: ReseedingXoshiro<Xoshiro256StarStar, _> =
+///     ReseedingXoshiro::new(1 << 20, source).unwrap();
+/// let x = rng.next_u64();
+/// # let _ = x;
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ReseedingXoshiro<R, E> {
+    rng: R,
+    source: E,
+    threshold: u64,
+    remaining: u64,
+}
+
+impl<R: RngCore + SeedableRng, E: RngCore> ReseedingXoshiro<R, E> {
+    /// Seed a generator from `source`, reseeding it after every `threshold`
+    /// bytes.
+    ///
+    /// This fails if `source` fails to provide the initial seed, and panics
+    /// if `threshold` is smaller than 8.
+    pub fn new(threshold: u64, mut source: E)
+        -> Result<ReseedingXoshiro<R, E>, Error>
+    {
+        assert!(threshold >= 8,
+                "ReseedingXoshiro::new called with threshold < 8");
+        let rng = R::from_rng(&mut source)?;
+        Ok(ReseedingXoshiro { rng, source, threshold, remaining: threshold })
+    }
+
+    /// Reseed the generator from the entropy source now.
+    pub fn reseed(&mut self) -> Result<(), Error> {
+        self.remaining = self.threshold;
+        self.rng = R::from_rng(&mut self.source)?;
+        Ok(())
+    }
+
+    /// Account for `n` bytes, reseeding first if the threshold would be
+    /// exceeded.
+    #[inline]
+    fn consume(&mut self, n: u64) {
+        if n > self.remaining {
+            // On failure, continue with the current state.
+            let _ = self.reseed();
+        }
+        self.remaining = self.remaining.saturating_sub(n);
+    }
+
+    /// Return the inner generator and entropy source.
+    pub fn into_inner(self) -> (R, E) {
+        (self.rng, self.source)
+    }
+}
+
+impl<R: RngCore + SeedableRng, E: RngCore> RngCore for ReseedingXoshiro<R, E> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.consume(4);
+        self.rng.next_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.consume(8);
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, mut dest: &mut [u8]) {
+        while !dest.is_empty() {
+            if self.remaining == 0 {
+                let _ = self.reseed();
+            }
+            let n = ::std::cmp::min(self.remaining, dest.len() as u64);
+            let (head, tail) = dest.split_at_mut(n as usize);
+            self.rng.fill_bytes(head);
+            self.remaining -= n;
+            dest = tail;
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use {SplitMix64, Xoshiro256StarStar};
+
+    #[test]
+    fn reseed() {
+        let mut source = SplitMix64::from_seed_u64(0);
+        let mut rng: ReseedingXoshiro<Xoshiro256StarStar, _> =
+            ReseedingXoshiro::new(16, source.clone()).unwrap();
+        let mut expected = Xoshiro256StarStar::from_rng(&mut source).unwrap();
+        assert_eq!(rng.next_u64(), expected.next_u64());
+        assert_eq!(rng.next_u32(), expected.next_u32());
+        assert_eq!(rng.next_u32(), expected.next_u32());
+        let mut expected = Xoshiro256StarStar::from_rng(&mut source).unwrap();
+        assert_eq!(rng.next_u64(), expected.next_u64());
+
+        let mut a = [0; 20];
+        rng.fill_bytes(&mut a);
+        let mut b = [0; 20];
+        expected.fill_bytes(&mut b[..8]);
+        let mut expected = Xoshiro256StarStar::from_rng(&mut source).unwrap();
+        expected.fill_bytes(&mut b[8..]);
+        assert_eq!(a, b);
+    }
+}
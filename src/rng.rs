@@ -0,0 +1,148 @@
+//! A trait for writing code generic over the generators of this crate.
+
+use std::mem::size_of;
+
+use rand_core::{RngCore, SeedableRng};
+
+use {Core, Jumpable, Scrambler, Xoshiro};
+#[cfg(feature = "xoroshiro64")]
+use Xoroshiro64Core;
+#[cfg(feature = "xoroshiro128")]
+use {Xoroshiro128Core, Xoroshiro128PlusPlusCore};
+#[cfg(feature = "xoshiro128")]
+use Xoshiro128Core;
+#[cfg(feature = "xoshiro256")]
+use Xoshiro256Core;
+#[cfg(feature = "xoshiro512")]
+use Xoshiro512Core;
+
+/// A xoshiro or xoroshiro generator.
+///
+/// This is implemented by all generators of this crate except `SplitMix64`,
+/// and collects the common interface: the `rand_core` traits, seeding from a
+/// `u64` and jumping.
+///
+/// ```
+/// # extern crate rand_core;
+/// # extern crate xoshiro;
+/// # fn main() {
+/// use rand_core::RngCore;
+/// use xoshiro::{Xoroshiro64Star, Xoshiro512PlusPlus, XoshiroRng};
+///
+/// fn first<R: XoshiroRng>() -> u64 {
+///     R::from_seed_u64(1).next_u64()
+/// }
+///
+/// assert!(first::<Xoshiro512PlusPlus>() != first::<Xoroshiro64Star>());
+/// assert_eq!(Xoshiro512PlusPlus::STATE_BITS, 512);
+/// assert_eq!(Xoroshiro64Star::OUTPUT_BITS, 32);
+/// # }
+/// ```
+pub trait XoshiroRng: RngCore + SeedableRng + Jumpable + Clone {
+    /// The size of the state in bits.
+    const STATE_BITS: u32;
+
+    /// The size of the native output, returned by `next_u32()` or
+    /// `next_u64()`, in bits.
+    const OUTPUT_BITS: u32;
+
+    /// Seed the generator from a `u64`, like the inherent `from_seed_u64()`.
+    fn from_seed_u64(seed: u64) -> Self;
+
+    /// Advance the state by `n` native outputs in O(log n), like the inherent
+    /// `advance()`.
+    fn advance(&mut self, n: u128);
+}
+
+macro_rules! impl_xoshiro_rng {
+    ($core:ident) => {
+        impl<S: Scrambler<$core>> XoshiroRng for Xoshiro<$core, S>
+            where Xoshiro<$core, S>: RngCore + Clone
+        {
+            const STATE_BITS: u32 =
+                8 * size_of::<<$core as Core>::State>() as u32;
+            const OUTPUT_BITS: u32 =
+                8 * size_of::<<$core as Core>::Word>() as u32;
+
+            #[inline]
+            fn from_seed_u64(seed: u64) -> Self {
+                Xoshiro::<$core, S>::from_seed_u64(seed)
+            }
+
+            #[inline]
+            fn advance(&mut self, n: u128) {
+                Xoshiro::<$core, S>::advance(self, n)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "xoroshiro64")]
+impl_xoshiro_rng!(Xoroshiro64Core);
+#[cfg(feature = "xoroshiro128")]
+impl_xoshiro_rng!(Xoroshiro128Core);
+#[cfg(feature = "xoroshiro128")]
+impl_xoshiro_rng!(Xoroshiro128PlusPlusCore);
+#[cfg(feature = "xoshiro128")]
+impl_xoshiro_rng!(Xoshiro128Core);
+#[cfg(feature = "xoshiro256")]
+impl_xoshiro_rng!(Xoshiro256Core);
+#[cfg(feature = "xoshiro512")]
+impl_xoshiro_rng!(Xoshiro512Core);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use *;
+
+    fn check<R: XoshiroRng + PartialEq + ::std::fmt::Debug>(bits: u32,
+                                                             output: u32) {
+        assert_eq!(R::STATE_BITS, bits);
+        assert_eq!(R::OUTPUT_BITS, output);
+        let rng = <R as XoshiroRng>::from_seed_u64(1);
+        let mut jumped = rng.clone();
+        jumped.jump();
+        assert!(jumped != rng);
+    }
+
+    #[test]
+    fn consts() {
+        check::<Xoroshiro128StarStar>(128, 64);
+        check::<Xoshiro128Plus>(128, 32);
+        check::<Xoshiro256StarStar>(256, 64);
+    }
+    /// Check that `SeedableRng::seed_from_u64` agrees with `from_seed_u64()`.
+    fn check_seed<R: XoshiroRng + PartialEq + ::std::fmt::Debug>() {
+        for &seed in &[1, 42, u64::MAX] {
+            assert_eq!(<R as SeedableRng>::seed_from_u64(seed),
+                       <R as XoshiroRng>::from_seed_u64(seed));
+        }
+    }
+
+    #[test]
+    fn seed_from_u64() {
+        check_seed::<Xoroshiro64Star>();
+        check_seed::<Xoroshiro64StarStar>();
+        check_seed::<Xoroshiro128Plus>();
+        check_seed::<Xoroshiro128StarStar>();
+        check_seed::<Xoroshiro128PlusPlus>();
+        check_seed::<Xoshiro128Plus>();
+        check_seed::<Xoshiro128PlusPlus>();
+        check_seed::<Xoshiro128StarStar>();
+        check_seed::<Xoshiro256Plus>();
+        check_seed::<Xoshiro256PlusPlus>();
+        check_seed::<Xoshiro256StarStar>();
+        check_seed::<Xoshiro512Plus>();
+        check_seed::<Xoshiro512PlusPlus>();
+        check_seed::<Xoshiro512StarStar>();
+        for &seed in &[0, 1, 42, u64::MAX] {
+            assert_eq!(<SplitMix64 as SeedableRng>::seed_from_u64(seed),
+                       SplitMix64::from_seed_u64(seed));
+        }
+        assert_eq!(<Xoroshiro64Star as SeedableRng>::seed_from_u64(0),
+                   Xoroshiro64Star::from_seed([0; 8]));
+
+        let mut rng = <Xoshiro256StarStar as SeedableRng>::seed_from_u64(42);
+        assert_eq!(rng.next_u64(), 1546998764402558742);
+    }
+}
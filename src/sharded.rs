@@ -0,0 +1,216 @@
+use std::cell::UnsafeCell;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use rand_core::{RngCore, Error};
+
+use Jumpable;
+
+/// The source of the shard indices of the threads.
+static NEXT_SLOT: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    /// The preferred shard of the current thread, modulo the shard count.
+    static SLOT: usize = NEXT_SLOT.fetch_add(1, Ordering::Relaxed);
+}
+
+/// A generator shared by many threads without locks.
+///
+/// The generator consists of a number of shards, each holding a generator on
+/// its own subsequence, obtained by jumping. Each thread is assigned a shard
+/// when it first uses any `ShardedRng`, and only falls back to the following
+/// shards if its own shard is in use by another thread at the same moment.
+/// With at least as many shards as threads, there is no contention.
+///
+/// The outputs are not reproducible, because they depend on which thread
+/// happens to use which shard.
+///
+/// ```
+/// # extern crate xoshiro;
+/// # fn main() {
+/// use std::sync::Arc;
+/// use std::thread;
+/// use xoshiro::{ShardedRng, Xoshiro256PlusPlus};
+///
+/// let rng = Arc::new(ShardedRng::new(Xoshiro256PlusPlus::from_seed_u64(0), 4));
+/// let handles: Vec<_> = (0..4).map(|_| {
+///     let rng = rng.clone();
+///     thread::spawn(move || rng.next_u64())
+/// }).collect();
+/// for h in handles {
+///     h.join().unwrap();
+/// }
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ShardedRng<R> {
+    shards: Box<[Shard<R>]>,
+}
+
+/// A generator and a flag marking it as in use, aligned to avoid false
+/// sharing between the shards.
+#[derive(Debug)]
+#[repr(align(64))]
+struct Shard<R> {
+    busy: AtomicBool,
+    rng: UnsafeCell<R>,
+}
+
+// The generator of a shard is only accessed by the thread which set `busy`.
+unsafe impl<R: Send> Sync for Shard<R> {}
+
+/// Clears the `busy` flag of a shard when dropped, even on panic.
+struct Release<'a>(&'a AtomicBool);
+
+impl<'a> Drop for Release<'a> {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::Release);
+    }
+}
+
+impl<R: Jumpable + Clone> ShardedRng<R> {
+    /// Create a generator with `shards` shards, the first being `rng` and
+    /// the others successively jumped once.
+    ///
+    /// # Panics
+    ///
+    /// If `shards` is zero.
+    pub fn new(rng: R, shards: usize) -> ShardedRng<R> {
+        assert!(shards != 0, "at least one shard is required");
+        ShardedRng {
+            shards: rng.streams().take(shards).map(|rng| Shard {
+                busy: AtomicBool::new(false),
+                rng: UnsafeCell::new(rng),
+            }).collect(),
+        }
+    }
+
+    /// Create a generator with one shard per available CPU.
+    pub fn per_cpu(rng: R) -> ShardedRng<R> {
+        let n = ::std::thread::available_parallelism().map_or(1, |n| n.get());
+        ShardedRng::new(rng, n)
+    }
+}
+
+impl<R> ShardedRng<R> {
+    /// Return the number of shards.
+    pub fn shards(&self) -> usize {
+        self.shards.len()
+    }
+
+    /// Call `f` with exclusive access to a shard.
+    ///
+    /// The shard is in use until `f` returns, so `f` should be short.
+    /// Calling `with()` recursively from `f` uses another shard, and spins
+    /// forever if there is none.
+    pub fn with<T, F: FnOnce(&mut R) -> T>(&self, f: F) -> T {
+        let start = SLOT.with(|&slot| slot);
+        let mut i = start;
+        loop {
+            let shard = &self.shards[i % self.shards.len()];
+            if !shard.busy.swap(true, Ordering::Acquire) {
+                let _release = Release(&shard.busy);
+                // The flag was clear, so no other thread accesses the
+                // generator until `_release` is dropped.
+                return f(unsafe { &mut *shard.rng.get() });
+            }
+            i = i.wrapping_add(1);
+            if i % self.shards.len() == start % self.shards.len() {
+                ::std::hint::spin_loop();
+            }
+        }
+    }
+}
+
+impl<R: RngCore> ShardedRng<R> {
+    /// Return the next `u32` of some shard.
+    #[inline]
+    pub fn next_u32(&self) -> u32 {
+        self.with(|rng| rng.next_u32())
+    }
+
+    /// Return the next `u64` of some shard.
+    #[inline]
+    pub fn next_u64(&self) -> u64 {
+        self.with(|rng| rng.next_u64())
+    }
+
+    /// Fill `dest` from some shard.
+    #[inline]
+    pub fn fill_bytes(&self, dest: &mut [u8]) {
+        self.with(|rng| rng.fill_bytes(dest))
+    }
+}
+
+impl<R: RngCore> RngCore for &ShardedRng<R> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        ShardedRng::next_u32(self)
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        ShardedRng::next_u64(self)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        ShardedRng::fill_bytes(self, dest)
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        ShardedRng::fill_bytes(self, dest);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::sync::Arc;
+    use std::thread;
+
+    use super::*;
+    use Xoshiro256StarStar;
+
+    #[test]
+    fn shards() {
+        let rng = Xoshiro256StarStar::from_seed_u64(1);
+        let sharded = ShardedRng::new(rng.clone(), 3);
+        assert_eq!(sharded.shards(), 3);
+        // Only this thread uses the generator, so it always gets its shard.
+        let mut expected = rng.clone().streams()
+            .nth(SLOT.with(|&slot| slot) % 3).unwrap();
+        for _ in 0..10 {
+            assert_eq!(sharded.next_u64(), expected.next_u64());
+        }
+        // Nested use takes another shard.
+        let (a, b) = sharded.with(|r1| (r1.next_u64(), sharded.next_u64()));
+        assert!(a != b);
+        assert!(ShardedRng::per_cpu(rng).shards() >= 1);
+    }
+
+    #[test]
+    fn threads() {
+        let sharded = Arc::new(ShardedRng::new(
+            Xoshiro256StarStar::from_seed_u64(1), 2));
+        let handles: Vec<_> = (0..4).map(|_| {
+            let sharded = sharded.clone();
+            thread::spawn(move || {
+                (0..1000).map(|_| sharded.next_u64()).collect::<Vec<_>>()
+            })
+        }).collect();
+        let mut seen = HashSet::new();
+        for h in handles {
+            for x in h.join().unwrap() {
+                assert!(seen.insert(x));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_shards() {
+        ShardedRng::new(Xoshiro256StarStar::from_seed_u64(1), 0);
+    }
+}
@@ -0,0 +1,148 @@
+use std::arch::x86_64::*;
+use std::fmt;
+
+use Xoshiro256Plus;
+use super::transposed_states;
+
+/// Four interleaved xoshiro256+ generators using AVX2.
+///
+/// This produces four `u64` per step, one from each lane.
+///
+/// ```
+/// # extern crate xoshiro;
+/// # fn main() {
+/// use xoshiro::simd::Xoshiro256PlusX4;
+///
+/// if let Some(mut rng) = Xoshiro256PlusX4::from_seed_u64(0) {
+///     let x: [u64; 4] = rng.next_u64x4();
+/// #   let _ = x;
+/// }
+/// # }
+/// ```
+#[derive(Clone, Copy)]
+pub struct Xoshiro256PlusX4 {
+    // Only constructed if AVX2 is available.
+    s: [__m256i; 4],
+}
+
+impl Xoshiro256PlusX4 {
+    /// Create the lanes from `rng`, jumped 0 to 3 times.
+    ///
+    /// Returns `None` if AVX2 is not available.
+    pub fn new(rng: Xoshiro256Plus) -> Option<Xoshiro256PlusX4> {
+        if !is_x86_feature_detected!("avx2") {
+            return None;
+        }
+        let words = transposed_states::<[u64; 4]>(rng);
+        let mut s = [unsafe { _mm256_setzero_si256() }; 4];
+        for (s, w) in s.iter_mut().zip(&words) {
+            *s = unsafe { _mm256_loadu_si256(w.as_ptr() as *const __m256i) };
+        }
+        Some(Xoshiro256PlusX4 { s })
+    }
+
+    /// Seed the lanes from a `u64` using `SplitMix64`.
+    ///
+    /// Returns `None` if AVX2 is not available.
+    pub fn from_seed_u64(seed: u64) -> Option<Xoshiro256PlusX4> {
+        Xoshiro256PlusX4::new(Xoshiro256Plus::from_seed_u64(seed))
+    }
+
+    /// Return the next output of each lane.
+    #[inline]
+    pub fn next_u64x4(&mut self) -> [u64; 4] {
+        let mut r = [0; 4];
+        // Safe, because `self` is only constructed if AVX2 is available.
+        unsafe {
+            let x = self.next_avx2();
+            _mm256_storeu_si256(r.as_mut_ptr() as *mut __m256i, x);
+        }
+        r
+    }
+
+    /// Fill `dest` with outputs, taking four at a time from the lanes.
+    pub fn fill_u64(&mut self, dest: &mut [u64]) {
+        let mut chunks = dest.chunks_exact_mut(4);
+        for chunk in &mut chunks {
+            // Safe, because `self` is only constructed if AVX2 is available
+            // and the chunk holds four `u64`.
+            unsafe {
+                let x = self.next_avx2();
+                _mm256_storeu_si256(chunk.as_mut_ptr() as *mut __m256i, x);
+            }
+        }
+        let rest = chunks.into_remainder();
+        if !rest.is_empty() {
+            let n = rest.len();
+            rest.copy_from_slice(&self.next_u64x4()[..n]);
+        }
+    }
+
+    /// Return the current state of each lane as scalar generators.
+    pub fn lanes(&self) -> [Xoshiro256Plus; 4] {
+        let mut words = [[0u64; 4]; 4];
+        for (w, s) in words.iter_mut().zip(&self.s) {
+            // Safe, because `self` is only constructed if AVX2 is available.
+            unsafe { _mm256_storeu_si256(w.as_mut_ptr() as *mut __m256i, *s) };
+        }
+        let lane = |i: usize| Xoshiro256Plus::new_unchecked(
+            [words[0][i], words[1][i], words[2][i], words[3][i]]);
+        [lane(0), lane(1), lane(2), lane(3)]
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn next_avx2(&mut self) -> __m256i {
+        let s = &mut self.s;
+        let result = _mm256_add_epi64(s[0], s[3]);
+
+        let t = _mm256_slli_epi64(s[1], 17);
+
+        s[2] = _mm256_xor_si256(s[2], s[0]);
+        s[3] = _mm256_xor_si256(s[3], s[1]);
+        s[1] = _mm256_xor_si256(s[1], s[2]);
+        s[0] = _mm256_xor_si256(s[0], s[3]);
+
+        s[2] = _mm256_xor_si256(s[2], t);
+
+        s[3] = _mm256_or_si256(_mm256_slli_epi64(s[3], 45),
+                               _mm256_srli_epi64(s[3], 19));
+        result
+    }
+}
+
+impl fmt::Debug for Xoshiro256PlusX4 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Xoshiro256PlusX4")
+            .field("lanes", &self.lanes())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::RngCore;
+    use super::*;
+    use simd::tests::check_lanes;
+
+    #[test]
+    fn lanes() {
+        let mut rng = match Xoshiro256PlusX4::from_seed_u64(0) {
+            Some(rng) => rng,
+            None => return,
+        };
+        check_lanes(0, 4, || rng.next_u64x4().to_vec());
+
+        let mut copy = rng;
+        let mut v = [0; 7];
+        rng.fill_u64(&mut v);
+        assert_eq!(v[..4], copy.next_u64x4());
+        assert_eq!(v[4..], copy.next_u64x4()[..3]);
+
+        let mut lanes = rng.lanes();
+        let x = rng.next_u64x4();
+        for (x, lane) in x.iter().zip(&mut lanes) {
+            assert_eq!(*x, lane.next_u64());
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use std::arch::x86_64::*;
+use std::fmt;
+
+use Xoshiro256Plus;
+use super::transposed_states;
+
+/// Eight interleaved xoshiro256+ generators using AVX-512.
+///
+/// This produces eight `u64` per step, one from each lane.
+///
+/// ```
+/// # extern crate xoshiro;
+/// # fn main() {
+/// use xoshiro::simd::Xoshiro256PlusX8;
+///
+/// if let Some(mut rng) = Xoshiro256PlusX8::from_seed_u64(0) {
+///     let x: [u64; 8] = rng.next_u64x8();
+/// #   let _ = x;
+/// }
+/// # }
+/// ```
+#[derive(Clone, Copy)]
+pub struct Xoshiro256PlusX8 {
+    // Only constructed if AVX-512F is available.
+    s: [__m512i; 4],
+}
+
+impl Xoshiro256PlusX8 {
+    /// Create the lanes from `rng`, jumped 0 to 7 times.
+    ///
+    /// Returns `None` if AVX-512F is not available.
+    pub fn new(rng: Xoshiro256Plus) -> Option<Xoshiro256PlusX8> {
+        if !is_x86_feature_detected!("avx512f") {
+            return None;
+        }
+        let words = transposed_states::<[u64; 8]>(rng);
+        let mut s = [unsafe { _mm512_setzero_si512() }; 4];
+        for (s, w) in s.iter_mut().zip(&words) {
+            *s = unsafe { _mm512_loadu_si512(w.as_ptr() as *const __m512i) };
+        }
+        Some(Xoshiro256PlusX8 { s })
+    }
+
+    /// Seed the lanes from a `u64` using `SplitMix64`.
+    ///
+    /// Returns `None` if AVX-512F is not available.
+    pub fn from_seed_u64(seed: u64) -> Option<Xoshiro256PlusX8> {
+        Xoshiro256PlusX8::new(Xoshiro256Plus::from_seed_u64(seed))
+    }
+
+    /// Return the next output of each lane.
+    #[inline]
+    pub fn next_u64x8(&mut self) -> [u64; 8] {
+        let mut r = [0; 8];
+        // Safe, because `self` is only constructed if AVX-512F is available.
+        unsafe {
+            let x = self.next_avx512();
+            _mm512_storeu_si512(r.as_mut_ptr() as *mut __m512i, x);
+        }
+        r
+    }
+
+    /// Fill `dest` with outputs, taking eight at a time from the lanes.
+    pub fn fill_u64(&mut self, dest: &mut [u64]) {
+        let mut chunks = dest.chunks_exact_mut(8);
+        for chunk in &mut chunks {
+            // Safe, because `self` is only constructed if AVX-512F is available
+            // and the chunk holds eight `u64`.
+            unsafe {
+                let x = self.next_avx512();
+                _mm512_storeu_si512(chunk.as_mut_ptr() as *mut __m512i, x);
+            }
+        }
+        let rest = chunks.into_remainder();
+        if !rest.is_empty() {
+            let n = rest.len();
+            rest.copy_from_slice(&self.next_u64x8()[..n]);
+        }
+    }
+
+    /// Return the current state of each lane as scalar generators.
+    pub fn lanes(&self) -> [Xoshiro256Plus; 8] {
+        let mut words = [[0u64; 8]; 4];
+        for (w, s) in words.iter_mut().zip(&self.s) {
+            // Safe, because `self` is only constructed if AVX-512F is available.
+            unsafe { _mm512_storeu_si512(w.as_mut_ptr() as *mut __m512i, *s) };
+        }
+        let lane = |i: usize| Xoshiro256Plus::new_unchecked(
+            [words[0][i], words[1][i], words[2][i], words[3][i]]);
+        [
+            lane(0), lane(1), lane(2), lane(3),
+            lane(4), lane(5), lane(6), lane(7),
+        ]
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    unsafe fn next_avx512(&mut self) -> __m512i {
+        let s = &mut self.s;
+        let result = _mm512_add_epi64(s[0], s[3]);
+
+        let t = _mm512_slli_epi64(s[1], 17);
+
+        s[2] = _mm512_xor_si512(s[2], s[0]);
+        s[3] = _mm512_xor_si512(s[3], s[1]);
+        s[1] = _mm512_xor_si512(s[1], s[2]);
+        s[0] = _mm512_xor_si512(s[0], s[3]);
+
+        s[2] = _mm512_xor_si512(s[2], t);
+
+        s[3] = _mm512_rol_epi64(s[3], 45);
+        result
+    }
+}
+
+impl fmt::Debug for Xoshiro256PlusX8 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Xoshiro256PlusX8")
+            .field("lanes", &self.lanes())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::RngCore;
+    use super::*;
+    use simd::tests::check_lanes;
+
+    #[test]
+    fn lanes() {
+        let mut rng = match Xoshiro256PlusX8::from_seed_u64(0) {
+            Some(rng) => rng,
+            None => return,
+        };
+        check_lanes(0, 8, || rng.next_u64x8().to_vec());
+
+        let mut copy = rng;
+        let mut v = [0; 13];
+        rng.fill_u64(&mut v);
+        assert_eq!(v[..8], copy.next_u64x8());
+        assert_eq!(v[8..], copy.next_u64x8()[..5]);
+
+        let mut lanes = rng.lanes();
+        let x = rng.next_u64x8();
+        for (x, lane) in x.iter().zip(&mut lanes) {
+            assert_eq!(*x, lane.next_u64());
+        }
+    }
+}
@@ -0,0 +1,248 @@
+use std::fmt;
+
+use rand_core::{RngCore, Error};
+
+use Xoshiro256Plus;
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64",
+          all(target_arch = "wasm32", target_feature = "simd128")))]
+use super::Xoshiro256PlusX4;
+#[cfg(target_arch = "x86_64")]
+use super::Xoshiro256PlusX8;
+
+/// Number of interleaved xoshiro256+ lanes in `FastRng`.
+const LANES: usize = 8;
+
+/// The scalar generators `rng` jumped 0 to `LANES - 1` times.
+fn scalar_lanes(mut rng: Xoshiro256Plus) -> [Xoshiro256Plus; LANES] {
+    ::std::array::from_fn(|_| {
+        let lane = rng.clone();
+        rng.jump();
+        lane
+    })
+}
+
+/// The vectorized generators backing `FastRng`.
+#[derive(Clone)]
+enum Backend {
+    Scalar([Xoshiro256Plus; LANES]),
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64",
+              all(target_arch = "wasm32", target_feature = "simd128")))]
+    X4([Xoshiro256PlusX4; 2]),
+    #[cfg(target_arch = "x86_64")]
+    X8(Xoshiro256PlusX8),
+}
+
+impl Backend {
+    /// Select the best backend available at runtime.
+    fn new(rng: Xoshiro256Plus) -> Backend {
+        Backend::x8(rng.clone())
+            .or_else(|| Backend::x4(rng.clone()))
+            .unwrap_or_else(|| Backend::Scalar(scalar_lanes(rng)))
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    fn x8(rng: Xoshiro256Plus) -> Option<Backend> {
+        Xoshiro256PlusX8::new(rng).map(Backend::X8)
+    }
+
+    #[cfg(not(target_arch = "x86_64"))]
+    fn x8(_: Xoshiro256Plus) -> Option<Backend> {
+        None
+    }
+
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64",
+              all(target_arch = "wasm32", target_feature = "simd128")))]
+    fn x4(rng: Xoshiro256Plus) -> Option<Backend> {
+        let mut high = rng.clone();
+        for _ in 0..4 {
+            high.jump();
+        }
+        let low = Xoshiro256PlusX4::new(rng)?;
+        let high = Xoshiro256PlusX4::new(high)?;
+        Some(Backend::X4([low, high]))
+    }
+
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64",
+                  all(target_arch = "wasm32", target_feature = "simd128"))))]
+    fn x4(_: Xoshiro256Plus) -> Option<Backend> {
+        None
+    }
+
+    /// Fill `dest`, whose length must be a multiple of `LANES`.
+    #[inline]
+    fn fill(&mut self, dest: &mut [u64]) {
+        debug_assert!(dest.len().is_multiple_of(LANES));
+        match *self {
+            Backend::Scalar(ref mut lanes) => {
+                for chunk in dest.chunks_exact_mut(LANES) {
+                    for (x, lane) in chunk.iter_mut().zip(lanes.iter_mut()) {
+                        *x = lane.next_u64();
+                    }
+                }
+            }
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64",
+                      all(target_arch = "wasm32", target_feature = "simd128")))]
+            Backend::X4([ref mut low, ref mut high]) => {
+                for chunk in dest.chunks_exact_mut(LANES) {
+                    let (l, h) = chunk.split_at_mut(4);
+                    l.copy_from_slice(&low.next_u64x4());
+                    h.copy_from_slice(&high.next_u64x4());
+                }
+            }
+            #[cfg(target_arch = "x86_64")]
+            Backend::X8(ref mut x8) => x8.fill_u64(dest),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match *self {
+            Backend::Scalar(_) => "scalar",
+            #[cfg(target_arch = "x86_64")]
+            Backend::X4(_) => "avx2",
+            #[cfg(target_arch = "aarch64")]
+            Backend::X4(_) => "neon",
+            #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+            Backend::X4(_) => "simd128",
+            #[cfg(target_arch = "x86_64")]
+            Backend::X8(_) => "avx512",
+        }
+    }
+}
+
+/// A fast generator dispatching to the best vectorized backend at runtime.
+///
+/// `FastRng` runs eight interleaved xoshiro256+ lanes, created from the
+/// initial generator jumped 0 to 7 times, and outputs one value from each
+/// lane in turn. When it is created, it detects the available instructions
+/// and uses AVX-512, AVX2, NEON or WebAssembly SIMD if possible, falling back
+/// to scalar code otherwise. The output does not depend on the backend.
+///
+/// ```
+/// # extern crate rand_core;
+/// # extern crate xoshiro;
+/// # fn main() {
+/// use rand_core::RngCore;
+/// use xoshiro::simd::FastRng;
+///
+/// let mut rng = FastRng::from_seed_u64(0);
+/// let mut buf = [0u64; 1024];
+/// rng.fill_u64(&mut buf);
+/// let x = rng.next_u64();
+/// # let _ = x;
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct FastRng {
+    backend: Backend,
+    buf: [u64; LANES],
+    index: usize,
+}
+
+impl FastRng {
+    /// Create the lanes from `rng`, jumped 0 to 7 times.
+    pub fn new(rng: Xoshiro256Plus) -> FastRng {
+        FastRng::with_backend(Backend::new(rng))
+    }
+
+    /// Seed the lanes from a `u64` using `SplitMix64`.
+    pub fn from_seed_u64(seed: u64) -> FastRng {
+        FastRng::new(Xoshiro256Plus::from_seed_u64(seed))
+    }
+
+    fn with_backend(backend: Backend) -> FastRng {
+        FastRng { backend, buf: [0; LANES], index: LANES }
+    }
+
+    /// The name of the backend selected at runtime.
+    pub fn backend(&self) -> &'static str {
+        self.backend.name()
+    }
+
+    /// Fill `dest` with the same values as repeatedly calling `next_u64`.
+    pub fn fill_u64(&mut self, dest: &mut [u64]) {
+        let buffered = ::std::cmp::min(LANES - self.index, dest.len());
+        let (head, dest) = dest.split_at_mut(buffered);
+        head.copy_from_slice(&self.buf[self.index..self.index + buffered]);
+        self.index += buffered;
+
+        let n = dest.len() - dest.len() % LANES;
+        let (body, tail) = dest.split_at_mut(n);
+        self.backend.fill(body);
+        for x in tail {
+            *x = self.next_u64();
+        }
+    }
+}
+
+impl RngCore for FastRng {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        // The lowest bits have some linear dependencies, so we use the
+        // upper bits instead.
+        (self.next_u64() >> 32) as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        if self.index == LANES {
+            self.backend.fill(&mut self.buf);
+            self.index = 0;
+        }
+        let x = self.buf[self.index];
+        self.index += 1;
+        x
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impl_fill_bytes!(u64, self, dest);
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl fmt::Debug for FastRng {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FastRng")
+            .field("backend", &self.backend())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backends_agree() {
+        let rng = Xoshiro256Plus::from_seed_u64(0);
+        let mut backends = vec![FastRng::new(rng.clone())];
+        backends.extend(Backend::x8(rng.clone()).map(FastRng::with_backend));
+        backends.extend(Backend::x4(rng.clone()).map(FastRng::with_backend));
+        let mut scalar = FastRng::with_backend(Backend::Scalar(scalar_lanes(rng)));
+        for _ in 0..100 {
+            let x = scalar.next_u64();
+            for rng in &mut backends {
+                assert_eq!(rng.next_u64(), x, "{} backend", rng.backend());
+            }
+        }
+    }
+
+    #[test]
+    fn fill_u64() {
+        let mut rng = FastRng::from_seed_u64(0);
+        let mut copy = rng.clone();
+        rng.next_u64();
+        copy.next_u64();
+        let mut v = [0; 45];
+        rng.fill_u64(&mut v);
+        for x in v.iter() {
+            assert_eq!(*x, copy.next_u64());
+        }
+        assert_eq!(rng.next_u64(), copy.next_u64());
+    }
+}
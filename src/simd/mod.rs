@@ -0,0 +1,91 @@
+//! Vectorized generators running several interleaved states in parallel.
+//!
+//! Each generator in this module holds `N` lanes of a scalar generator. Lane
+//! `i` produces the same sequence as the scalar generator the vectorized one
+//! was created from, jumped `i` times, so the lanes are on non-overlapping
+//! subsequences.
+//!
+//! The generators are only available on targets with a suitable instruction
+//! set. Their constructors return `None` if the instructions are not
+//! supported by the CPU at runtime. On WebAssembly, where there is no runtime
+//! detection, they require the `simd128` target feature at compile time.
+//!
+//! With the `portable-simd` feature, which requires a nightly compiler,
+//! `Xoshiro256PlusXN` is available on all targets, using `std::simd` instead
+//! of intrinsics.
+
+#[cfg(target_arch = "x86_64")]
+mod avx2;
+#[cfg(target_arch = "x86_64")]
+mod avx512;
+mod fast;
+#[cfg(target_arch = "aarch64")]
+mod neon;
+#[cfg(feature = "portable-simd")]
+mod portable;
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+mod wasm;
+
+pub use self::fast::FastRng;
+#[cfg(target_arch = "x86_64")]
+pub use self::avx2::Xoshiro256PlusX4;
+#[cfg(target_arch = "x86_64")]
+pub use self::avx512::Xoshiro256PlusX8;
+#[cfg(target_arch = "aarch64")]
+pub use self::neon::Xoshiro256PlusX4;
+#[cfg(feature = "portable-simd")]
+pub use self::portable::Xoshiro256PlusXN;
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+pub use self::wasm::Xoshiro256PlusX4;
+
+use Xoshiro256Plus;
+
+/// The states of `n` successively jumped copies of `rng`, by lane.
+fn jumped_states(mut rng: Xoshiro256Plus, n: usize) -> Vec<[u64; 4]> {
+    let mut states = Vec::with_capacity(n);
+    for _ in 0..n {
+        states.push(rng.state());
+        rng.jump();
+    }
+    states
+}
+
+/// The state words of `n` successively jumped copies of `rng`, by word.
+#[allow(dead_code)]
+fn transposed_states<T: Default + Copy + AsMut<[u64]>>(rng: Xoshiro256Plus)
+    -> [T; 4]
+{
+    let mut words = [T::default(); 4];
+    let n = words[0].as_mut().len();
+    for (lane, state) in jumped_states(rng, n).iter().enumerate() {
+        for (w, &x) in words.iter_mut().zip(state) {
+            w.as_mut()[lane] = x;
+        }
+    }
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::RngCore;
+    use Xoshiro256Plus;
+
+    /// Check that lane `i` of the outputs matches the scalar generator jumped
+    /// `i` times.
+    pub fn check_lanes<F: FnMut() -> Vec<u64>>(seed: u64, n: usize, mut next: F) {
+        let mut scalars: Vec<_> = (0..n).map(|i| {
+            let mut rng = Xoshiro256Plus::from_seed_u64(seed);
+            for _ in 0..i {
+                rng.jump();
+            }
+            rng
+        }).collect();
+        for _ in 0..20 {
+            let x = next();
+            assert_eq!(x.len(), n);
+            for (x, rng) in x.iter().zip(&mut scalars) {
+                assert_eq!(*x, rng.next_u64());
+            }
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use std::arch::aarch64::*;
+use std::arch::is_aarch64_feature_detected;
+use std::fmt;
+
+use Xoshiro256Plus;
+use super::transposed_states;
+
+/// Four interleaved xoshiro256+ generators using NEON.
+///
+/// This produces four `u64` per step, one from each lane. NEON registers
+/// hold two `u64`, so each state word is split over two registers.
+///
+/// ```
+/// # extern crate xoshiro;
+/// # fn main() {
+/// use xoshiro::simd::Xoshiro256PlusX4;
+///
+/// if let Some(mut rng) = Xoshiro256PlusX4::from_seed_u64(0) {
+///     let x: [u64; 4] = rng.next_u64x4();
+/// #   let _ = x;
+/// }
+/// # }
+/// ```
+#[derive(Clone, Copy)]
+pub struct Xoshiro256PlusX4 {
+    // Only constructed if NEON is available.
+    s: [[uint64x2_t; 2]; 4],
+}
+
+impl Xoshiro256PlusX4 {
+    /// Create the lanes from `rng`, jumped 0 to 3 times.
+    ///
+    /// Returns `None` if NEON is not available.
+    pub fn new(rng: Xoshiro256Plus) -> Option<Xoshiro256PlusX4> {
+        if !is_aarch64_feature_detected!("neon") {
+            return None;
+        }
+        let words = transposed_states::<[u64; 4]>(rng);
+        let mut s = [[unsafe { vdupq_n_u64(0) }; 2]; 4];
+        for (s, w) in s.iter_mut().zip(&words) {
+            for (s, w) in s.iter_mut().zip(w.chunks_exact(2)) {
+                *s = unsafe { vld1q_u64(w.as_ptr()) };
+            }
+        }
+        Some(Xoshiro256PlusX4 { s })
+    }
+
+    /// Seed the lanes from a `u64` using `SplitMix64`.
+    ///
+    /// Returns `None` if NEON is not available.
+    pub fn from_seed_u64(seed: u64) -> Option<Xoshiro256PlusX4> {
+        Xoshiro256PlusX4::new(Xoshiro256Plus::from_seed_u64(seed))
+    }
+
+    /// Return the next output of each lane.
+    #[inline]
+    pub fn next_u64x4(&mut self) -> [u64; 4] {
+        let mut r = [0; 4];
+        self.fill_u64(&mut r);
+        r
+    }
+
+    /// Fill `dest` with outputs, taking four at a time from the lanes.
+    pub fn fill_u64(&mut self, dest: &mut [u64]) {
+        let mut chunks = dest.chunks_exact_mut(4);
+        for chunk in &mut chunks {
+            // Safe, because `self` is only constructed if NEON is available
+            // and the chunk holds four `u64`.
+            unsafe {
+                let [lo, hi] = self.next_neon();
+                vst1q_u64(chunk.as_mut_ptr(), lo);
+                vst1q_u64(chunk.as_mut_ptr().add(2), hi);
+            }
+        }
+        let rest = chunks.into_remainder();
+        if !rest.is_empty() {
+            let n = rest.len();
+            rest.copy_from_slice(&self.next_u64x4()[..n]);
+        }
+    }
+
+    /// Return the current state of each lane as scalar generators.
+    pub fn lanes(&self) -> [Xoshiro256Plus; 4] {
+        let mut words = [[0u64; 4]; 4];
+        for (w, s) in words.iter_mut().zip(&self.s) {
+            // Safe, because `self` is only constructed if NEON is available.
+            unsafe {
+                vst1q_u64(w.as_mut_ptr(), s[0]);
+                vst1q_u64(w.as_mut_ptr().add(2), s[1]);
+            }
+        }
+        let lane = |i: usize| Xoshiro256Plus::new_unchecked(
+            [words[0][i], words[1][i], words[2][i], words[3][i]]);
+        [lane(0), lane(1), lane(2), lane(3)]
+    }
+
+    #[inline]
+    #[target_feature(enable = "neon")]
+    unsafe fn next_neon(&mut self) -> [uint64x2_t; 2] {
+        let mut result = [vdupq_n_u64(0); 2];
+        for (h, result) in result.iter_mut().enumerate() {
+            let s = &mut self.s;
+            *result = vaddq_u64(s[0][h], s[3][h]);
+
+            let t = vshlq_n_u64(s[1][h], 17);
+
+            s[2][h] = veorq_u64(s[2][h], s[0][h]);
+            s[3][h] = veorq_u64(s[3][h], s[1][h]);
+            s[1][h] = veorq_u64(s[1][h], s[2][h]);
+            s[0][h] = veorq_u64(s[0][h], s[3][h]);
+
+            s[2][h] = veorq_u64(s[2][h], t);
+
+            s[3][h] = vorrq_u64(vshlq_n_u64(s[3][h], 45),
+                                vshrq_n_u64(s[3][h], 19));
+        }
+        result
+    }
+}
+
+impl fmt::Debug for Xoshiro256PlusX4 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Xoshiro256PlusX4")
+            .field("lanes", &self.lanes())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::RngCore;
+    use super::*;
+    use simd::tests::check_lanes;
+
+    #[test]
+    fn lanes() {
+        let mut rng = match Xoshiro256PlusX4::from_seed_u64(0) {
+            Some(rng) => rng,
+            None => return,
+        };
+        check_lanes(0, 4, || rng.next_u64x4().to_vec());
+
+        let mut copy = rng;
+        let mut v = [0; 7];
+        rng.fill_u64(&mut v);
+        assert_eq!(v[..4], copy.next_u64x4());
+        assert_eq!(v[4..], copy.next_u64x4()[..3]);
+
+        let mut lanes = rng.lanes();
+        let x = rng.next_u64x4();
+        for (x, lane) in x.iter().zip(&mut lanes) {
+            assert_eq!(*x, lane.next_u64());
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use std::fmt;
+use std::simd::Simd;
+
+use Xoshiro256Plus;
+use super::jumped_states;
+
+/// `LANES` interleaved xoshiro256+ generators using `std::simd`.
+///
+/// This produces `LANES` `u64` per step, one from each lane. Unlike the
+/// other generators in this module, it does not depend on a particular
+/// instruction set and is always available, leaving the choice of
+/// instructions to the compiler.
+///
+/// ```
+/// # #![feature(portable_simd)]
+/// # extern crate xoshiro;
+/// # fn main() {
+/// use xoshiro::simd::Xoshiro256PlusXN;
+///
+/// let mut rng = Xoshiro256PlusXN::<8>::from_seed_u64(0);
+/// let x: [u64; 8] = rng.next_u64xn();
+/// # let _ = x;
+/// # }
+/// ```
+#[derive(Clone, Copy)]
+pub struct Xoshiro256PlusXN<const LANES: usize> {
+    s: [Simd<u64, LANES>; 4],
+}
+
+impl<const LANES: usize> Xoshiro256PlusXN<LANES> {
+    /// Create the lanes from `rng`, jumped 0 to `LANES - 1` times.
+    pub fn new(rng: Xoshiro256Plus) -> Xoshiro256PlusXN<LANES> {
+        let mut words = [[0; LANES]; 4];
+        for (lane, state) in jumped_states(rng, LANES).iter().enumerate() {
+            for (w, &x) in words.iter_mut().zip(state) {
+                w[lane] = x;
+            }
+        }
+        Xoshiro256PlusXN { s: words.map(Simd::from_array) }
+    }
+
+    /// Seed the lanes from a `u64` using `SplitMix64`.
+    pub fn from_seed_u64(seed: u64) -> Xoshiro256PlusXN<LANES> {
+        Xoshiro256PlusXN::new(Xoshiro256Plus::from_seed_u64(seed))
+    }
+
+    /// Return the next output of each lane as a vector.
+    #[inline]
+    pub fn next_simd(&mut self) -> Simd<u64, LANES> {
+        let s = &mut self.s;
+        let result = s[0] + s[3];
+
+        let t = s[1] << 17;
+
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+
+        s[2] ^= t;
+
+        s[3] = (s[3] << 45) | (s[3] >> 19);
+        result
+    }
+
+    /// Return the next output of each lane.
+    #[inline]
+    pub fn next_u64xn(&mut self) -> [u64; LANES] {
+        self.next_simd().to_array()
+    }
+
+    /// Fill `dest` with outputs, taking `LANES` at a time from the lanes.
+    pub fn fill_u64(&mut self, dest: &mut [u64]) {
+        let mut chunks = dest.chunks_exact_mut(LANES);
+        for chunk in &mut chunks {
+            self.next_simd().copy_to_slice(chunk);
+        }
+        let rest = chunks.into_remainder();
+        if !rest.is_empty() {
+            let n = rest.len();
+            rest.copy_from_slice(&self.next_u64xn()[..n]);
+        }
+    }
+
+    /// Return the current state of each lane as scalar generators.
+    pub fn lanes(&self) -> [Xoshiro256Plus; LANES] {
+        let words = self.s.map(|s| s.to_array());
+        ::std::array::from_fn(|i| Xoshiro256Plus::new_unchecked(
+            [words[0][i], words[1][i], words[2][i], words[3][i]]))
+    }
+}
+
+impl<const LANES: usize> fmt::Debug for Xoshiro256PlusXN<LANES> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Xoshiro256PlusXN")
+            .field("lanes", &self.lanes())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::RngCore;
+    use super::*;
+    use simd::tests::check_lanes;
+
+    fn lanes<const LANES: usize>() {
+        let mut rng = Xoshiro256PlusXN::<LANES>::from_seed_u64(0);
+        check_lanes(0, LANES, || rng.next_u64xn().to_vec());
+
+        let mut copy = rng;
+        let mut v = vec![0; 2 * LANES - 1];
+        rng.fill_u64(&mut v);
+        assert_eq!(v[..LANES], copy.next_u64xn());
+        assert_eq!(v[LANES..], copy.next_u64xn()[..LANES - 1]);
+
+        let mut lanes = rng.lanes();
+        let x = rng.next_u64xn();
+        for (x, lane) in x.iter().zip(&mut lanes) {
+            assert_eq!(*x, lane.next_u64());
+        }
+    }
+
+    #[test]
+    fn lane_counts() {
+        lanes::<1>();
+        lanes::<2>();
+        lanes::<4>();
+        lanes::<8>();
+        lanes::<16>();
+    }
+}
@@ -0,0 +1,149 @@
+use std::arch::wasm32::*;
+use std::fmt;
+
+use Xoshiro256Plus;
+use super::transposed_states;
+
+/// Four interleaved xoshiro256+ generators using WebAssembly SIMD.
+///
+/// This produces four `u64` per step, one from each lane. `v128` registers
+/// hold two `u64`, so each state word is split over two registers.
+///
+/// WebAssembly has no runtime feature detection, so this is only available
+/// when compiling with the `simd128` target feature, for instance with
+/// `RUSTFLAGS="-C target-feature=+simd128"`.
+///
+/// ```
+/// # extern crate xoshiro;
+/// # fn main() {
+/// use xoshiro::simd::Xoshiro256PlusX4;
+///
+/// if let Some(mut rng) = Xoshiro256PlusX4::from_seed_u64(0) {
+///     let x: [u64; 4] = rng.next_u64x4();
+/// #   let _ = x;
+/// }
+/// # }
+/// ```
+#[derive(Clone, Copy)]
+pub struct Xoshiro256PlusX4 {
+    s: [[v128; 2]; 4],
+}
+
+impl Xoshiro256PlusX4 {
+    /// Create the lanes from `rng`, jumped 0 to 3 times.
+    ///
+    /// Never returns `None`, because `simd128` is enabled at compile time.
+    pub fn new(rng: Xoshiro256Plus) -> Option<Xoshiro256PlusX4> {
+        let words = transposed_states::<[u64; 4]>(rng);
+        let mut s = [[u64x2_splat(0); 2]; 4];
+        for (s, w) in s.iter_mut().zip(&words) {
+            for (s, w) in s.iter_mut().zip(w.chunks_exact(2)) {
+                // Safe, because the chunk holds two `u64`.
+                *s = unsafe { v128_load(w.as_ptr() as *const v128) };
+            }
+        }
+        Some(Xoshiro256PlusX4 { s })
+    }
+
+    /// Seed the lanes from a `u64` using `SplitMix64`.
+    ///
+    /// Never returns `None`, because `simd128` is enabled at compile time.
+    pub fn from_seed_u64(seed: u64) -> Option<Xoshiro256PlusX4> {
+        Xoshiro256PlusX4::new(Xoshiro256Plus::from_seed_u64(seed))
+    }
+
+    /// Return the next output of each lane.
+    #[inline]
+    pub fn next_u64x4(&mut self) -> [u64; 4] {
+        let mut r = [0; 4];
+        self.fill_u64(&mut r);
+        r
+    }
+
+    /// Fill `dest` with outputs, taking four at a time from the lanes.
+    pub fn fill_u64(&mut self, dest: &mut [u64]) {
+        let mut chunks = dest.chunks_exact_mut(4);
+        for chunk in &mut chunks {
+            let [lo, hi] = self.next_simd128();
+            // Safe, because the chunk holds four `u64`.
+            unsafe {
+                v128_store(chunk.as_mut_ptr() as *mut v128, lo);
+                v128_store(chunk.as_mut_ptr().add(2) as *mut v128, hi);
+            }
+        }
+        let rest = chunks.into_remainder();
+        if !rest.is_empty() {
+            let n = rest.len();
+            rest.copy_from_slice(&self.next_u64x4()[..n]);
+        }
+    }
+
+    /// Return the current state of each lane as scalar generators.
+    pub fn lanes(&self) -> [Xoshiro256Plus; 4] {
+        let mut words = [[0u64; 4]; 4];
+        for (w, s) in words.iter_mut().zip(&self.s) {
+            // Safe, because `w` holds four `u64`.
+            unsafe {
+                v128_store(w.as_mut_ptr() as *mut v128, s[0]);
+                v128_store(w.as_mut_ptr().add(2) as *mut v128, s[1]);
+            }
+        }
+        let lane = |i: usize| Xoshiro256Plus::new_unchecked(
+            [words[0][i], words[1][i], words[2][i], words[3][i]]);
+        [lane(0), lane(1), lane(2), lane(3)]
+    }
+
+    #[inline]
+    fn next_simd128(&mut self) -> [v128; 2] {
+        let mut result = [u64x2_splat(0); 2];
+        for (h, result) in result.iter_mut().enumerate() {
+            let s = &mut self.s;
+            *result = u64x2_add(s[0][h], s[3][h]);
+
+            let t = u64x2_shl(s[1][h], 17);
+
+            s[2][h] = v128_xor(s[2][h], s[0][h]);
+            s[3][h] = v128_xor(s[3][h], s[1][h]);
+            s[1][h] = v128_xor(s[1][h], s[2][h]);
+            s[0][h] = v128_xor(s[0][h], s[3][h]);
+
+            s[2][h] = v128_xor(s[2][h], t);
+
+            s[3][h] = v128_or(u64x2_shl(s[3][h], 45), u64x2_shr(s[3][h], 19));
+        }
+        result
+    }
+}
+
+impl fmt::Debug for Xoshiro256PlusX4 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Xoshiro256PlusX4")
+            .field("lanes", &self.lanes())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::RngCore;
+    use super::*;
+    use simd::tests::check_lanes;
+
+    #[test]
+    fn lanes() {
+        let mut rng = Xoshiro256PlusX4::from_seed_u64(0).unwrap();
+        check_lanes(0, 4, || rng.next_u64x4().to_vec());
+
+        let mut copy = rng;
+        let mut v = [0; 7];
+        rng.fill_u64(&mut v);
+        assert_eq!(v[..4], copy.next_u64x4());
+        assert_eq!(v[4..], copy.next_u64x4()[..3]);
+
+        let mut lanes = rng.lanes();
+        let x = rng.next_u64x4();
+        for (x, lane) in x.iter().zip(&mut lanes) {
+            assert_eq!(*x, lane.next_u64());
+        }
+    }
+}
@@ -1,7 +1,51 @@
-use byteorder::{ByteOrder, LittleEndian};
-use rand_core::le::read_u64_into;
-use rand_core::impls::fill_bytes_via_next;
 use rand_core::{RngCore, SeedableRng, Error};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The increment of the state of `SplitMix64`.
+const GAMMA: u64 = 0x9e3779b97f4a7c15;
+
+/// The output function of `SplitMix64`.
+#[inline]
+pub(crate) const fn mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+/// The first `N` outputs of a `SplitMix64` seeded with `seed`.
+///
+/// This is used for seeding the other generators in const contexts.
+pub(crate) const fn splitmix_states<const N: usize>(seed: u64) -> [u64; N] {
+    let mut s = [0; N];
+    let mut x = seed;
+    let mut i = 0;
+    while i < N {
+        x = x.wrapping_add(GAMMA);
+        s[i] = mix(x);
+        i += 1;
+    }
+    s
+}
+
+/// Fill `seed` with a hash of `bytes`.
+///
+/// Each 8-byte word of `seed` is an independent hash, absorbing `bytes` in
+/// little-endian words with the `SplitMix64` output function, starting from a
+/// different key. The length of `bytes` is absorbed as well, so inputs
+/// differing only by trailing zeros give different seeds.
+pub(crate) fn hash_bytes(bytes: &[u8], seed: &mut [u8]) {
+    for (i, out) in seed.chunks_mut(8).enumerate() {
+        let mut h = (i as u64 + 1).wrapping_mul(GAMMA) ^ bytes.len() as u64;
+        for chunk in bytes.chunks(8) {
+            let mut w = [0; 8];
+            w[..chunk.len()].copy_from_slice(chunk);
+            h = mix((h ^ u64::from_le_bytes(w)).wrapping_add(GAMMA));
+        }
+        h = mix(h.wrapping_add(GAMMA));
+        out.copy_from_slice(&h.to_le_bytes()[..out.len()]);
+    }
+}
 
 /// A splitmix64 random number generator.
 ///
@@ -12,18 +56,134 @@ use rand_core::{RngCore, SeedableRng, Error};
 /// reference source code](http://xoshiro.di.unimi.it/splitmix64.c) by
 /// Sebastiano Vigna.
 #[allow(missing_copy_implementations)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "copy", derive(Copy))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
 pub struct SplitMix64 {
     x: u64,
 }
 
 impl SplitMix64 {
     /// Seed a `SplitMix64` from a `u64`.
-    pub fn from_seed_u64(seed: u64) -> SplitMix64 {
-        let mut x = [0; 8];
-        LittleEndian::write_u64(&mut x, seed);
-        SplitMix64::from_seed(x)
+    pub const fn from_seed_u64(seed: u64) -> SplitMix64 {
+        SplitMix64 { x: seed }
+    }
+
+    /// Return the name of the generator, `"splitmix64"`.
+    ///
+    /// This is the name used by the `Display` and `FromStr` implementations.
+    pub fn name() -> String {
+        "splitmix64".to_string()
+    }
+
+    /// Return the state as little-endian bytes.
+    ///
+    /// This is a snapshot of the full state, which can be restored with
+    /// `from_bytes()`.
+    pub fn to_bytes(&self) -> [u8; 8] {
+        self.x.to_le_bytes()
+    }
+
+    /// Restore a generator from a state returned by `to_bytes()`.
+    pub fn from_bytes(bytes: [u8; 8]) -> SplitMix64 {
+        SplitMix64 { x: u64::from_le_bytes(bytes) }
+    }
+
+    /// Seed the generator from the operating system's random number source.
+    ///
+    /// This panics if the operating system fails to provide randomness.
+    #[cfg(feature = "getrandom")]
+    pub fn from_entropy() -> SplitMix64 {
+        ::common::from_entropy()
+    }
+
+    /// Advance the state, equivalently to `n` calls to `next_u64()`.
+    ///
+    /// Because the state is a simple counter, this takes constant time.
+    #[inline]
+    pub fn advance(&mut self, n: u64) {
+        self.x = self.x.wrapping_add(GAMMA.wrapping_mul(n));
+    }
+
+    /// Rewind the state, equivalently to undoing `n` calls to `next_u64()`.
+    #[inline]
+    pub fn rewind(&mut self, n: u64) {
+        self.x = self.x.wrapping_sub(GAMMA.wrapping_mul(n));
+    }
+
+    /// Step the generator backwards, undoing the last call to `next_u32()`,
+    /// and return the value that call produced.
+    #[inline]
+    pub fn prev_u32(&mut self) -> u32 {
+        self.prev_u64() as u32
     }
+
+    /// Step the generator backwards, undoing the last call to `next_u64()`,
+    /// and return the value that call produced.
+    #[inline]
+    pub fn prev_u64(&mut self) -> u64 {
+        let r = mix(self.x);
+        self.x = self.x.wrapping_sub(GAMMA);
+        r
+    }
+
+    /// Fill `states` with outputs, to be used as seeds of xoshiro256
+    /// generators.
+    ///
+    /// This is equivalent to calling `next_u64()` for each word of each state
+    /// in order. However, the outputs are computed independently from the
+    /// counter, which allows the mixing to be vectorized, making this much
+    /// faster for seeding many generators at once.
+    pub fn fill_states(&mut self, states: &mut [[u64; 4]]) {
+        #[cfg(target_arch = "x86_64")]
+        {
+            // Safe, because we checked that the instructions are available.
+            if is_x86_feature_detected!("avx512dq") {
+                unsafe { fill_states_avx512(self.x, states) };
+            } else if is_x86_feature_detected!("avx2") {
+                unsafe { fill_states_avx2(self.x, states) };
+            } else {
+                fill_states(self.x, states);
+            }
+        }
+        #[cfg(not(target_arch = "x86_64"))]
+        fill_states(self.x, states);
+        self.advance(4 * states.len() as u64);
+    }
+
+    /// Return the upper 32 bits of the next output.
+    #[inline]
+    fn upper_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    impl_convenience!(SplitMix64);
+}
+
+/// Fill `states` with the outputs following the counter `x`.
+///
+/// Each output only depends on its index, so the loop can be vectorized.
+#[inline(always)]
+fn fill_states(x: u64, states: &mut [[u64; 4]]) {
+    for (i, state) in states.iter_mut().enumerate() {
+        let x = x.wrapping_add(GAMMA.wrapping_mul(4 * i as u64));
+        for (j, w) in state.iter_mut().enumerate() {
+            *w = mix(x.wrapping_add(GAMMA.wrapping_mul(j as u64 + 1)));
+        }
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn fill_states_avx2(x: u64, states: &mut [[u64; 4]]) {
+    fill_states(x, states)
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx512f,avx512dq")]
+unsafe fn fill_states_avx512(x: u64, states: &mut [[u64; 4]]) {
+    fill_states(x, states)
 }
 
 impl RngCore for SplitMix64 {
@@ -34,16 +194,13 @@ impl RngCore for SplitMix64 {
 
     #[inline]
     fn next_u64(&mut self) -> u64 {
-        self.x = self.x.wrapping_add(0x9e3779b97f4a7c15);
-        let mut z = self.x;
-        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
-        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
-        z ^ (z >> 31)
+        self.x = self.x.wrapping_add(GAMMA);
+        mix(self.x)
     }
 
     #[inline]
     fn fill_bytes(&mut self, dest: &mut [u8]) {
-        fill_bytes_via_next(self, dest);
+        impl_fill_bytes!(u64, self, dest);
     }
 
     #[inline]
@@ -53,22 +210,106 @@ impl RngCore for SplitMix64 {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> ::arbitrary::Arbitrary<'a> for SplitMix64 {
+    fn arbitrary(u: &mut ::arbitrary::Unstructured<'a>)
+        -> ::arbitrary::Result<SplitMix64>
+    {
+        let mut seed = [0; 8];
+        u.fill_buffer(&mut seed)?;
+        Ok(SplitMix64::from_seed(seed))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (8, Some(8))
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl ::bytemuck::Zeroable for SplitMix64 {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl ::bytemuck::Pod for SplitMix64 {}
+
+#[cfg(feature = "quickcheck")]
+impl ::quickcheck::Arbitrary for SplitMix64 {
+    fn arbitrary(g: &mut ::quickcheck::Gen) -> SplitMix64 {
+        SplitMix64::from_seed_u64(::quickcheck::Arbitrary::arbitrary(g))
+    }
+}
+
+/// Overwrite the state with zeros.
+#[cfg(feature = "zeroize")]
+impl ::zeroize::Zeroize for SplitMix64 {
+    fn zeroize(&mut self) {
+        self.x.zeroize();
+    }
+}
+
+#[cfg(all(feature = "zeroize", not(feature = "copy")))]
+impl Drop for SplitMix64 {
+    fn drop(&mut self) {
+        ::zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(all(feature = "zeroize", not(feature = "copy")))]
+impl ::zeroize::ZeroizeOnDrop for SplitMix64 {}
+
 impl SeedableRng for SplitMix64 {
     type Seed = [u8; 8];
 
     /// Create a new `SplitMix64`.
     fn from_seed(seed: [u8; 8]) -> SplitMix64 {
-        let mut state = [0; 1];
-        read_u64_into(&seed, &mut state);
         SplitMix64 {
-            x: state[0],
+            x: u64::from_le_bytes(seed),
         }
     }
+
+    /// Seed the generator like `from_seed_u64()`, instead of the PCG-based
+    /// expansion of the default implementation.
+    fn seed_from_u64(seed: u64) -> SplitMix64 {
+        SplitMix64::from_seed_u64(seed)
+    }
+}
+
+impl ::std::fmt::Display for SplitMix64 {
+    /// Write the state as `splitmix64/v1:<hex>`, where the hexadecimal digits
+    /// encode `to_bytes()`.
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::text::write_state(f, &SplitMix64::name(), &self.to_bytes())
+    }
+}
+
+impl ::std::fmt::LowerHex for SplitMix64 {
+    /// Write the state padded to 16 digits, like `%016llx` in C.
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::text::write_hex(f, &[self.x], 16, false)
+    }
+}
+
+impl ::std::fmt::UpperHex for SplitMix64 {
+    /// Write the state padded to 16 digits, like `%016llX` in C.
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::text::write_hex(f, &[self.x], 16, true)
+    }
+}
+
+impl ::std::str::FromStr for SplitMix64 {
+    type Err = ::text::ParseStateError;
+
+    /// Restore a generator from a state written by `Display`.
+    fn from_str(s: &str) -> Result<SplitMix64, ::text::ParseStateError> {
+        let mut bytes = [0; 8];
+        ::text::parse_state(s, &SplitMix64::name(), &mut bytes)?;
+        Ok(SplitMix64::from_bytes(bytes))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use common::{u32_to_f32, u64_to_f64};
 
     #[test]
     fn reference() {
@@ -98,4 +339,116 @@ mod tests {
             assert_eq!(rng.next_u64(), e);
         }
     }
+
+    #[test]
+    fn text() {
+        let rng = SplitMix64::from_seed_u64(0x0102);
+        assert_eq!(rng.to_string(), "splitmix64/v1:0201000000000000");
+        assert_eq!("splitmix64/v1:0201000000000000".parse(), Ok(rng));
+        assert_eq!("splitmix64/v1:0000000000000000".parse(),
+                   Ok(SplitMix64::from_seed_u64(0)));
+        assert_eq!(format!("{:x}", SplitMix64::from_seed_u64(0x0102)),
+                   "0000000000000102");
+        assert_eq!(format!("{:#X}", SplitMix64::from_seed_u64(0xab)),
+                   "0x00000000000000AB");
+    }
+
+    #[test]
+    fn advance() {
+        let mut rng1 = SplitMix64::from_seed_u64(1477776061723855037);
+        let mut rng2 = rng1.clone();
+        for _ in 0..100 {
+            rng1.next_u64();
+        }
+        rng2.advance(100);
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn rewind() {
+        let mut rng = SplitMix64::from_seed_u64(1477776061723855037);
+        let values: Vec<_> = (0..10).map(|_| rng.next_u64()).collect();
+        for &v in values.iter().rev() {
+            assert_eq!(rng.prev_u64(), v);
+        }
+        rng.advance(5);
+        rng.rewind(5);
+        for &v in &values {
+            assert_eq!(rng.next_u64(), v);
+        }
+        let x = rng.next_u32();
+        assert_eq!(rng.prev_u32(), x);
+    }
+
+    #[test]
+    fn fill_states() {
+        let mut rng1 = SplitMix64::from_seed_u64(1477776061723855037);
+        let mut rng2 = rng1.clone();
+        let mut states = [[0; 4]; 37];
+        rng1.fill_states(&mut states);
+        for state in &states {
+            for &w in state {
+                assert_eq!(w, rng2.next_u64());
+            }
+        }
+        assert_eq!(rng1.next_u64(), rng2.next_u64());
+    }
+
+    #[test]
+    fn fill_ints() {
+        let mut rng1 = SplitMix64::from_seed_u64(1);
+        let mut rng2 = rng1.clone();
+        let mut v64 = [0; 17];
+        rng1.fill_u64(&mut v64);
+        for &x in &v64 {
+            assert_eq!(x, rng2.next_u64());
+        }
+        let mut v32 = [0; 17];
+        rng1.fill_u32(&mut v32);
+        for &x in &v32 {
+            assert_eq!(x, rng2.next_u32());
+        }
+    }
+
+    #[test]
+    fn fill_floats() {
+        let mut rng1 = SplitMix64::from_seed_u64(1);
+        let mut rng2 = rng1.clone();
+        let mut v64 = [0.0; 17];
+        rng1.fill_f64(&mut v64);
+        for &x in &v64 {
+            assert_eq!(x, u64_to_f64(rng2.next_u64()));
+            assert!((0.0..1.0).contains(&x));
+        }
+        let mut v32 = [0.0; 17];
+        rng1.fill_f32(&mut v32);
+        for &x in &v32 {
+            assert_eq!(x, u32_to_f32((rng2.next_u64() >> 32) as u32));
+            assert!((0.0..1.0).contains(&x));
+        }
+    }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn fill_half_floats() {
+        use half::{bf16, f16};
+        use common::{u32_to_bf16, u32_to_f16};
+
+        let mut rng1 = SplitMix64::from_seed_u64(1);
+        let mut rng2 = rng1.clone();
+        let mut v16 = [f16::ZERO; 17];
+        rng1.fill_f16(&mut v16);
+        for &x in &v16 {
+            assert_eq!(x, u32_to_f16((rng2.next_u64() >> 32) as u32));
+            assert!((0.0..1.0).contains(&x.to_f32()));
+        }
+        let mut vb16 = [bf16::ZERO; 17];
+        rng1.fill_bf16(&mut vb16);
+        for &x in &vb16 {
+            assert_eq!(x, u32_to_bf16((rng2.next_u64() >> 32) as u32));
+            assert!((0.0..1.0).contains(&x.to_f32()));
+        }
+    }
 }
@@ -0,0 +1,215 @@
+//! Quick statistical tests of the output.
+//!
+//! This module is only available with the `stats` feature. The tests are far
+//! too weak to establish the quality of a generator, which needs batteries
+//! like PractRand or TestU01, but they run in a fraction of a second and catch
+//! mistakes that ruin the output, e.g. a wrong shift in a state update. Run
+//! them for all generators with `cargo test --features stats`.
+//!
+//! Each test returns the statistic and its p-value, the probability of a
+//! result at least as extreme for an ideal generator. The p-values are only
+//! approximate, which is irrelevant for the tiny values broken generators
+//! produce:
+//!
+//! ```
+//! # extern crate xoshiro;
+//! # fn main() {
+//! use xoshiro::Xoshiro256StarStar;
+//! use xoshiro::stats::monobit;
+//!
+//! let mut rng = Xoshiro256StarStar::from_seed_u64(1);
+//! assert!(monobit(&mut rng, 1 << 16).p_value > 1e-6);
+//! # }
+//! ```
+
+use rand_core::RngCore;
+
+use common::u64_to_f64;
+
+/// The outcome of a statistical test.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TestResult {
+    /// The test statistic.
+    pub statistic: f64,
+    /// The probability of a statistic at least as extreme for an ideal
+    /// generator.
+    pub p_value: f64,
+}
+
+/// The complementary error function, with a relative error below 1.2e-7.
+///
+/// This is the Chebyshev approximation from Numerical Recipes.
+fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1. / (1. + 0.5 * z);
+    let r = t * (-z * z - 1.26551223 + t * (1.00002368 + t * (0.37409196
+        + t * (0.09678418 + t * (-0.18628806 + t * (0.27886807
+        + t * (-1.13520398 + t * (1.48851587 + t * (-0.82215223
+        + t * 0.17087277))))))))).exp();
+    if x >= 0. { r } else { 2. - r }
+}
+
+/// The two-sided p-value of a standard normal statistic `z`.
+fn normal_p_value(z: f64) -> f64 {
+    erfc(z.abs() / ::std::f64::consts::SQRT_2)
+}
+
+/// Count the one bits of `words` outputs of `next_u64()`.
+///
+/// The statistic is the excess of ones over zeros divided by the square root
+/// of the number of bits, which is approximately standard normal.
+pub fn monobit<R: RngCore>(rng: &mut R, words: usize) -> TestResult {
+    let mut ones = 0u64;
+    for _ in 0..words {
+        ones += u64::from(rng.next_u64().count_ones());
+    }
+    let bits = 64. * words as f64;
+    let z = (2. * ones as f64 - bits) / bits.sqrt();
+    TestResult { statistic: z, p_value: normal_p_value(z) }
+}
+
+/// Compare the frequencies of the values of `n` bytes from `fill_bytes()` with
+/// the uniform distribution.
+///
+/// The statistic is chi-square with 255 degrees of freedom, and the p-value
+/// is the probability of exceeding it, using the Wilson–Hilferty
+/// approximation. `n` should be at least a few thousand.
+pub fn chi_square_bytes<R: RngCore>(rng: &mut R, n: usize) -> TestResult {
+    let mut counts = [0u64; 256];
+    let mut buf = [0u8; 4096];
+    let mut remaining = n;
+    while remaining > 0 {
+        let len = remaining.min(buf.len());
+        rng.fill_bytes(&mut buf[..len]);
+        for &b in &buf[..len] {
+            counts[usize::from(b)] += 1;
+        }
+        remaining -= len;
+    }
+    let expected = n as f64 / 256.;
+    let chi2: f64 = counts.iter().map(|&c| {
+        let d = c as f64 - expected;
+        d * d / expected
+    }).sum();
+
+    let k = 255.;
+    let v = 2. / (9. * k);
+    let z = ((chi2 / k).cbrt() - (1. - v)) / v.sqrt();
+    let p = 0.5 * erfc(z / ::std::f64::consts::SQRT_2);
+    TestResult { statistic: chi2, p_value: p }
+}
+
+/// Compute the serial correlation of `n` consecutive floats from
+/// `next_u64()`.
+///
+/// This is the circular coefficient of Knuth (TAOCP 3.3.2 K), which is close
+/// to zero for independent outputs. The p-value treats it as normal with mean
+/// `-1 / (n - 1)` and standard deviation `1 / sqrt(n)`. `n` must be at least
+/// 3.
+pub fn serial_correlation<R: RngCore>(rng: &mut R, n: usize) -> TestResult {
+    assert!(n >= 3, "serial_correlation needs at least 3 outputs");
+    let first = u64_to_f64(rng.next_u64());
+    let (mut sum, mut sum_sq, mut sum_prod) = (first, first * first, 0.);
+    let mut prev = first;
+    for _ in 1..n {
+        let u = u64_to_f64(rng.next_u64());
+        sum += u;
+        sum_sq += u * u;
+        sum_prod += prev * u;
+        prev = u;
+    }
+    sum_prod += prev * first;
+
+    let n = n as f64;
+    let c = (n * sum_prod - sum * sum) / (n * sum_sq - sum * sum);
+    let z = (c + 1. / (n - 1.)) * n.sqrt();
+    TestResult { statistic: c, p_value: normal_p_value(z) }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::{impls, Error};
+    use super::*;
+    use *;
+
+    /// The smallest p-value accepted for the fixed seeds of the tests.
+    const ALPHA: f64 = 1e-4;
+
+    macro_rules! smoke_test {
+        ($name:ident, $rng:ident) => {
+            #[test]
+            fn $name() {
+                for seed in 1..5 {
+                    let mut rng = $rng::from_seed_u64(seed);
+                    let r = monobit(&mut rng, 1 << 16);
+                    assert!(r.p_value > ALPHA, "monobit: {:?}", r);
+                    let r = chi_square_bytes(&mut rng, 1 << 20);
+                    assert!(r.p_value > ALPHA, "chi-square: {:?}", r);
+                    let r = serial_correlation(&mut rng, 1 << 18);
+                    assert!(r.p_value > ALPHA, "serial: {:?}", r);
+                }
+            }
+        }
+    }
+
+    smoke_test!(xoroshiro64star, Xoroshiro64Star);
+    smoke_test!(xoroshiro64starstar, Xoroshiro64StarStar);
+    smoke_test!(xoroshiro128plus, Xoroshiro128Plus);
+    smoke_test!(xoroshiro128starstar, Xoroshiro128StarStar);
+    smoke_test!(xoroshiro128plusplus, Xoroshiro128PlusPlus);
+    smoke_test!(xoshiro128plus, Xoshiro128Plus);
+    smoke_test!(xoshiro128plusplus, Xoshiro128PlusPlus);
+    smoke_test!(xoshiro128starstar, Xoshiro128StarStar);
+    smoke_test!(xoshiro256plus, Xoshiro256Plus);
+    smoke_test!(xoshiro256plusplus, Xoshiro256PlusPlus);
+    smoke_test!(xoshiro256starstar, Xoshiro256StarStar);
+    smoke_test!(xoshiro512plus, Xoshiro512Plus);
+    smoke_test!(xoshiro512plusplus, Xoshiro512PlusPlus);
+    smoke_test!(xoshiro512starstar, Xoshiro512StarStar);
+
+    #[test]
+    fn splitmix64() {
+        let mut rng = SplitMix64::from_seed_u64(0);
+        assert!(monobit(&mut rng, 1 << 16).p_value > ALPHA);
+        assert!(chi_square_bytes(&mut rng, 1 << 20).p_value > ALPHA);
+        assert!(serial_correlation(&mut rng, 1 << 18).p_value > ALPHA);
+    }
+
+    /// A counter, which fails every test.
+    struct Counter(u64);
+
+    impl RngCore for Counter {
+        fn next_u32(&mut self) -> u32 {
+            self.next_u64() as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.0 += 1;
+            self.0
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            impls::fill_bytes_via_next(self, dest)
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn counter() {
+        assert!(monobit(&mut Counter(0), 1 << 16).p_value < 1e-100);
+        assert!(chi_square_bytes(&mut Counter(0), 1 << 20).p_value < 1e-100);
+        assert!(serial_correlation(&mut Counter(0), 1 << 16).p_value < 1e-100);
+    }
+
+    #[test]
+    fn erfc_values() {
+        assert!((erfc(0.) - 1.).abs() < 1e-7);
+        assert!((erfc(1.) - 0.157_299_207_050_285_1).abs() < 1e-7);
+        assert!((erfc(-1.) - 1.842_700_792_949_715).abs() < 1e-7);
+        assert!((normal_p_value(1.959_963_984_540_054) - 0.05).abs() < 1e-7);
+    }
+}
@@ -0,0 +1,152 @@
+use std::sync::Mutex;
+
+use rand_core::impls::fill_bytes_via_next;
+use rand_core::{RngCore, Error};
+
+/// The default number of `u64` taken from the shared generator at once.
+const DEFAULT_BATCH: usize = 64;
+
+/// A single generator shared by many threads through a mutex.
+///
+/// Threads do not draw values from the generator directly, but through a
+/// `SyncHandle`, which locks the mutex only to take a batch of consecutive
+/// `next_u64()` outputs. All values come from the same stream, and no value
+/// is handed out twice. Which thread gets which batch depends on scheduling.
+///
+/// Prefer `ShardedRng` unless a single stream is actually required.
+///
+/// ```
+/// # extern crate rand_core;
+/// # extern crate xoshiro;
+/// # fn main() {
+/// use std::sync::Arc;
+/// use std::thread;
+/// use rand_core::RngCore;
+/// use xoshiro::{SyncXoshiro, Xoshiro256PlusPlus};
+///
+/// let shared = Arc::new(SyncXoshiro::new(Xoshiro256PlusPlus::from_seed_u64(0)));
+/// let handles: Vec<_> = (0..4).map(|_| {
+///     let shared = shared.clone();
+///     thread::spawn(move || {
+///         let mut rng = shared.handle();
+///         (0..1000).filter(|_| rng.next_u64() & 1 == 1).count()
+///     })
+/// }).collect();
+/// for h in handles {
+///     h.join().unwrap();
+/// }
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct SyncXoshiro<R> {
+    rng: Mutex<R>,
+    batch: usize,
+}
+
+impl<R: RngCore> SyncXoshiro<R> {
+    /// Share `rng`, handing out batches of 64 values.
+    pub fn new(rng: R) -> SyncXoshiro<R> {
+        SyncXoshiro::with_batch_size(rng, DEFAULT_BATCH)
+    }
+
+    /// Share `rng`, handing out batches of `batch` values.
+    ///
+    /// Larger batches reduce contention, but waste more values when handles
+    /// are dropped early.
+    ///
+    /// # Panics
+    ///
+    /// If `batch` is zero.
+    pub fn with_batch_size(rng: R, batch: usize) -> SyncXoshiro<R> {
+        assert!(batch != 0, "batch size must be nonzero");
+        SyncXoshiro { rng: Mutex::new(rng), batch }
+    }
+
+    /// Return a handle for drawing values on the current thread.
+    pub fn handle(&self) -> SyncHandle<'_, R> {
+        SyncHandle { shared: self, buf: Vec::new(), pos: 0 }
+    }
+
+    /// Return the shared generator, positioned after the last batch.
+    pub fn into_inner(self) -> R {
+        self.rng.into_inner().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Replace the contents of `buf` with the next batch.
+    fn refill(&self, buf: &mut Vec<u64>) {
+        buf.resize(self.batch, 0);
+        // Filling cannot panic, so a poisoned generator is still valid.
+        let mut rng = self.rng.lock().unwrap_or_else(|e| e.into_inner());
+        for x in buf.iter_mut() {
+            *x = rng.next_u64();
+        }
+    }
+}
+
+/// A handle drawing batches of values from a `SyncXoshiro`.
+///
+/// This is created by `SyncXoshiro::handle`. Values left in the batch are
+/// discarded when the handle is dropped.
+#[derive(Debug)]
+pub struct SyncHandle<'a, R: 'a> {
+    shared: &'a SyncXoshiro<R>,
+    buf: Vec<u64>,
+    pos: usize,
+}
+
+impl<'a, R: RngCore> RngCore for SyncHandle<'a, R> {
+    /// Return the upper half of `next_u64()`.
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        if self.pos == self.buf.len() {
+            self.shared.refill(&mut self.buf);
+            self.pos = 0;
+        }
+        self.pos += 1;
+        self.buf[self.pos - 1]
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        fill_bytes_via_next(self, dest)
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Xoshiro128PlusPlus;
+
+    #[test]
+    fn batches() {
+        let rng = Xoshiro128PlusPlus::from_seed_u64(1);
+        let shared = SyncXoshiro::with_batch_size(rng.clone(), 3);
+        let mut expected = rng;
+        let mut a = shared.handle();
+        let mut b = shared.handle();
+        let first: Vec<u64> = (0..3).map(|_| expected.next_u64()).collect();
+        let second: Vec<u64> = (0..3).map(|_| expected.next_u64()).collect();
+        assert_eq!(a.next_u64(), first[0]);
+        assert_eq!(b.next_u64(), second[0]);
+        assert_eq!(a.next_u64(), first[1]);
+        assert_eq!(a.next_u64(), first[2]);
+        assert_eq!(a.next_u64(), expected.next_u64());
+        assert_eq!(b.next_u32(), (second[1] >> 32) as u32);
+        drop(a);
+        drop(b);
+        expected.next_u64();
+        expected.next_u64();
+        assert_eq!(shared.into_inner(), expected);
+    }
+}
@@ -0,0 +1,95 @@
+//! Bindings for running the TestU01 batteries on the generators.
+//!
+//! This module is only available with the `testu01` feature and requires the
+//! TestU01 libraries (`libtestu01`, `libprobdist` and `libmylib`) to be
+//! installed where the linker can find them, e.g. by setting `LIBRARY_PATH`.
+//!
+//! TestU01 calls the generator through a C function pointer without any
+//! context, so the generator is stored in a thread-local variable while a
+//! battery is running. The batteries test 32-bit outputs as returned by
+//! `next_u32()`. Wrap a generator in `Buffered32` to test all bits of the
+//! 64-bit outputs.
+//!
+//! ```no_run
+//! # extern crate xoshiro;
+//! # fn main() {
+//! use xoshiro::Xoshiro256StarStar;
+//! use xoshiro::testu01::{run, Battery};
+//!
+//! run("xoshiro256**", Xoshiro256StarStar::from_seed_u64(1), Battery::SmallCrush);
+//! # }
+//! ```
+
+use std::cell::RefCell;
+use std::ffi::CString;
+use std::os::raw::{c_char, c_uint};
+
+use rand_core::RngCore;
+
+/// An opaque TestU01 generator.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+struct unif01_Gen {
+    _private: [u8; 0],
+}
+
+#[link(name = "testu01")]
+#[link(name = "probdist")]
+#[link(name = "mylib")]
+extern "C" {
+    fn unif01_CreateExternGenBits(name: *mut c_char,
+                                  bits: extern "C" fn() -> c_uint)
+        -> *mut unif01_Gen;
+    fn unif01_DeleteExternGenBits(gen: *mut unif01_Gen);
+    fn bbattery_SmallCrush(gen: *mut unif01_Gen);
+    fn bbattery_Crush(gen: *mut unif01_Gen);
+    fn bbattery_BigCrush(gen: *mut unif01_Gen);
+}
+
+/// A battery of statistical tests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Battery {
+    /// 10 tests, taking seconds.
+    SmallCrush,
+    /// 96 tests, taking about an hour.
+    Crush,
+    /// 106 tests, taking several hours.
+    BigCrush,
+}
+
+thread_local! {
+    static RNG: RefCell<Option<Box<dyn RngCore>>> = RefCell::new(None);
+}
+
+extern "C" fn next_bits() -> c_uint {
+    RNG.with(|rng| {
+        rng.borrow_mut().as_mut()
+            .expect("TestU01 called the generator outside of a battery")
+            .next_u32()
+    })
+}
+
+/// Run `battery` on `rng`, printing the report to stdout.
+///
+/// `name` is used in the report. This panics if called recursively.
+pub fn run<R: RngCore + 'static>(name: &str, rng: R, battery: Battery) {
+    RNG.with(|cell| {
+        let mut cell = cell.borrow_mut();
+        assert!(cell.is_none(), "testu01::run called recursively");
+        *cell = Some(Box::new(rng));
+    });
+    let name = CString::new(name).expect("name contains a NUL byte");
+    // TestU01 copies the name and does not modify it, despite the
+    // signature.
+    unsafe {
+        let gen = unif01_CreateExternGenBits(name.as_ptr() as *mut c_char,
+                                             next_bits);
+        match battery {
+            Battery::SmallCrush => bbattery_SmallCrush(gen),
+            Battery::Crush => bbattery_Crush(gen),
+            Battery::BigCrush => bbattery_BigCrush(gen),
+        }
+        unif01_DeleteExternGenBits(gen);
+    }
+    RNG.with(|cell| *cell.borrow_mut() = None);
+}
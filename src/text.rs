@@ -0,0 +1,106 @@
+//! The textual state format `<name>/v<version>:<hex>`.
+//!
+//! The hexadecimal part encodes the bytes returned by `to_bytes()`, two
+//! lowercase digits per byte. The version is bumped whenever the meaning of
+//! the bytes changes, so old snapshots are rejected instead of being
+//! restored incorrectly.
+
+use std::error::Error;
+use std::fmt;
+
+/// The current version of the format.
+const VERSION: &str = "v1";
+
+/// An error returned when parsing a generator state from a string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseStateError {
+    /// The string does not have the form `<name>/<version>:<hex>`.
+    Format,
+    /// The name does not match the generator.
+    Name,
+    /// The version of the format is not supported.
+    Version,
+    /// The hexadecimal state has the wrong length.
+    Length,
+    /// The state contains a character that is not a hexadecimal digit.
+    Digit,
+    /// The state is entirely zero, which is not allowed for the generator.
+    Zero,
+}
+
+impl fmt::Display for ParseStateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            ParseStateError::Format => "invalid generator state format",
+            ParseStateError::Name => "generator name mismatch",
+            ParseStateError::Version => "unsupported state format version",
+            ParseStateError::Length => "invalid generator state length",
+            ParseStateError::Digit => "invalid hexadecimal digit in state",
+            ParseStateError::Zero => "generator state is entirely zero",
+        })
+    }
+}
+
+impl Error for ParseStateError {}
+
+/// Write `bytes` as the state of the generator `name`.
+pub fn write_state(f: &mut fmt::Formatter, name: &str, bytes: &[u8])
+    -> fmt::Result
+{
+    write!(f, "{}/{}:", name, VERSION)?;
+    for b in bytes {
+        write!(f, "{:02x}", b)?;
+    }
+    Ok(())
+}
+
+/// Write `words` in hexadecimal, separated by spaces.
+///
+/// Each word is padded to `width` digits, like `%016llx` in C. The alternate
+/// flag prefixes each word with `0x`.
+pub fn write_hex(f: &mut fmt::Formatter, words: &[u64], width: usize,
+                 upper: bool) -> fmt::Result
+{
+    for (i, &w) in words.iter().enumerate() {
+        if i > 0 {
+            f.write_str(" ")?;
+        }
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        if upper {
+            write!(f, "{:01$X}", w, width)?;
+        } else {
+            write!(f, "{:01$x}", w, width)?;
+        }
+    }
+    Ok(())
+}
+
+/// Parse the state of the generator `name` from `s` into `bytes`.
+///
+/// Both lowercase and uppercase digits are accepted.
+pub fn parse_state(s: &str, name: &str, bytes: &mut [u8])
+    -> Result<(), ParseStateError>
+{
+    let (n, rest) = s.split_once('/').ok_or(ParseStateError::Format)?;
+    let (version, hex) = rest.split_once(':')
+        .ok_or(ParseStateError::Format)?;
+    if n != name {
+        return Err(ParseStateError::Name);
+    }
+    if version != VERSION {
+        return Err(ParseStateError::Version);
+    }
+    if hex.len() != 2 * bytes.len() {
+        return Err(ParseStateError::Length);
+    }
+    for (b, pair) in bytes.iter_mut().zip(hex.as_bytes().chunks_exact(2)) {
+        *b = digit(pair[0])? << 4 | digit(pair[1])?;
+    }
+    Ok(())
+}
+
+fn digit(c: u8) -> Result<u8, ParseStateError> {
+    (c as char).to_digit(16).map(|d| d as u8).ok_or(ParseStateError::Digit)
+}
@@ -0,0 +1,79 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use rand_core::{RngCore, Error};
+
+use Xoshiro256StarStar;
+
+thread_local! {
+    static THREAD_RNG: Rc<RefCell<Xoshiro256StarStar>> =
+        Rc::new(RefCell::new(Xoshiro256StarStar::from_entropy()));
+}
+
+/// A handle to the generator of the current thread.
+///
+/// The handle is cheap to clone, and all clones share the same generator. It
+/// cannot be sent to other threads, which have their own generator.
+#[derive(Debug, Clone)]
+pub struct ThreadRng {
+    rng: Rc<RefCell<Xoshiro256StarStar>>,
+}
+
+/// Return a handle to the generator of the current thread.
+///
+/// The generator is a `Xoshiro256StarStar`, seeded from the operating
+/// system's random number source the first time it is used on each thread.
+/// This panics if the operating system fails to provide randomness.
+///
+/// ```
+/// # extern crate rand_core;
+/// # extern crate xoshiro;
+/// # fn main() {
+/// use rand_core::RngCore;
+///
+/// let x = xoshiro::thread_rng().next_u64();
+/// # let _ = x;
+/// # }
+/// ```
+pub fn thread_rng() -> ThreadRng {
+    ThreadRng { rng: THREAD_RNG.with(|rng| rng.clone()) }
+}
+
+impl RngCore for ThreadRng {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.rng.borrow_mut().next_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.rng.borrow_mut().next_u64()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.borrow_mut().fill_bytes(dest);
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.rng.borrow_mut().try_fill_bytes(dest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shared() {
+        let mut a = thread_rng();
+        let mut b = a.clone();
+        let mut expected = a.rng.borrow().clone();
+        assert_eq!(a.next_u64(), expected.next_u64());
+        assert_eq!(b.next_u64(), expected.next_u64());
+        assert_eq!(thread_rng().next_u64(), expected.next_u64());
+        let other = ::std::thread::spawn(|| thread_rng().next_u64());
+        assert!(other.join().unwrap() != expected.next_u64());
+    }
+}
@@ -0,0 +1,164 @@
+//! JavaScript bindings.
+//!
+//! The `wasm` feature exports a class for each generator with `wasm-bindgen`,
+//! named like its Rust counterpart. Seeded with the same `u64`, the classes
+//! produce exactly the same streams as the Rust generators on any platform:
+//!
+//! ```js
+//! import { Xoshiro256StarStar } from "xoshiro";
+//!
+//! const rng = new Xoshiro256StarStar(42n);
+//! const x = rng.nextF64();
+//! rng.jump();
+//! const bytes = new Uint8Array(16);
+//! rng.fillBytes(bytes);
+//! ```
+//!
+//! The state can be saved with `state()` and restored with `fromState()` to
+//! share a stream, e.g. between a server and its clients. Build the module
+//! and generate its JavaScript glue with the `wasm-bindgen` command line tool
+//! of the same version as the dependency:
+//!
+//! ```text
+//! cargo rustc --release --lib --target wasm32-unknown-unknown \
+//!     --features wasm --crate-type cdylib
+//! wasm-bindgen --target web --out-dir pkg \
+//!     target/wasm32-unknown-unknown/release/xoshiro.wasm
+//! ```
+
+use rand_core::RngCore;
+use wasm_bindgen::prelude::*;
+
+/// Define the JavaScript class `$rng` wrapping the generator of the same
+/// name with the state `[$word; $n]`.
+macro_rules! wasm_generator {
+    ($rng:ident, [$word:ty; $n:expr] $(, $long_jump:ident)*) => {
+        #[doc = concat!("The JavaScript class wrapping `", stringify!($rng),
+                        "`.")]
+        #[wasm_bindgen]
+        #[derive(Debug, Clone)]
+        pub struct $rng {
+            rng: ::$rng,
+        }
+
+        #[wasm_bindgen]
+        impl $rng {
+            /// Seed the generator from a `BigInt` like `from_seed_u64()`.
+            #[wasm_bindgen(constructor)]
+            pub fn new(seed: u64) -> $rng {
+                $rng { rng: ::$rng::from_seed_u64(seed) }
+            }
+
+            /// Create a generator with the given state words.
+            #[wasm_bindgen(js_name = fromState)]
+            pub fn from_state(state: &[$word]) -> Result<$rng, JsError> {
+                if state.len() != $n {
+                    return Err(JsError::new(concat!(
+                        "the state must have ", stringify!($n), " words")));
+                }
+                if state.iter().all(|&w| w == 0) {
+                    return Err(JsError::new(
+                        "the state must not be entirely zero"));
+                }
+                let mut words = [0; $n];
+                words.copy_from_slice(state);
+                Ok($rng { rng: ::$rng::new_unchecked(words) })
+            }
+
+            /// Return the state words.
+            pub fn state(&self) -> Vec<$word> {
+                self.rng.state().to_vec()
+            }
+
+            #[wasm_bindgen(js_name = nextU32)]
+            pub fn next_u32(&mut self) -> u32 {
+                self.rng.next_u32()
+            }
+
+            /// Return the next `u64` as a `BigInt`.
+            #[wasm_bindgen(js_name = nextU64)]
+            pub fn next_u64(&mut self) -> u64 {
+                self.rng.next_u64()
+            }
+
+            /// Return a number in [0, 1) like `next_f64()`.
+            #[wasm_bindgen(js_name = nextF64)]
+            pub fn next_f64(&mut self) -> f64 {
+                self.rng.next_f64()
+            }
+
+            /// Fill a `Uint8Array` like `fill_bytes()`.
+            #[wasm_bindgen(js_name = fillBytes)]
+            pub fn fill_bytes(&mut self, dest: &mut [u8]) {
+                self.rng.fill_bytes(dest);
+            }
+
+            pub fn jump(&mut self) {
+                self.rng.jump();
+            }
+
+            $(
+                #[wasm_bindgen(js_name = longJump)]
+                pub fn $long_jump(&mut self) {
+                    self.rng.long_jump();
+                }
+            )*
+        }
+    }
+}
+
+#[cfg(feature = "xoroshiro64")]
+wasm_generator!(Xoroshiro64Star, [u32; 2]);
+#[cfg(feature = "xoroshiro64")]
+wasm_generator!(Xoroshiro64StarStar, [u32; 2]);
+#[cfg(feature = "xoroshiro128")]
+wasm_generator!(Xoroshiro128Plus, [u64; 2], long_jump);
+#[cfg(feature = "xoroshiro128")]
+wasm_generator!(Xoroshiro128PlusPlus, [u64; 2], long_jump);
+#[cfg(feature = "xoroshiro128")]
+wasm_generator!(Xoroshiro128StarStar, [u64; 2], long_jump);
+#[cfg(feature = "xoshiro128")]
+wasm_generator!(Xoshiro128Plus, [u32; 4], long_jump);
+#[cfg(feature = "xoshiro128")]
+wasm_generator!(Xoshiro128PlusPlus, [u32; 4], long_jump);
+#[cfg(feature = "xoshiro128")]
+wasm_generator!(Xoshiro128StarStar, [u32; 4], long_jump);
+#[cfg(feature = "xoshiro256")]
+wasm_generator!(Xoshiro256Plus, [u64; 4], long_jump);
+#[cfg(feature = "xoshiro256")]
+wasm_generator!(Xoshiro256PlusPlus, [u64; 4], long_jump);
+#[cfg(feature = "xoshiro256")]
+wasm_generator!(Xoshiro256StarStar, [u64; 4], long_jump);
+#[cfg(feature = "xoshiro512")]
+wasm_generator!(Xoshiro512Plus, [u64; 8], long_jump);
+#[cfg(feature = "xoshiro512")]
+wasm_generator!(Xoshiro512PlusPlus, [u64; 8], long_jump);
+#[cfg(feature = "xoshiro512")]
+wasm_generator!(Xoshiro512StarStar, [u64; 8], long_jump);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn xoshiro256ss() {
+        let mut wasm_rng = Xoshiro256StarStar::new(42);
+        let mut rng = ::Xoshiro256StarStar::from_seed_u64(42);
+        assert_eq!(wasm_rng.next_u64(), rng.next_u64());
+        assert_eq!(wasm_rng.next_u32(), rng.next_u32());
+        assert_eq!(wasm_rng.next_f64(), rng.next_f64());
+        wasm_rng.jump();
+        rng.jump();
+        wasm_rng.long_jump();
+        rng.long_jump();
+        let mut a = [0u8; 13];
+        let mut b = [0u8; 13];
+        wasm_rng.fill_bytes(&mut a);
+        rng.fill_bytes(&mut b);
+        assert_eq!(a, b);
+        assert_eq!(wasm_rng.state(), rng.state());
+        let mut copy = Xoshiro256StarStar::from_state(&wasm_rng.state())
+            .unwrap();
+        assert_eq!(copy.next_u64(), rng.next_u64());
+    }
+}
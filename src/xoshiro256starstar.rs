@@ -1,107 +0,0 @@
-use rand_core::impls::fill_bytes_via_next;
-use rand_core::le::read_u64_into;
-use rand_core::{SeedableRng, RngCore, Error};
-
-/// A xoshiro256** random number generator.
-///
-/// The xoshiro256** algorithm is not suitable for cryptographic purposes, but
-/// is very fast and has excellent statistical properties.
-///
-/// The algorithm used here is translated from [the `xoshiro256starstar.c`
-/// reference source code](http://xoshiro.di.unimi.it/xoshiro256starstar.c) by
-/// David Blackman and Sebastiano Vigna.
-#[derive(Debug, Clone)]
-pub struct Xoshiro256StarStar {
-    s: [u64; 4],
-}
-
-impl Xoshiro256StarStar {
-    /// Seed a `Xoshiro256StarStar` from a `u64` using `SplitMix64`.
-    pub fn from_seed_u64(seed: u64) -> Xoshiro256StarStar {
-        from_splitmix!(seed)
-    }
-
-    /// Jump forward, equivalently to 2^128 calls to `next_u64()`.
-    ///
-    /// This can be used to generate 2^128 non-overlapping subsequences for
-    /// parallel computations.
-    ///
-    /// ```
-    /// # extern crate rand;
-    /// # extern crate xoshiro;
-    /// # fn main() {
-    /// use rand::SeedableRng;
-    /// use xoshiro::Xoshiro256StarStar;
-    ///
-    /// let rng1 = Xoshiro256StarStar::from_seed_u64(0);
-    /// let mut rng2 = rng1.clone();
-    /// rng2.jump();
-    /// let mut rng3 = rng2.clone();
-    /// rng3.jump();
-    /// # }
-    /// ```
-    pub fn jump(&mut self) {
-        impl_jump!(u64, self, [
-            0x180ec6d33cfd0aba, 0xd5a61266f0c9392c,
-            0xa9582618e03fc9aa, 0x39abdc4529b1661c
-        ]);
-    }
-}
-
-impl SeedableRng for Xoshiro256StarStar {
-    type Seed = [u8; 32];
-
-    #[inline]
-    fn from_seed(seed: [u8; 32]) -> Xoshiro256StarStar {
-        let mut state = [0; 4];
-        read_u64_into(&seed, &mut state);
-        Xoshiro256StarStar { s: state }
-    }
-}
-
-impl RngCore for Xoshiro256StarStar {
-    #[inline]
-    fn next_u32(&mut self) -> u32 {
-        self.next_u64() as u32
-    }
-
-    #[inline]
-    fn next_u64(&mut self) -> u64 {
-        let result_starstar = starstar_u64!(self.s[1]);
-        impl_xoshiro_u64!(self);
-        result_starstar
-    }
-
-    #[inline]
-    fn fill_bytes(&mut self, dest: &mut [u8]) {
-        fill_bytes_via_next(self, dest);
-    }
-
-    #[inline]
-    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-        self.fill_bytes(dest);
-        Ok(())
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn reference() {
-        let mut rng = Xoshiro256StarStar::from_seed(
-            [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
-             3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0]);
-        // These values were produced with the reference implementation:
-        // http://xoshiro.di.unimi.it/xoshiro128starstar.c
-        let expected = [
-            11520, 0, 1509978240, 1215971899390074240, 1216172134540287360,
-            607988272756665600, 16172922978634559625, 8476171486693032832,
-            10595114339597558777, 2904607092377533576,
-        ];
-        for &e in &expected {
-            assert_eq!(rng.next_u64(), e);
-        }
-    }
-}
@@ -0,0 +1,165 @@
+//! Property-based tests of invariants that hold for any seed.
+
+#[macro_use]
+extern crate proptest;
+extern crate rand_core;
+extern crate xoshiro;
+
+use proptest::prelude::*;
+use rand_core::{RngCore, SeedableRng};
+use xoshiro::*;
+
+/// Return the seed of `R` with the given bytes, which must have its length.
+fn seed<R: SeedableRng>(bytes: &[u8]) -> R::Seed {
+    let mut seed = R::Seed::default();
+    seed.as_mut().copy_from_slice(bytes);
+    seed
+}
+
+/// Define a module of properties of `$rng`, with the state of `$bytes` bytes,
+/// whose 32-bit and 64-bit outputs are consistent as checked by
+/// `$consistent`. The `long_jump` properties are only defined if `long_jump`
+/// is given.
+macro_rules! properties {
+    ($name:ident, $rng:ident, $bytes:expr, $consistent:ident
+     $(, $long_jump:ident)*) => {
+        mod $name {
+            use super::*;
+
+            proptest! {
+                #[test]
+                fn jump_commutes_with_advance(s in any::<u64>(),
+                                              n in any::<u64>(),
+                                              steps in 0..100usize) {
+                    let mut a = $rng::from_seed_u64(s);
+                    let mut b = a.clone();
+                    a.jump();
+                    a.advance(u128::from(n));
+                    b.advance(u128::from(n));
+                    b.jump();
+                    prop_assert_eq!(&a, &b);
+
+                    for _ in 0..steps {
+                        a.next_u64();
+                    }
+                    a.jump();
+                    b.jump();
+                    for _ in 0..steps {
+                        b.next_u64();
+                    }
+                    prop_assert_eq!(a, b);
+                }
+
+                $(
+                    #[test]
+                    fn $long_jump(s in any::<u64>(), steps in 0..100usize) {
+                        let mut a = $rng::from_seed_u64(s);
+                        let mut b = a.clone();
+                        for _ in 0..steps {
+                            a.next_u64();
+                        }
+                        a.long_jump();
+                        b.long_jump();
+                        for _ in 0..steps {
+                            b.next_u64();
+                        }
+                        prop_assert_eq!(a, b);
+                    }
+                )*
+
+                #[test]
+                fn seed_round_trip(
+                    bytes in prop::collection::vec(any::<u8>(), $bytes)
+                        .prop_filter("the seed must not be zero",
+                                     |b| b.iter().any(|&x| x != 0)))
+                {
+                    let rng = $rng::from_seed(seed::<$rng>(&bytes));
+                    prop_assert_eq!(&rng.to_bytes()[..], &bytes[..]);
+                    prop_assert_eq!(&$rng::from_bytes(rng.to_bytes()), &rng);
+                    prop_assert_eq!($rng::from_c_state(rng.to_c_state()), rng);
+                }
+
+                #[test]
+                fn clones_diverge_when_stepped(s in any::<u64>(),
+                                               steps in 1..100usize) {
+                    let mut a = $rng::from_seed_u64(s);
+                    let mut b = a.clone();
+                    prop_assert_eq!(&a, &b);
+                    for _ in 0..steps {
+                        a.next_u32();
+                    }
+                    prop_assert!(a != b);
+                    for _ in 0..steps {
+                        b.next_u32();
+                    }
+                    prop_assert_eq!(&a, &b);
+                    prop_assert_eq!(a.next_u64(), b.next_u64());
+                }
+
+                #[test]
+                fn next_u32_consistent_with_next_u64(s in any::<u64>()) {
+                    let mut a = $rng::from_seed_u64(s);
+                    let mut b = a.clone();
+                    $consistent(&mut a, &mut b)?;
+                    prop_assert_eq!(a, b);
+                }
+            }
+        }
+    }
+}
+
+/// Check that `next_u64()` of `a` is made of two `next_u32()` of `b`, like
+/// the generators with 32-bit output compute it.
+fn concatenated<R: RngCore>(a: &mut R, b: &mut R)
+    -> Result<(), TestCaseError>
+{
+    let x = a.next_u64();
+    let lo = b.next_u32();
+    let hi = b.next_u32();
+    prop_assert_eq!(x, u64::from(lo) | u64::from(hi) << 32);
+    Ok(())
+}
+
+/// Check that `next_u32()` of `a` is the lower half of `next_u64()` of `b`.
+fn lower_half<R: RngCore>(a: &mut R, b: &mut R)
+    -> Result<(), TestCaseError>
+{
+    prop_assert_eq!(a.next_u32(), b.next_u64() as u32);
+    Ok(())
+}
+
+/// Check that `next_u32()` of `a` is the upper half of `next_u64()` of `b`,
+/// as for the `+` scrambler with its weak low bits.
+fn upper_half<R: RngCore>(a: &mut R, b: &mut R)
+    -> Result<(), TestCaseError>
+{
+    prop_assert_eq!(a.next_u32(), (b.next_u64() >> 32) as u32);
+    Ok(())
+}
+
+properties!(xoroshiro64star, Xoroshiro64Star, 8, concatenated);
+properties!(xoroshiro64starstar, Xoroshiro64StarStar, 8, concatenated);
+properties!(xoroshiro128plus, Xoroshiro128Plus, 16, upper_half,
+            long_jump_commutes_with_stepping);
+properties!(xoroshiro128plusplus, Xoroshiro128PlusPlus, 16, lower_half,
+            long_jump_commutes_with_stepping);
+properties!(xoroshiro128starstar, Xoroshiro128StarStar, 16, lower_half,
+            long_jump_commutes_with_stepping);
+properties!(xoshiro128plus, Xoshiro128Plus, 16, concatenated,
+            long_jump_commutes_with_stepping);
+properties!(xoshiro128plusplus, Xoshiro128PlusPlus, 16, concatenated,
+            long_jump_commutes_with_stepping);
+properties!(xoshiro128starstar, Xoshiro128StarStar, 16, concatenated,
+            long_jump_commutes_with_stepping);
+properties!(xoshiro256plus, Xoshiro256Plus, 32, upper_half,
+            long_jump_commutes_with_stepping);
+properties!(xoshiro256plusplus, Xoshiro256PlusPlus, 32, lower_half,
+            long_jump_commutes_with_stepping);
+properties!(xoshiro256starstar, Xoshiro256StarStar, 32, lower_half,
+            long_jump_commutes_with_stepping);
+properties!(xoshiro512plus, Xoshiro512Plus, 64, upper_half,
+            long_jump_commutes_with_stepping);
+properties!(xoshiro512plusplus, Xoshiro512PlusPlus, 64, lower_half,
+            long_jump_commutes_with_stepping);
+properties!(xoshiro512starstar, Xoshiro512StarStar, 64, lower_half,
+            long_jump_commutes_with_stepping);
@@ -0,0 +1,77 @@
+//! Compare the generators with the C reference implementations.
+//!
+//! The C code is vendored in `reference/c` and compiled by the
+//! `xoshiro-reference` crate, which is only a development dependency.
+
+extern crate rand_core;
+extern crate xoshiro;
+extern crate xoshiro_reference as reference;
+
+use rand_core::{RngCore, SeedableRng};
+use xoshiro::*;
+
+/// The number of outputs compared after seeding and after each jump.
+const OUTPUTS: usize = 1 << 20;
+
+/// The seeds for `from_seed_u64()`.
+const SEEDS: [u64; 2] = [1, 0x0123_4567_89ab_cdef];
+
+/// Define a test comparing `$rng` with its reference implementation, which
+/// returns outputs of `$next`. The outputs are compared after seeding and,
+/// if `jump` is given, after calling `jump()` and `long_jump()`.
+macro_rules! cross_validate {
+    ($test:ident, $rng:ident, $next:ident $(, $jump:ident)*) => {
+        #[test]
+        fn $test() {
+            for &seed in &SEEDS {
+                let mut rng = $rng::from_seed_u64(seed);
+                let mut c = reference::$rng::new(rng.state());
+                for _ in 0..OUTPUTS {
+                    assert_eq!(rng.$next(), c.next_word());
+                }
+                assert_eq!(rng.state(), c.state());
+                $(
+                    rng.$jump();
+                    c.jump();
+                    assert_eq!(rng.state(), c.state());
+                    for _ in 0..OUTPUTS {
+                        assert_eq!(rng.$next(), c.next_word());
+                    }
+                    rng.long_jump();
+                    c.long_jump();
+                    assert_eq!(rng.state(), c.state());
+                    for _ in 0..OUTPUTS {
+                        assert_eq!(rng.$next(), c.next_word());
+                    }
+                )*
+            }
+        }
+    }
+}
+
+cross_validate!(xoroshiro64star, Xoroshiro64Star, next_u32);
+cross_validate!(xoroshiro64starstar, Xoroshiro64StarStar, next_u32);
+cross_validate!(xoroshiro128plus, Xoroshiro128Plus, next_u64, jump);
+cross_validate!(xoroshiro128plusplus, Xoroshiro128PlusPlus, next_u64, jump);
+cross_validate!(xoroshiro128starstar, Xoroshiro128StarStar, next_u64, jump);
+cross_validate!(xoshiro128plus, Xoshiro128Plus, next_u32, jump);
+cross_validate!(xoshiro128plusplus, Xoshiro128PlusPlus, next_u32, jump);
+cross_validate!(xoshiro128starstar, Xoshiro128StarStar, next_u32, jump);
+cross_validate!(xoshiro256plus, Xoshiro256Plus, next_u64, jump);
+cross_validate!(xoshiro256plusplus, Xoshiro256PlusPlus, next_u64, jump);
+cross_validate!(xoshiro256starstar, Xoshiro256StarStar, next_u64, jump);
+cross_validate!(xoshiro512plus, Xoshiro512Plus, next_u64, jump);
+cross_validate!(xoshiro512plusplus, Xoshiro512PlusPlus, next_u64, jump);
+cross_validate!(xoshiro512starstar, Xoshiro512StarStar, next_u64, jump);
+
+#[test]
+fn splitmix64() {
+    for &seed in &SEEDS {
+        let mut rng = SplitMix64::from_seed(seed.to_le_bytes());
+        let mut c = reference::SplitMix64::new([seed]);
+        for _ in 0..OUTPUTS {
+            assert_eq!(rng.next_u64(), c.next_word());
+        }
+        assert_eq!(rng.to_bytes(), c.state()[0].to_le_bytes());
+    }
+}